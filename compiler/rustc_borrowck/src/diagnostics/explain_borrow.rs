@@ -408,6 +408,7 @@ fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) {
 
                 cx.add_placeholder_from_predicate_note(err, &path);
                 cx.add_sized_or_copy_bound_info(err, category, &path);
+                cx.add_trait_bound_notes(err, &path);
 
                 if let ConstraintCategory::Cast {
                     is_raw_ptr_dyn_type_cast: _,
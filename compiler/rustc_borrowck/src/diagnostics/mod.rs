@@ -742,6 +742,31 @@ fn add_sized_or_copy_bound_info<G: EmissionGuarantee>(
             }
         }
     }
+
+    /// Points to the trait bounds which introduced a constraint of `path`, see
+    /// [`ConstraintCategory::TraitBound`].
+    fn add_trait_bound_notes<G: EmissionGuarantee>(
+        &self,
+        err: &mut Diag<'_, G>,
+        path: &[OutlivesConstraint<'tcx>],
+    ) {
+        let mut spans: Vec<_> = path
+            .iter()
+            .filter_map(|constraint| match constraint.category {
+                ConstraintCategory::TraitBound(span) if !span.is_dummy() => Some(span),
+                _ => None,
+            })
+            .collect();
+        spans.sort();
+        spans.dedup();
+        for span in spans {
+            let msg = match self.infcx.tcx.sess.source_map().span_to_snippet(span) {
+                Ok(bound) => format!("this lifetime requirement comes from `{bound}` here"),
+                Err(_) => "this lifetime requirement comes from this trait bound".to_owned(),
+            };
+            err.span_note(span, msg);
+        }
+    }
 }
 
 /// The span(s) associated to a use of a place.
@@ -58,6 +58,7 @@ fn description(&self) -> &'static str {
             ConstraintCategory::ClosureUpvar(_) => "closure capture ",
             ConstraintCategory::Usage => "this usage ",
             ConstraintCategory::Predicate(_)
+            | ConstraintCategory::TraitBound(_)
             | ConstraintCategory::Boring
             | ConstraintCategory::BoringNoLocation
             | ConstraintCategory::Internal
@@ -540,6 +541,7 @@ pub(crate) fn report_region_error(
 
         self.add_placeholder_from_predicate_note(&mut diag, &path);
         self.add_sized_or_copy_bound_info(&mut diag, category, &path);
+        self.add_trait_bound_notes(&mut diag, &path);
 
         for constraint in &path {
             if let ConstraintCategory::Cast { is_raw_ptr_dyn_type_cast: true, .. } =
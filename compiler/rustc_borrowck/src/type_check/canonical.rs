@@ -152,7 +152,12 @@ pub(super) fn normalize_and_prove_instantiated_predicates(
     ) {
         for (predicate, span) in instantiated_predicates {
             debug!(?span, ?predicate);
-            let category = ConstraintCategory::Predicate(span);
+            let category =
+                if self.infcx.next_trait_solver() && predicate.as_trait_clause().is_some() {
+                    ConstraintCategory::TraitBound(span)
+                } else {
+                    ConstraintCategory::Predicate(span)
+                };
             let predicate = self.normalize_with_category(predicate, locations, category);
             self.prove_predicate(predicate, locations, category);
         }
@@ -1540,6 +1540,10 @@ pub struct BuiltinAttribute {
         TEST, rustc_dump_predicates, Normal, template!(Word),
         WarnFollowing, EncodeCrossCrate::No
     ),
+    rustc_attr!(
+        TEST, rustc_dump_leaf_obligations, Normal, template!(Word),
+        WarnFollowing, EncodeCrossCrate::No
+    ),
     rustc_attr!(
         TEST, rustc_dump_def_parents, Normal, template!(Word),
         WarnFollowing, EncodeCrossCrate::No
@@ -1599,6 +1603,7 @@ pub fn is_stable_diagnostic_attribute(sym: Symbol, features: &Features) -> bool
     match sym {
         sym::on_unimplemented | sym::do_not_recommend => true,
         sym::on_const => features.diagnostic_on_const(),
+        sym::inferred_from_self => features.diagnostic_inferred_from_self(),
         _ => false,
     }
 }
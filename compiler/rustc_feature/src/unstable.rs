@@ -460,6 +460,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (incomplete, deref_patterns, "1.79.0", Some(87121)),
     /// Allows deriving the From trait on single-field structs.
     (unstable, derive_from, "1.91.0", Some(144889)),
+    /// Allows marking trait type parameters with `#[diagnostic::inferred_from_self]`.
+    (unstable, diagnostic_inferred_from_self, "CURRENT_RUSTC_VERSION", None),
     /// Allows giving non-const impls custom diagnostic messages if attempted to be used as const
     (unstable, diagnostic_on_const, "1.93.0", Some(143874)),
     /// Allows `#[doc(cfg(...))]`.
@@ -386,6 +386,8 @@ hir_analysis_only_current_traits_foreign = this is not defined in the current cr
 
 hir_analysis_only_current_traits_name = this is not defined in the current crate because {$name} are always foreign
 
+hir_analysis_only_current_traits_not_fundamental = `{$name}` is not `#[fundamental]`, so `{$ty}` is not local even though it contains the local type `{$local_type}`
+
 hir_analysis_only_current_traits_note = define and implement a trait or new type instead
 
 hir_analysis_only_current_traits_note_more_info = for more information see https://doc.rust-lang.org/reference/items/implementations.html#orphan-rules
@@ -538,6 +540,11 @@ hir_analysis_ty_param_first_local = type parameter `{$param}` must be covered by
     .note = implementing a foreign trait is only possible if at least one of the types for which it is implemented is local, and no uncovered type parameters appear before that first local type
     .case_note = in this case, 'before' refers to the following order: `impl<..> ForeignTrait<T1, ..., Tn> for T0`, where `T0` is the first and `Tn` is the last
 
+hir_analysis_ty_param_fundamental = `{$param}` is not covered by `{$ty}`, as {$wrapper ->
+    [reference] references are
+    *[other] `{$wrapper}` is
+} `#[fundamental]`, which makes the orphan rules look at the wrapped type instead
+
 hir_analysis_ty_param_some = type parameter `{$param}` must be used as the type parameter for some local type (e.g., `MyStruct<{$param}>`)
     .label = type parameter `{$param}` must be used as the type parameter for some local type
     .note = implementing a foreign trait is only possible if at least one of the types for which it is implemented is local
@@ -673,11 +673,17 @@ fn ty_known_to_outlive<'tcx>(
     region: ty::Region<'tcx>,
 ) -> bool {
     test_region_obligations(tcx, id, param_env, wf_tys, |infcx| {
+        if infcx.next_trait_solver() {
+            let clause = ty::ClauseKind::TypeOutlives(ty::OutlivesPredicate(ty, region));
+            return register_outlives_goal(infcx, param_env, clause);
+        }
+
         infcx.register_type_outlives_constraint_inner(infer::TypeOutlivesConstraint {
             sub_region: region,
             sup_type: ty,
             origin: SubregionOrigin::RelateParamBound(DUMMY_SP, ty, None),
         });
+        Ok(())
     })
 }
 
@@ -692,14 +698,37 @@ fn region_known_to_outlive<'tcx>(
     region_b: ty::Region<'tcx>,
 ) -> bool {
     test_region_obligations(tcx, id, param_env, wf_tys, |infcx| {
+        if infcx.next_trait_solver() {
+            let clause = ty::ClauseKind::RegionOutlives(ty::OutlivesPredicate(region_a, region_b));
+            return register_outlives_goal(infcx, param_env, clause);
+        }
+
         infcx.sub_regions(
             SubregionOrigin::RelateRegionParamBound(DUMMY_SP, None),
             region_b,
             region_a,
         );
+        Ok(())
     })
 }
 
+/// Proves an outlives `clause` using the new solver, which adds the region
+/// constraints it results in to `infcx`.
+fn register_outlives_goal<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    clause: ty::ClauseKind<'tcx>,
+) -> Result<(), NoSolution> {
+    let ocx = ObligationCtxt::new(infcx);
+    ocx.register_obligation(Obligation::new(
+        infcx.tcx,
+        ObligationCause::dummy(),
+        param_env,
+        clause,
+    ));
+    if ocx.evaluate_obligations_error_on_ambiguity().is_empty() { Ok(()) } else { Err(NoSolution) }
+}
+
 /// Given a known `param_env` and a set of well formed types, set up an
 /// `InferCtxt`, call the passed function (to e.g. set up region constraints
 /// to be tested), then resolve region and return errors
@@ -708,14 +737,16 @@ fn test_region_obligations<'tcx>(
     id: LocalDefId,
     param_env: ty::ParamEnv<'tcx>,
     wf_tys: &FxIndexSet<Ty<'tcx>>,
-    add_constraints: impl FnOnce(&InferCtxt<'tcx>),
+    add_constraints: impl FnOnce(&InferCtxt<'tcx>) -> Result<(), NoSolution>,
 ) -> bool {
     // Unfortunately, we have to use a new `InferCtxt` each call, because
     // region constraints get added and solved there and we need to test each
     // call individually.
     let infcx = tcx.infer_ctxt().build(TypingMode::non_body_analysis());
 
-    add_constraints(&infcx);
+    if add_constraints(&infcx).is_err() {
+        return false;
+    }
 
     let errors = infcx.resolve_regions(id, param_env, wf_tys.iter().copied());
     debug!(?errors, "errors");
@@ -2268,6 +2299,10 @@ fn check_false_global_bounds(&mut self) {
 
             // Match the existing behavior.
             if pred.is_global() && !pred.has_type_flags(TypeFlags::HAS_BINDER_VARS) {
+                // Projections are lowered with the span of their `Assoc = Ty` binding,
+                // which is more precise than the whole predicate clause.
+                let is_projection =
+                    matches!(pred.kind().skip_binder(), ty::ClauseKind::Projection(..));
                 let pred = self.normalize(span, None, pred);
 
                 // only use the span of the predicate clause (#90869)
@@ -2277,7 +2312,7 @@ fn check_false_global_bounds(&mut self) {
                         .iter()
                         // There seems to be no better way to find out which predicate we are in
                         .find(|pred| pred.span.contains(obligation_span))
-                        .map(|pred| pred.span)
+                        .map(|pred| if is_projection { obligation_span } else { pred.span })
                         .unwrap_or(obligation_span);
                 }
 
@@ -137,6 +137,11 @@ fn check_for_common_items_in_impls(
                 for cause in &overlap.intercrate_ambiguity_causes {
                     cause.add_intercrate_ambiguity_hint(&mut err);
                 }
+                traits::add_fundamental_type_notes(
+                    self.tcx,
+                    &mut err,
+                    &overlap.intercrate_ambiguity_causes,
+                );
 
                 if overlap.involves_placeholder {
                     traits::add_placeholder_note(&mut err);
@@ -462,6 +462,22 @@ fn emit_orphan_check_error<'tcx>(
                 }
             }
 
+            for &(ty, _) in &tys {
+                if let ty::Adt(adt_def, _) = *ty.kind()
+                    && !adt_def.is_fundamental()
+                    && let Some(local_type) = ty
+                        .walk()
+                        .skip(1)
+                        .find_map(|arg| arg.as_type().filter(|&ty| is_local_ty_constructor(ty)))
+                {
+                    diag.subdiagnostic(errors::OnlyCurrentTraitsNotFundamental {
+                        ty: tcx.erase_and_anonymize_regions(ty),
+                        name: tcx.def_path_str(adt_def.did()),
+                        local_type: tcx.erase_and_anonymize_regions(local_type),
+                    });
+                }
+            }
+
             diag.emit()
         }
         traits::OrphanCheckErr::UncoveredTyParams(UncoveredTyParams { uncovered, local_ty }) => {
@@ -469,6 +485,8 @@ fn emit_orphan_check_error<'tcx>(
             for param_def_id in uncovered {
                 let name = tcx.item_ident(param_def_id);
                 let span = name.span;
+                let fundamental = uncovered_by_fundamental_type(tcx, trait_ref, param_def_id)
+                    .map(|(ty, wrapper)| errors::TyParamFundamental { param: name, ty, wrapper });
 
                 reported.get_or_insert(match local_ty {
                     Some(local_type) => tcx.dcx().emit_err(errors::TyParamFirstLocal {
@@ -476,8 +494,14 @@ fn emit_orphan_check_error<'tcx>(
                         note: (),
                         param: name,
                         local_type,
+                        fundamental,
+                    }),
+                    None => tcx.dcx().emit_err(errors::TyParamSome {
+                        span,
+                        note: (),
+                        param: name,
+                        fundamental,
                     }),
-                    None => tcx.dcx().emit_err(errors::TyParamSome { span, note: (), param: name }),
                 });
             }
             reported.unwrap() // FIXME(fmease): This is very likely reachable.
@@ -485,6 +509,65 @@ fn emit_orphan_check_error<'tcx>(
     }
 }
 
+/// Whether `ty` is a type constructor defined in the current crate.
+fn is_local_ty_constructor(ty: Ty<'_>) -> bool {
+    match *ty.kind() {
+        ty::Adt(adt_def, _) => adt_def.did().is_local(),
+        ty::Foreign(def_id) => def_id.is_local(),
+        ty::Dynamic(data, ..) => data.principal_def_id().is_some_and(|def_id| def_id.is_local()),
+        _ => false,
+    }
+}
+
+/// If the type parameter `param_def_id` is first used in `trait_ref` inside of a
+/// `#[fundamental]` type, returns that type together with the name of the fundamental
+/// type or `reference`.
+fn uncovered_by_fundamental_type<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    trait_ref: ty::TraitRef<'tcx>,
+    param_def_id: DefId,
+) -> Option<(Ty<'tcx>, String)> {
+    for ty in trait_ref.args.types() {
+        if is_param(tcx, ty, param_def_id) {
+            return None;
+        }
+        if let Some(uncovered) = fundamental_wrapper_of_param(tcx, ty, param_def_id) {
+            return Some(uncovered);
+        }
+    }
+    None
+}
+
+/// If the type parameter `param_def_id` is only reachable in `ty` by looking through
+/// `#[fundamental]` types, returns the innermost such type, which doesn't cover the
+/// parameter, together with the name of the fundamental type or `reference`.
+fn fundamental_wrapper_of_param<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    param_def_id: DefId,
+) -> Option<(Ty<'tcx>, String)> {
+    let (wrapper, args) = match *ty.kind() {
+        ty::Ref(_, inner, _) => ("reference".to_owned(), vec![inner]),
+        ty::Adt(adt_def, args) if adt_def.is_fundamental() && !adt_def.did().is_local() => {
+            (tcx.def_path_str(adt_def.did()), args.types().collect())
+        }
+        _ => return None,
+    };
+    if args.iter().any(|&arg| is_param(tcx, arg, param_def_id)) {
+        return Some((ty, wrapper));
+    }
+    args.into_iter().find_map(|arg| fundamental_wrapper_of_param(tcx, arg, param_def_id))
+}
+
+fn is_param<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, param_def_id: DefId) -> bool {
+    match *ty.kind() {
+        ty::Param(param) => {
+            tcx.generics_of(tcx.parent(param_def_id)).type_param(param, tcx).def_id == param_def_id
+        }
+        _ => false,
+    }
+}
+
 fn lint_uncovered_ty_params<'tcx>(
     tcx: TyCtxt<'tcx>,
     UncoveredTyParams { uncovered, local_ty }: UncoveredTyParams<TyCtxt<'tcx>, FxIndexSet<DefId>>,
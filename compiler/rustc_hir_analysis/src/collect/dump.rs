@@ -1,9 +1,16 @@
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_hir::def_id::{CRATE_DEF_ID, LocalDefId};
 use rustc_hir::intravisit;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_infer::traits::{Obligation, ObligationCause};
 use rustc_middle::hir::nested_filter;
-use rustc_middle::ty::{self, TyCtxt, TypeVisitableExt};
+use rustc_middle::traits::solve::Goal;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{self, TyCtxt, TypeVisitableExt, TypingMode};
 use rustc_span::sym;
+use rustc_trait_selection::solve::inspect::InferCtxtProofTreeExt;
+use rustc_trait_selection::traits::{FulfillmentError, ObligationCtxt};
 
 pub(crate) fn opaque_hidden_types(tcx: TyCtxt<'_>) {
     if !tcx.has_attr(CRATE_DEF_ID, sym::rustc_hidden_type_of_opaques) {
@@ -51,6 +58,95 @@ pub(crate) fn predicates_and_item_bounds(tcx: TyCtxt<'_>) {
     }
 }
 
+/// Proves the own where-clauses of items annotated with `#[rustc_dump_leaf_obligations]`
+/// with the next-generation trait solver, using the where-clauses of the parent item
+/// as the param-env. For each where-clause which does not hold, we dump the leaf
+/// obligation derived from its proof tree, its error code, and its cause chain.
+pub(crate) fn leaf_obligations(tcx: TyCtxt<'_>) {
+    for id in tcx.hir_crate_items(()).owners() {
+        if !tcx.has_attr(id, sym::rustc_dump_leaf_obligations) {
+            continue;
+        }
+
+        let param_env = parent_param_env(tcx, id.def_id);
+        let infcx =
+            tcx.infer_ctxt().with_next_trait_solver(true).build(TypingMode::non_body_analysis());
+        for &(clause, span) in tcx.predicates_of(id).predicates {
+            let ocx = ObligationCtxt::<FulfillmentError<'_>>::new_with_diagnostics(&infcx);
+            let cause = ObligationCause::misc(span, id.def_id);
+            ocx.register_obligation(Obligation::new(tcx, cause, param_env, clause));
+            let errors = ocx.evaluate_obligations_error_on_ambiguity();
+
+            let mut diag = tcx.dcx().struct_span_err(
+                span,
+                format!("{}: `{clause}`", sym::rustc_dump_leaf_obligations.as_str()),
+            );
+            if errors.is_empty() {
+                diag.note("holds");
+            }
+            for error in errors {
+                let obligation = infcx.resolve_vars_if_possible(error.obligation);
+                diag.span_note(obligation.cause.span, format!("leaf: `{}`", obligation.predicate));
+                diag.note(format!("code: {:?}", error.code));
+                let mut code = obligation.cause.code();
+                while let Some((parent_code, parent_trait_pred)) = code.parent_with_predicate() {
+                    if let Some(parent_trait_pred) = parent_trait_pred {
+                        diag.note(format!("required for `{parent_trait_pred}`"));
+                    }
+                    code = parent_code;
+                }
+            }
+            diag.emit();
+        }
+    }
+}
+
+/// Prints the proof trees of the own where-clauses of the items passed to `-Zdump-proof-tree`,
+/// proven in the same way as for `#[rustc_dump_leaf_obligations]`.
+///
+/// The output only mentions goals, the candidates used to prove them, and their results.
+/// It does not contain spans or `DefId`s so that it can be used as a snapshot in UI tests.
+pub(crate) fn proof_trees(tcx: TyCtxt<'_>) {
+    let items = &tcx.sess.opts.unstable_opts.dump_proof_tree;
+    let mut found = FxHashSet::default();
+    for id in tcx.hir_crate_items(()).owners() {
+        let path = with_no_trimmed_paths!(tcx.def_path_str(id));
+        if !items.contains(&path) {
+            continue;
+        }
+
+        let param_env = parent_param_env(tcx, id.def_id);
+        let infcx =
+            tcx.infer_ctxt().with_next_trait_solver(true).build(TypingMode::non_body_analysis());
+        for &(clause, span) in tcx.predicates_of(id).predicates {
+            with_no_trimmed_paths!({
+                println!("proof tree for `{clause}` in `{path}`:");
+                let goal = Goal::new(tcx, param_env, clause);
+                let proof_tree = infcx.owned_proof_tree(goal, span);
+                for line in proof_tree.to_string().lines() {
+                    println!("    {line}");
+                }
+            });
+        }
+        found.insert(path);
+    }
+
+    for item in items {
+        if !found.contains(item) {
+            tcx.dcx().err(format!("cannot dump the proof trees of `{item}`: no such item"));
+        }
+    }
+}
+
+/// The param-env used to prove the own where-clauses of `id`, which are only
+/// proven in the environment of its parent.
+fn parent_param_env(tcx: TyCtxt<'_>, id: LocalDefId) -> ty::ParamEnv<'_> {
+    match tcx.generics_of(id).parent {
+        Some(parent) => tcx.param_env(parent),
+        None => ty::ParamEnv::empty(),
+    }
+}
+
 pub(crate) fn def_parents(tcx: TyCtxt<'_>) {
     for iid in tcx.hir_free_items() {
         let did = iid.owner_id.def_id;
@@ -1285,6 +1285,8 @@ pub(crate) struct TyParamFirstLocal<'tcx> {
     pub note: (),
     pub param: Ident,
     pub local_type: Ty<'tcx>,
+    #[subdiagnostic]
+    pub fundamental: Option<TyParamFundamental<'tcx>>,
 }
 
 #[derive(LintDiagnostic)]
@@ -1302,13 +1304,24 @@ pub(crate) struct TyParamFirstLocalLint<'tcx> {
 #[derive(Diagnostic)]
 #[diag(hir_analysis_ty_param_some, code = E0210)]
 #[note]
-pub(crate) struct TyParamSome {
+pub(crate) struct TyParamSome<'tcx> {
     #[primary_span]
     #[label]
     pub span: Span,
     #[note(hir_analysis_only_note)]
     pub note: (),
     pub param: Ident,
+    #[subdiagnostic]
+    pub fundamental: Option<TyParamFundamental<'tcx>>,
+}
+
+#[derive(Subdiagnostic)]
+#[note(hir_analysis_ty_param_fundamental)]
+pub(crate) struct TyParamFundamental<'tcx> {
+    pub param: Ident,
+    pub ty: Ty<'tcx>,
+    /// The name of the `#[fundamental]` type, or `reference`.
+    pub wrapper: String,
 }
 
 #[derive(LintDiagnostic)]
@@ -1353,6 +1366,14 @@ pub(crate) enum OnlyCurrentTraits {
     },
 }
 
+#[derive(Subdiagnostic)]
+#[note(hir_analysis_only_current_traits_not_fundamental)]
+pub(crate) struct OnlyCurrentTraitsNotFundamental<'a> {
+    pub ty: Ty<'a>,
+    pub name: String,
+    pub local_type: Ty<'a>,
+}
+
 #[derive(Subdiagnostic)]
 #[label(hir_analysis_only_current_traits_opaque)]
 pub(crate) struct OnlyCurrentTraitsOpaque {
@@ -268,11 +268,16 @@ pub fn check_crate(tcx: TyCtxt<'_>) {
             variance::dump::variances(tcx);
             collect::dump::opaque_hidden_types(tcx);
             collect::dump::predicates_and_item_bounds(tcx);
+            collect::dump::leaf_obligations(tcx);
             collect::dump::def_parents(tcx);
             collect::dump::vtables(tcx);
         });
     }
 
+    if !tcx.sess.opts.unstable_opts.dump_proof_tree.is_empty() {
+        tcx.sess.time("dumping_proof_trees", || collect::dump::proof_trees(tcx));
+    }
+
     tcx.ensure_ok().check_unused_traits(());
 }
 
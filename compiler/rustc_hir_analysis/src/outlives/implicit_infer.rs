@@ -3,6 +3,7 @@
 use rustc_hir::def_id::DefId;
 use rustc_middle::ty::{self, GenericArg, GenericArgKind, Ty, TyCtxt};
 use rustc_span::Span;
+use rustc_trait_selection::traits::outlives_bounds::wf_outlives_requirements;
 use tracing::debug;
 
 use super::explicit::ExplicitPredicatesMap;
@@ -117,6 +118,61 @@ fn insert_required_predicates_to_be_wf<'tcx>(
     global_inferred_outlives: &FxIndexMap<DefId, ty::EarlyBinder<'tcx, RequiredPredicates<'tcx>>>,
     required_predicates: &mut RequiredPredicates<'tcx>,
     explicit_map: &mut ExplicitPredicatesMap<'tcx>,
+) {
+    // FIXME: The requirements of ADTs and aliases depend on their inferred predicates,
+    // which are only available once we've reached a fixed point, so we still have to
+    // compute them ourselves.
+    if !tcx.sess.opts.unstable_opts.next_solver_infer_outlives || !is_structural(ty) {
+        return walk_required_predicates_to_be_wf(
+            tcx,
+            ty,
+            span,
+            global_inferred_outlives,
+            required_predicates,
+            explicit_map,
+        );
+    }
+
+    for predicate in wf_outlives_requirements(tcx, ty::ParamEnv::empty(), ty, span) {
+        required_predicates.entry(predicate).or_insert(span);
+    }
+}
+
+/// Whether the outlives requirements of `ty` only depend on its structure, and not
+/// on the predicates of other items.
+fn is_structural(ty: Ty<'_>) -> bool {
+    ty.walk().all(|arg| match arg.kind() {
+        GenericArgKind::Type(ty) => matches!(
+            ty.kind(),
+            ty::Bool
+                | ty::Char
+                | ty::Int(_)
+                | ty::Uint(_)
+                | ty::Float(_)
+                | ty::Str
+                | ty::Never
+                | ty::Param(_)
+                | ty::Ref(..)
+                | ty::RawPtr(..)
+                | ty::Slice(_)
+                | ty::Array(..)
+                | ty::Tuple(_)
+                | ty::FnPtr(..)
+        ),
+        GenericArgKind::Lifetime(_) => true,
+        GenericArgKind::Const(ct) => {
+            matches!(ct.kind(), ty::ConstKind::Param(_) | ty::ConstKind::Value(_))
+        }
+    })
+}
+
+fn walk_required_predicates_to_be_wf<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    span: Span,
+    global_inferred_outlives: &FxIndexMap<DefId, ty::EarlyBinder<'tcx, RequiredPredicates<'tcx>>>,
+    required_predicates: &mut RequiredPredicates<'tcx>,
+    explicit_map: &mut ExplicitPredicatesMap<'tcx>,
 ) {
     for arg in ty.walk() {
         let leaf_ty = match arg.kind() {
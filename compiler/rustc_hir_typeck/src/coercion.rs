@@ -763,6 +763,7 @@ fn coerce_unsized_old_solver(
                         obligation.clone(),
                         &obligation,
                         &err,
+                        &|_| {},
                     );
                     self.fcx.set_tainted_by_errors(guar);
                     // Treat this like an obligation and follow through
@@ -7,10 +7,11 @@
 pub(crate) mod probe;
 mod suggest;
 
+use rustc_data_structures::fx::FxIndexMap;
 use rustc_errors::{Applicability, Diag, SubdiagMessage};
 use rustc_hir as hir;
 use rustc_hir::def::{CtorOf, DefKind, Namespace};
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_infer::infer::{BoundRegionConversionTime, InferOk};
 use rustc_infer::traits::PredicateObligations;
 use rustc_middle::traits::ObligationCause;
@@ -21,6 +22,7 @@
 use rustc_span::{ErrorGuaranteed, Ident, Span, Symbol};
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt;
 use rustc_trait_selection::traits::{self, NormalizeExt};
+use smallvec::SmallVec;
 use tracing::{debug, instrument};
 
 pub(crate) use self::MethodError::*;
@@ -45,8 +47,9 @@ pub(crate) enum MethodError<'tcx> {
     /// Did not find an applicable method, but we did find various near-misses that may work.
     NoMatch(NoMatchData<'tcx>),
 
-    /// Multiple methods might apply.
-    Ambiguity(Vec<CandidateSource>),
+    /// Multiple methods might apply. For the candidates from traits in scope, we also
+    /// keep the imports which brought these traits into scope, if any.
+    Ambiguity(Vec<CandidateSource>, FxIndexMap<DefId, SmallVec<[LocalDefId; 1]>>),
 
     /// Found an applicable method, but it is not visible. The third argument contains a list of
     /// not-in-scope traits which may work.
@@ -236,7 +239,7 @@ pub(crate) fn lookup_method(
                 Ok(ref new_pick) if pick.differs_from(new_pick) => {
                     vec![new_pick.item.container_id(self.tcx)]
                 }
-                Err(Ambiguity(ref sources)) => sources
+                Err(Ambiguity(ref sources, _)) => sources
                     .iter()
                     .filter_map(|source| {
                         match *source {
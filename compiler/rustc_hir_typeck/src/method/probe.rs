@@ -1179,7 +1179,7 @@ fn pick(mut self) -> PickResult<'tcx> {
 
         let out_of_scope_traits = match self.pick_core(&mut Vec::new()) {
             Some(Ok(p)) => vec![p.item.container_id(self.tcx)],
-            Some(Err(MethodError::Ambiguity(v))) => v
+            Some(Err(MethodError::Ambiguity(v, _))) => v
                 .into_iter()
                 .map(|source| match source {
                     CandidateSource::Trait(id) => id,
@@ -1464,11 +1464,14 @@ fn check_for_shadowed_autorefd_method(
         // Look for actual pairs of shadower/shadowed which are
         // the sort of shadowing case we want to avoid. Specifically...
         if let Some(Ok(possible_shadowed)) = potentially_shadowed_pick.as_ref() {
-            let sources = [possible_shadower, possible_shadowed]
-                .into_iter()
-                .map(|p| self.candidate_source_from_pick(p))
+            let picks = [possible_shadower, possible_shadowed];
+            let sources = picks.iter().map(|p| self.candidate_source_from_pick(p)).collect();
+            let import_ids = picks
+                .iter()
+                .filter(|p| matches!(p.kind, TraitPick))
+                .map(|p| (p.item.container_id(self.tcx), p.import_ids.clone()))
                 .collect();
-            return Err(MethodError::Ambiguity(sources));
+            return Err(MethodError::Ambiguity(sources, import_ids));
         }
         Ok(())
     }
@@ -1738,7 +1741,12 @@ fn consider_candidates(
 
             let sources =
                 applicable_candidates.iter().map(|p| self.candidate_source(p.0, self_ty)).collect();
-            return Some(Err(MethodError::Ambiguity(sources)));
+            let import_ids = applicable_candidates
+                .iter()
+                .filter(|p| matches!(p.0.kind, TraitCandidate(_)))
+                .map(|p| (p.0.item.container_id(self.tcx), p.0.import_ids.clone()))
+                .collect();
+            return Some(Err(MethodError::Ambiguity(sources, import_ids)));
         }
 
         applicable_candidates.pop().map(|(probe, status)| match status {
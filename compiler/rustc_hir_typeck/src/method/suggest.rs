@@ -16,7 +16,7 @@
 use rustc_errors::{Applicability, Diag, MultiSpan, StashKey, pluralize, struct_span_code_err};
 use rustc_hir::attrs::AttributeKind;
 use rustc_hir::def::{CtorKind, DefKind, Res};
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{
@@ -31,6 +31,7 @@
 };
 use rustc_middle::ty::{self, GenericArgKind, IsSuggestable, Ty, TyCtxt, TypeVisitableExt};
 use rustc_span::def_id::DefIdSet;
+use rustc_span::edition::Edition;
 use rustc_span::{
     DUMMY_SP, ErrorGuaranteed, ExpnKind, FileName, Ident, MacroKind, Span, Symbol, edit_distance,
     kw, sym,
@@ -42,6 +43,7 @@
 use rustc_trait_selection::traits::{
     FulfillmentError, Obligation, ObligationCauseCode, supertraits,
 };
+use smallvec::SmallVec;
 use tracing::{debug, info, instrument};
 
 use super::probe::{AutorefOrPtrAdjustment, IsSuggestion, Mode, ProbeScope};
@@ -218,7 +220,7 @@ pub(crate) fn report_method_error(
                 within_macro_span,
             ),
 
-            MethodError::Ambiguity(mut sources) => {
+            MethodError::Ambiguity(mut sources, import_ids) => {
                 let mut err = struct_span_code_err!(
                     self.dcx(),
                     item_name.span,
@@ -240,6 +242,7 @@ pub(crate) fn report_method_error(
                     &mut sources,
                     Some(expr_span),
                 );
+                self.note_ambiguous_trait_imports(&mut err, &sources, &import_ids);
                 err.emit()
             }
 
@@ -2301,6 +2304,92 @@ fn note_candidates_on_method_error(
         }
     }
 
+    /// Points out how the traits of ambiguous candidates are in scope if the ambiguity is
+    /// caused by a trait from the prelude colliding with an imported trait, or by traits
+    /// from multiple glob imports, as these are easy to miss.
+    ///
+    /// This expects `sources` to already be sorted by `note_candidates_on_method_error`,
+    /// so that the candidate numbers match.
+    fn note_ambiguous_trait_imports(
+        &self,
+        err: &mut Diag<'_>,
+        sources: &[CandidateSource],
+        import_ids: &FxIndexMap<DefId, SmallVec<[LocalDefId; 1]>>,
+    ) {
+        if sources.len() < 2 {
+            return;
+        }
+        let limit = if sources.len() == 5 { 5 } else { 4 };
+        let in_scope_traits: Vec<_> = sources
+            .iter()
+            .take(limit)
+            .enumerate()
+            .filter_map(|(idx, source)| {
+                let trait_did = match *source {
+                    CandidateSource::Trait(trait_did) => trait_did,
+                    CandidateSource::Impl(impl_did) => self.tcx.impl_opt_trait_id(impl_did)?,
+                };
+                Some((idx, trait_did, import_ids.get(&trait_did)?.as_slice()))
+            })
+            .collect();
+        // Traits which are neither imported nor defined in this crate can only be in
+        // scope through the prelude.
+        let is_prelude = |trait_did: DefId, import_ids: &[LocalDefId]| {
+            import_ids.is_empty() && !trait_did.is_local()
+        };
+        let is_glob = |import_ids: &[LocalDefId]| {
+            import_ids.first().is_some_and(|&import_id| {
+                matches!(
+                    self.tcx.hir_node_by_def_id(import_id),
+                    hir::Node::Item(hir::Item {
+                        kind: hir::ItemKind::Use(_, hir::UseKind::Glob),
+                        ..
+                    })
+                )
+            })
+        };
+        let has_prelude = in_scope_traits.iter().any(|&(_, did, ids)| is_prelude(did, ids));
+        let has_import = in_scope_traits.iter().any(|&(_, _, ids)| !ids.is_empty());
+        let glob_imports = in_scope_traits.iter().filter(|&&(_, _, ids)| is_glob(ids)).count();
+        if !(has_prelude && has_import) && glob_imports < 2 {
+            return;
+        }
+
+        for (idx, trait_did, import_ids) in in_scope_traits {
+            let trait_path = self.tcx.def_path_str(trait_did);
+            match import_ids {
+                [] if is_prelude(trait_did, import_ids) => {
+                    err.note(format!(
+                        "the trait `{trait_path}` of candidate #{} is in scope through the prelude",
+                        idx + 1,
+                    ));
+                }
+                [] => {}
+                [import_id, reexport_ids @ ..] => {
+                    let mut span = MultiSpan::from_span(self.tcx.def_span(*import_id));
+                    span.push_span_label(
+                        self.tcx.def_span(*import_id),
+                        format!("`{trait_path}` is imported here"),
+                    );
+                    for &reexport_id in reexport_ids {
+                        span.push_span_label(
+                            self.tcx.def_span(reexport_id),
+                            format!("`{trait_path}` is re-exported here"),
+                        );
+                    }
+                    err.span_note(
+                        span,
+                        format!(
+                            "the trait `{trait_path}` of candidate #{} is in scope because of \
+                             this import",
+                            idx + 1,
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
     /// Look at all the associated functions without receivers in the type's inherent impls
     /// to look for builders that return `Self`, `Option<Self>` or `Result<Self, _>`.
     fn find_builder_fn(&self, err: &mut Diag<'_>, rcvr_ty: Ty<'tcx>, expr_id: hir::HirId) {
@@ -3737,10 +3826,17 @@ fn suggest_valid_traits(
             candidates.dedup();
 
             // `TryFrom` and `FromIterator` have no methods
-            let edition_fix = candidates
-                .iter()
-                .find(|did| self.tcx.is_diagnostic_item(sym::TryInto, **did))
-                .copied();
+            let edition_fix = candidates.iter().find_map(|&did| {
+                if self.tcx.is_diagnostic_item(sym::TryInto, did) {
+                    Some((did, Edition::Edition2021))
+                } else if self.tcx.is_diagnostic_item(sym::IntoFuture, did)
+                    || self.tcx.is_lang_item(did, LangItem::Future)
+                {
+                    Some((did, Edition::Edition2024))
+                } else {
+                    None
+                }
+            });
 
             if explain {
                 err.help("items from traits can only be used if the trait is in scope");
@@ -3792,9 +3888,9 @@ fn suggest_valid_traits(
                 }
             });
 
-            if let Some(did) = edition_fix {
+            if let Some((did, edition)) = edition_fix {
                 err.note(format!(
-                    "'{}' is included in the prelude starting in Edition 2021",
+                    "'{}' is included in the prelude starting in Edition {edition}",
                     with_crate_prefix!(self.tcx.def_path_str(did))
                 ));
             }
@@ -3880,7 +3976,7 @@ fn suggest_traits_to_import(
                         }
                         break;
                     }
-                    Err(MethodError::Ambiguity(_)) => {
+                    Err(MethodError::Ambiguity(..)) => {
                         // If the method is defined (but ambiguous) for the receiver we have, it is also
                         // likely we haven't `use`d it. It may be possible that if we `Box`/`Pin`/etc.
                         // the receiver, then it might disambiguate this method, but I think these
@@ -83,6 +83,8 @@ pub fn fork(&self) -> Self {
             universe: self.universe.clone(),
             next_trait_solver: self.next_trait_solver,
             obligation_inspector: self.obligation_inspector.clone(),
+            error_refinement_goals: self.error_refinement_goals.clone(),
+            obligation_shuffle_rng: self.obligation_shuffle_rng.clone(),
         }
     }
 
@@ -108,6 +110,8 @@ pub fn fork_with_typing_mode(&self, typing_mode: TypingMode<'tcx>) -> Self {
             universe: self.universe.clone(),
             next_trait_solver: self.next_trait_solver,
             obligation_inspector: self.obligation_inspector.clone(),
+            error_refinement_goals: self.error_refinement_goals.clone(),
+            obligation_shuffle_rng: self.obligation_shuffle_rng.clone(),
         };
         forked.inner.borrow_mut().projection_cache().clear();
         forked
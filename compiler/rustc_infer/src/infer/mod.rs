@@ -7,6 +7,9 @@
 use lexical_region_resolve::LexicalRegionResolutions;
 pub use lexical_region_resolve::RegionResolutionError;
 pub use opaque_types::{OpaqueTypeStorage, OpaqueTypeStorageEntries, OpaqueTypeTable};
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_xoshiro::Xoshiro128StarStar;
 use region_constraints::{
     GenericKind, RegionConstraintCollector, RegionConstraintStorage, VarInfos, VerifyBound,
 };
@@ -309,6 +312,14 @@ pub struct InferCtxt<'tcx> {
     next_trait_solver: bool,
 
     pub obligation_inspector: Cell<Option<ObligationInspector<'tcx>>>,
+
+    /// `-Znext-solver`: The number of goals visited so far while refining
+    /// fulfillment errors, see `-Zerror-refinement-budget`.
+    pub error_refinement_goals: Cell<usize>,
+
+    /// `-Zshuffle-obligations`: The random number generator used to shuffle pending
+    /// obligations and candidates, see [`InferCtxt::shuffle_if_requested`].
+    obligation_shuffle_rng: RefCell<Option<Xoshiro128StarStar>>,
 }
 
 /// See the `error_reporting` module for more details.
@@ -600,6 +611,10 @@ pub fn build(&mut self, typing_mode: TypingMode<'tcx>) -> InferCtxt<'tcx> {
             universe: Cell::new(ty::UniverseIndex::ROOT),
             next_trait_solver,
             obligation_inspector: Cell::new(None),
+            error_refinement_goals: Cell::new(0),
+            obligation_shuffle_rng: RefCell::new(
+                tcx.sess.obligation_shuffle_seed.map(Xoshiro128StarStar::seed_from_u64),
+            ),
         }
     }
 }
@@ -1473,6 +1488,16 @@ pub fn attach_obligation_inspector(&self, inspector: ObligationInspector<'tcx>)
         );
         self.obligation_inspector.set(Some(inspector));
     }
+
+    /// Randomly reorders `values` if `-Zshuffle-obligations` is enabled.
+    ///
+    /// This is used to find places where the behavior of the trait solver
+    /// depends on the order in which obligations or candidates are processed.
+    pub fn shuffle_if_requested<T>(&self, values: &mut [T]) {
+        if let Some(rng) = &mut *self.obligation_shuffle_rng.borrow_mut() {
+            values.shuffle(rng);
+        }
+    }
 }
 
 /// Helper for [InferCtxt::ty_or_const_infer_var_changed] (see comment on that), currently
@@ -1275,6 +1275,10 @@ pub(crate) fn start_codegen<'tcx>(
         tcx.sess.code_stats.print_type_sizes();
     }
 
+    if tcx.sess.opts.unstable_opts.dump_solver_cache {
+        solve::dump_evaluation_cache(tcx);
+    }
+
     (codegen, metadata)
 }
 
@@ -11,13 +11,13 @@
 use rustc_hir::attrs::NativeLibKind;
 use rustc_session::config::{
     AnnotateMoves, AutoDiff, BranchProtection, CFGuard, Cfg, CollapseMacroDebuginfo, CoverageLevel,
-    CoverageOptions, DebugInfo, DumpMonoStatsFormat, ErrorOutputType, ExternEntry, ExternLocation,
-    Externs, FmtDebug, FunctionReturn, InliningThreshold, Input, InstrumentCoverage,
-    InstrumentXRay, LinkSelfContained, LinkerPluginLto, LocationDetail, LtoCli, MirIncludeSpans,
-    NextSolverConfig, Offload, Options, OutFileName, OutputType, OutputTypes, PAuthKey, PacRet,
-    Passes, PatchableFunctionEntry, Polonius, ProcMacroExecutionStrategy, Strip, SwitchWithOptPath,
-    SymbolManglingVersion, WasiExecModel, build_configuration, build_session_options,
-    rustc_optgroups,
+    CoverageOptions, DebugInfo, DumpMonoStatsFormat, ErrorOutputType, ExplainGoal, ExternEntry,
+    ExternLocation, Externs, FmtDebug, FunctionReturn, InliningThreshold, Input,
+    InstrumentCoverage, InstrumentXRay, LinkSelfContained, LinkerPluginLto, LocationDetail, LtoCli,
+    MirIncludeSpans, NextSolverConfig, Offload, Options, OutFileName, OutputType, OutputTypes,
+    PAuthKey, PacRet, Passes, PatchableFunctionEntry, Polonius, ProcMacroExecutionStrategy, Strip,
+    SwitchWithOptPath, SymbolManglingVersion, WasiExecModel, build_configuration,
+    build_session_options, rustc_optgroups,
 };
 use rustc_session::lint::Level;
 use rustc_session::search_paths::SearchPath;
@@ -693,6 +693,7 @@ macro_rules! untracked {
     untracked!(codegen_source_order, true);
     untracked!(deduplicate_diagnostics, false);
     untracked!(dump_dep_graph, true);
+    untracked!(dump_goal_env, true);
     untracked!(dump_mir, Some(String::from("abc")));
     untracked!(dump_mir_dataflow, true);
     untracked!(dump_mir_dir, String::from("abc"));
@@ -701,8 +702,14 @@ macro_rules! untracked {
     untracked!(dump_mir_graphviz, true);
     untracked!(dump_mono_stats, SwitchWithOptPath::Enabled(Some("mono-items-dir/".into())));
     untracked!(dump_mono_stats_format, DumpMonoStatsFormat::Json);
+    untracked!(dump_proof_tree, vec![String::from("foo::bar")]);
+    untracked!(dump_solver_cache, true);
     untracked!(dylib_lto, true);
     untracked!(emit_stack_sizes, true);
+    untracked!(
+        explain_goal,
+        Some(ExplainGoal { predicate: String::from("u8: Clone"), file: None, line: 1, col: 1 })
+    );
     untracked!(future_incompat_test, true);
     untracked!(identify_regions, true);
     untracked!(incremental_info, true);
@@ -743,6 +750,7 @@ macro_rules! untracked {
     untracked!(time_passes_format, TimePassesFormat::Json);
     untracked!(trace_macros, true);
     untracked!(track_diagnostics, true);
+    untracked!(trait_solver_progress_interval, 100);
     untracked!(trim_diagnostic_paths, false);
     untracked!(ui_testing, true);
     untracked!(unpretty, Some("expanded".to_string()));
@@ -799,6 +807,7 @@ macro_rules! tracked {
     tracked!(embed_source, true);
     tracked!(emit_thin_lto, false);
     tracked!(emscripten_wasm_eh, false);
+    tracked!(error_refinement_budget, 100);
     tracked!(export_executable_symbols, true);
     tracked!(fewer_names, Some(true));
     tracked!(fixed_x18, true);
@@ -832,6 +841,8 @@ macro_rules! tracked {
     tracked!(move_size_limit, Some(4096));
     tracked!(mutable_noalias, false);
     tracked!(next_solver, NextSolverConfig { coherence: true, globally: true });
+    tracked!(next_solver_infer_outlives, true);
+    tracked!(next_solver_memory_limit, 1);
     tracked!(no_generate_arange_section, true);
     tracked!(no_link, true);
     tracked!(no_profiler_runtime, true);
@@ -867,8 +878,12 @@ macro_rules! tracked {
     tracked!(sanitizer_recover, SanitizerSet::ADDRESS);
     tracked!(saturating_float_casts, Some(true));
     tracked!(share_generics, Some(true));
+    tracked!(shuffle_obligations, true);
+    tracked!(shuffle_obligations_seed, Some(42));
+    tracked!(shuffle_obligations_verify, true);
     tracked!(simulate_remapped_rust_src_base, Some(PathBuf::from("/rustc/abc")));
     tracked!(small_data_threshold, Some(16));
+    tracked!(solver_validation, true);
     tracked!(split_lto_unit, Some(true));
     tracked!(src_hash_algorithm, Some(SourceFileHashAlgorithm::Sha1));
     tracked!(stack_protector, StackProtector::All);
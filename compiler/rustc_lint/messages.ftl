@@ -90,6 +90,12 @@ lint_builtin_export_name_static = declaration of a static with `export_name`
 lint_builtin_global_asm = usage of `core::arch::global_asm`
 lint_builtin_global_macro_unsafety = using this macro is unsafe even though it does not need an `unsafe` block
 
+lint_builtin_global_where_clause_always_holds = the trait bound `{$predicate}` always holds
+    .suggestion = remove the bound
+
+lint_builtin_global_where_clause_never_holds = the trait bound `{$predicate}` can never hold
+    .note = this item can never be used
+
 lint_builtin_impl_unsafe_method = implementation of an `unsafe` method
 
 lint_builtin_incomplete_features = the feature `{$name}` is incomplete and may not be safe to use and/or cause compiler crashes
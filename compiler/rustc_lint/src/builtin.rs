@@ -53,13 +53,13 @@
     BuiltinAnonymousParams, BuiltinConstNoMangle, BuiltinDerefNullptr, BuiltinDoubleNegations,
     BuiltinDoubleNegationsAddParens, BuiltinEllipsisInclusiveRangePatternsLint,
     BuiltinExplicitOutlives, BuiltinExplicitOutlivesSuggestion, BuiltinFeatureIssueNote,
-    BuiltinIncompleteFeatures, BuiltinIncompleteFeaturesHelp, BuiltinInternalFeatures,
-    BuiltinKeywordIdents, BuiltinMissingCopyImpl, BuiltinMissingDebugImpl, BuiltinMissingDoc,
-    BuiltinMutablesTransmutes, BuiltinNoMangleGeneric, BuiltinNonShorthandFieldPatterns,
-    BuiltinSpecialModuleNameUsed, BuiltinTrivialBounds, BuiltinTypeAliasBounds,
-    BuiltinUngatedAsyncFnTrackCaller, BuiltinUnpermittedTypeInit, BuiltinUnpermittedTypeInitSub,
-    BuiltinUnreachablePub, BuiltinUnsafe, BuiltinUnstableFeatures, BuiltinUnusedDocComment,
-    BuiltinUnusedDocCommentSub, BuiltinWhileTrue, InvalidAsmLabel,
+    BuiltinGlobalWhereClause, BuiltinIncompleteFeatures, BuiltinIncompleteFeaturesHelp,
+    BuiltinInternalFeatures, BuiltinKeywordIdents, BuiltinMissingCopyImpl, BuiltinMissingDebugImpl,
+    BuiltinMissingDoc, BuiltinMutablesTransmutes, BuiltinNoMangleGeneric,
+    BuiltinNonShorthandFieldPatterns, BuiltinSpecialModuleNameUsed, BuiltinTrivialBounds,
+    BuiltinTypeAliasBounds, BuiltinUngatedAsyncFnTrackCaller, BuiltinUnpermittedTypeInit,
+    BuiltinUnpermittedTypeInitSub, BuiltinUnreachablePub, BuiltinUnsafe, BuiltinUnstableFeatures,
+    BuiltinUnusedDocComment, BuiltinUnusedDocCommentSub, BuiltinWhileTrue, InvalidAsmLabel,
 };
 use crate::{
     EarlyContext, EarlyLintPass, LateContext, LateLintPass, Level, LintContext,
@@ -1502,18 +1502,50 @@ fn visit_qpath(&mut self, qpath: &hir::QPath<'_>, id: hir::HirId, _: Span) {
     "these bounds don't depend on an type parameters"
 }
 
+declare_lint! {
+    /// The `global_where_clauses` lint detects trait bounds that don't depend
+    /// on any generic parameters and therefore either always or never hold.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(global_where_clauses)]
+    /// pub fn f() where String: Clone {}
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// A bound such as `String: Clone` is checked once, without looking at
+    /// any uses of the item. If it holds, it can be removed without changing
+    /// the meaning of the program. If it doesn't hold, the item can never be
+    /// used; such bounds are only accepted with the unstable `trivial_bounds`
+    /// feature, in which case this lint is emitted instead of the less
+    /// specific `trivial_bounds` lint.
+    pub GLOBAL_WHERE_CLAUSES,
+    Allow,
+    "detects trait bounds that don't depend on generic parameters"
+}
+
 declare_lint_pass!(
     /// Lint for trait and lifetime bounds that don't depend on type parameters
     /// which either do nothing, or stop the item from being used.
-    TrivialConstraints => [TRIVIAL_BOUNDS]
+    TrivialConstraints => [TRIVIAL_BOUNDS, GLOBAL_WHERE_CLAUSES]
 );
 
 impl<'tcx> LateLintPass<'tcx> for TrivialConstraints {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
         use rustc_middle::ty::ClauseKind;
 
-        if cx.tcx.features().trivial_bounds() {
+        let trivial_bounds = cx.tcx.features().trivial_bounds();
+        // Proving the bounds is only worth it if the lint is enabled.
+        let LevelAndSource { level, .. } =
+            cx.tcx.lint_level_at_node(GLOBAL_WHERE_CLAUSES, item.hir_id());
+        let check_global_bounds = level != Level::Allow;
+        if trivial_bounds || check_global_bounds {
             let predicates = cx.tcx.predicates_of(item.owner_id);
+            let infcx = cx.tcx.infer_ctxt().build(cx.typing_mode());
             for &(predicate, span) in predicates.predicates {
                 let predicate_kind_name = match predicate.kind().skip_binder() {
                     ClauseKind::Trait(..) => "trait",
@@ -1533,7 +1565,46 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
                     // Users don't write this directly, only via another trait ref.
                     | ty::ClauseKind::HostEffect(..) => continue,
                 };
-                if predicate.is_global() {
+                if !predicate.is_global() {
+                    continue;
+                }
+                if check_global_bounds && let ClauseKind::Trait(..) = predicate.kind().skip_binder()
+                {
+                    let obligation = traits::Obligation::new(
+                        cx.tcx,
+                        traits::ObligationCause::dummy(),
+                        ty::ParamEnv::empty(),
+                        predicate,
+                    );
+                    if infcx.predicate_must_hold_modulo_regions(&obligation) {
+                        let removal = item.kind.generics().and_then(|generics| {
+                            generics.predicates.iter().enumerate().find_map(|(pos, where_pred)| {
+                                let bound_pos = where_pred
+                                    .kind
+                                    .bounds()
+                                    .iter()
+                                    .position(|bound| bound.span() == span)?;
+                                Some(generics.span_for_bound_removal(pos, bound_pos))
+                            })
+                        });
+                        cx.emit_span_lint(
+                            GLOBAL_WHERE_CLAUSES,
+                            span,
+                            BuiltinGlobalWhereClause::AlwaysHolds { predicate, removal },
+                        );
+                        continue;
+                    }
+                    // Without `trivial_bounds`, unsatisfied global bounds are already an error.
+                    if trivial_bounds && !infcx.predicate_may_hold(&obligation) {
+                        cx.emit_span_lint(
+                            GLOBAL_WHERE_CLAUSES,
+                            span,
+                            BuiltinGlobalWhereClause::NeverHolds { predicate },
+                        );
+                        continue;
+                    }
+                }
+                if trivial_bounds {
                     cx.emit_span_lint(
                         TRIVIAL_BOUNDS,
                         span,
@@ -352,6 +352,19 @@ pub(crate) struct BuiltinTrivialBounds<'a> {
     pub predicate: Clause<'a>,
 }
 
+#[derive(LintDiagnostic)]
+pub(crate) enum BuiltinGlobalWhereClause<'a> {
+    #[diag(lint_builtin_global_where_clause_always_holds)]
+    AlwaysHolds {
+        predicate: Clause<'a>,
+        #[suggestion(code = "", applicability = "maybe-incorrect", style = "verbose")]
+        removal: Option<Span>,
+    },
+    #[diag(lint_builtin_global_where_clause_never_holds)]
+    #[note]
+    NeverHolds { predicate: Clause<'a> },
+}
+
 #[derive(LintDiagnostic)]
 #[diag(lint_builtin_double_negations)]
 #[note(lint_note)]
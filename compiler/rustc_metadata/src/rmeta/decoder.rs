@@ -1378,6 +1378,11 @@ fn get_item_attrs(self, tcx: TyCtxt<'_>, id: DefIndex) -> impl Iterator<Item = h
             .attributes
             .get((self, tcx), id)
             .unwrap_or_else(|| {
+                // Type parameters only have their attributes encoded if they are marked
+                // `#[diagnostic::inferred_from_self]`.
+                if self.def_kind(tcx, id) == DefKind::TyParam {
+                    return LazyArray::default();
+                }
                 // Structure and variant constructors don't have any attributes encoded for them,
                 // but we assume that someone passing a constructor ID actually wants to look at
                 // the attributes on the corresponding struct or variant.
@@ -1467,6 +1467,10 @@ fn encode_def_ids(&mut self) {
             }
             if should_encode_attrs(def_kind) {
                 self.encode_attrs(local_id);
+            } else if def_kind == DefKind::TyParam && tcx.is_inferred_from_self(def_id) {
+                // Only type parameters that carry `#[diagnostic::inferred_from_self]`
+                // have their attributes encoded, see `get_item_attrs`.
+                self.encode_attrs(local_id);
             }
             if should_encode_expn_that_defined(def_kind) {
                 record!(self.tables.expn_that_defined[def_id] <- self.tcx.expn_that_defined(def_id));
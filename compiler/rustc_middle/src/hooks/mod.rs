@@ -86,6 +86,24 @@ fn clone(&self) -> Self { *self }
     /// of a normal function because external tools might want to override it.
     hook should_codegen_locally(instance: crate::ty::Instance<'tcx>) -> bool;
 
+    /// Reports the unsatisfied trait bound which prevents resolving the associated item
+    /// `def_id` with `args` to an instance. Used by `Instance::expect_resolve` to emit a
+    /// proper error for bounds which only fail after monomorphization.
+    ///
+    /// Returns `None` if no such bound could be found.
+    hook report_unresolved_assoc_item(
+        typing_env: crate::ty::TypingEnv<'tcx>,
+        def_id: DefId,
+        args: crate::ty::GenericArgsRef<'tcx>,
+        span: rustc_span::Span
+    ) -> Option<rustc_span::ErrorGuaranteed>;
+
+    /// Called by the trait solver every `-Ztrait-solver-progress-interval` solved goals,
+    /// with the total number of goals solved so far. External tools like IDEs can override
+    /// this to cancel long-running trait solving by returning `ControlFlow::Break`, in which
+    /// case compilation is aborted with a fatal error.
+    hook trait_solver_progress(solved_goals: usize) -> std::ops::ControlFlow<()>;
+
     hook alloc_self_profile_query_strings() -> ();
 
     /// Saves and writes the DepGraph to the file system.
@@ -132,6 +132,13 @@ pub enum ConstraintCategory<'tcx> {
     /// with the given `DefId`
     Predicate(Span),
 
+    /// A constraint required to prove a user-written trait bound, e.g. `T: Trait<'a>`,
+    /// with the provided span. Like `Predicate`, this does not store the `DefId` of
+    /// the item the bound is written on to keep this type small.
+    ///
+    /// This is currently only used with the next-generation trait solver.
+    TraitBound(Span),
+
     /// A "boring" constraint (caused by the given location) is one that
     /// the user probably doesn't want to see described in diagnostics,
     /// because it is kind of an artifact of the type system setup.
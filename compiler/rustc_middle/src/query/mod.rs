@@ -2572,6 +2572,30 @@
         }
     }
 
+    /// Reports an error if the where-clauses of `key.0` require different types for the
+    /// projection of the associated type `key.1` with the generic arguments `key.2`.
+    ///
+    /// This is used when reporting ambiguity errors. It is a query so that each conflict is
+    /// only reported once, even if it causes ambiguities both when checking the item's
+    /// well-formedness and when type checking its body.
+    query check_conflicting_projection_bounds(
+        key: (LocalDefId, DefId, GenericArgsRef<'tcx>)
+    ) -> Result<(), ErrorGuaranteed> {
+        desc { |tcx|
+            "checking the where-clauses of `{}` for conflicting requirements on `{}`",
+            tcx.def_path_str(key.0),
+            tcx.def_path_str_with_args(key.1, key.2),
+        }
+    }
+
+    /// Maps the def path of every trait in the crate graph, printed without trimmed or visible
+    /// paths, to the trait's `DefId`. This is used to resolve the traits named in the
+    /// `implements(..)` conditions of `#[rustc_on_unimplemented]`.
+    query traits_by_def_path(_: ()) -> &'tcx UnordMap<Symbol, DefId> {
+        arena_cache
+        desc { "mapping the def paths of all traits to their `DefId`s" }
+    }
+
     query method_autoderef_steps(
         goal: CanonicalMethodAutoderefStepsGoal<'tcx>
     ) -> MethodAutoderefStepsResult<'tcx> {
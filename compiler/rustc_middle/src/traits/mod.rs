@@ -638,6 +638,9 @@ pub enum SelectionError<'tcx> {
     OpaqueTypeAutoTraitLeakageUnknown(DefId),
     /// Error for a `ConstArgHasType` goal
     ConstArgHasWrongType { ct: ty::Const<'tcx>, ct_ty: Ty<'tcx>, expected_ty: Ty<'tcx> },
+    /// The trait is not implemented, but it would be implemented by a builtin
+    /// impl if the given unstable feature were enabled.
+    FeatureGated(Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, TypeVisitable)]
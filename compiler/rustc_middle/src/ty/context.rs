@@ -364,6 +364,14 @@ fn recursion_limit(self) -> usize {
         self.recursion_limit().0
     }
 
+    fn solver_memory_limit(self) -> usize {
+        self.sess.opts.unstable_opts.next_solver_memory_limit.saturating_mul(1024 * 1024)
+    }
+
+    fn solver_validation(self) -> bool {
+        self.sess.opts.unstable_opts.solver_validation
+    }
+
     type Features = &'tcx rustc_feature::Features;
 
     fn features(self) -> Self::Features {
@@ -560,10 +568,8 @@ fn for_each_relevant_impl(
         let tcx = self;
         let trait_impls = tcx.trait_impls_of(trait_def_id);
         let mut consider_impls_for_simplified_type = |simp| {
-            if let Some(impls_for_type) = trait_impls.non_blanket_impls().get(&simp) {
-                for &impl_def_id in impls_for_type {
-                    f(impl_def_id);
-                }
+            for &impl_def_id in trait_impls.impls_for_simplified_self_ty(&simp) {
+                f(impl_def_id);
             }
         };
 
@@ -3541,6 +3547,11 @@ pub fn do_not_recommend_impl(self, def_id: DefId) -> bool {
         self.get_diagnostic_attr(def_id, sym::do_not_recommend).is_some()
     }
 
+    /// Whether this is a trait type parameter that has `#[diagnostic::inferred_from_self]`
+    pub fn is_inferred_from_self(self, def_id: DefId) -> bool {
+        self.get_diagnostic_attr(def_id, sym::inferred_from_self).is_some()
+    }
+
     pub fn is_trivial_const<P>(self, def_id: P) -> bool
     where
         P: IntoQueryParam<DefId>,
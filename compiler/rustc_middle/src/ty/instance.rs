@@ -2,7 +2,7 @@
 use std::fmt;
 
 use rustc_data_structures::fx::FxHashMap;
-use rustc_errors::ErrorGuaranteed;
+use rustc_errors::{ErrorGuaranteed, FatalError};
 use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, DefKind, Namespace};
 use rustc_hir::def_id::{CrateNum, DefId};
@@ -575,6 +575,19 @@ pub fn expect_resolve(
                         instance: Instance::new_raw(def_id, args),
                         type_length,
                     });
+                } else if tcx.trait_of_assoc(def_id).is_some()
+                    && tcx
+                        .report_unresolved_assoc_item(
+                            typing_env,
+                            def_id,
+                            args,
+                            span_or_local_def_span(),
+                        )
+                        .is_some()
+                {
+                    // A trait bound which only fails after monomorphization,
+                    // e.g. due to a trivially false where-clause.
+                    FatalError.raise()
                 } else {
                     span_bug!(
                         span_or_local_def_span(),
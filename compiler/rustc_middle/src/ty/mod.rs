@@ -1836,7 +1836,7 @@ pub fn get_diagnostic_attr(
         if did.as_local().is_some() {
             // it's a crate local item, we need to check feature flags
             if rustc_feature::is_stable_diagnostic_attribute(attr, self.features()) {
-                self.get_attrs_by_path(did, &[sym::diagnostic, sym::do_not_recommend]).next()
+                self.get_attrs_by_path(did, &[sym::diagnostic, attr]).next()
             } else {
                 None
             }
@@ -3,6 +3,7 @@
 use rustc_data_structures::fx::FxIndexMap;
 use rustc_errors::ErrorGuaranteed;
 use rustc_hir as hir;
+use rustc_hir::LangItem;
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_macros::{Decodable, Encodable, HashStable};
@@ -12,7 +13,7 @@
 use crate::query::LocalCrate;
 use crate::traits::specialization_graph;
 use crate::ty::fast_reject::{self, SimplifiedType, TreatParams};
-use crate::ty::{Ident, Ty, TyCtxt};
+use crate::ty::{self, Ident, Ty, TyCtxt};
 
 /// A trait's definition with type information.
 #[derive(HashStable, Encodable, Decodable)]
@@ -117,6 +118,24 @@ pub fn blanket_impls(&self) -> &[DefId] {
     pub fn non_blanket_impls(&self) -> &FxIndexMap<SimplifiedType, Vec<DefId>> {
         &self.non_blanket_impls
     }
+
+    /// Returns the non-blanket impls whose self type simplifies to exactly `simp`.
+    pub fn impls_for_simplified_self_ty(&self, simp: &SimplifiedType) -> &[DefId] {
+        self.non_blanket_impls.get(simp).map_or(&[], |impls| impls.as_slice())
+    }
+
+    /// Iterates over the non-blanket impls whose simplified self type satisfies
+    /// `matches_shape`. The predicate is only called once per simplified type,
+    /// not once per impl.
+    pub fn non_blanket_impls_matching(
+        &self,
+        mut matches_shape: impl FnMut(SimplifiedType) -> bool,
+    ) -> impl Iterator<Item = DefId> {
+        self.non_blanket_impls
+            .iter()
+            .filter(move |(simp, _)| matches_shape(**simp))
+            .flat_map(|(_, impls)| impls.iter().copied())
+    }
 }
 
 impl<'tcx> TraitDef {
@@ -157,10 +176,8 @@ pub fn for_each_relevant_impl(
         // Note that we're using `TreatParams::AsRigid` to query `non_blanket_impls` while using
         // `TreatParams::InstantiateWithInfer` while actually adding them.
         if let Some(simp) = fast_reject::simplify_type(self, self_ty, TreatParams::AsRigid) {
-            if let Some(impls) = impls.non_blanket_impls.get(&simp) {
-                for &impl_def_id in impls {
-                    f(impl_def_id);
-                }
+            for &impl_def_id in impls.impls_for_simplified_self_ty(&simp) {
+                f(impl_def_id);
             }
         } else {
             for &impl_def_id in impls.non_blanket_impls.values().flatten() {
@@ -179,14 +196,46 @@ pub fn non_blanket_impls_for_ty(
         if let Some(simp) =
             fast_reject::simplify_type(self, self_ty, TreatParams::InstantiateWithInfer)
         {
-            if let Some(impls) = impls.non_blanket_impls.get(&simp) {
-                return impls.iter().copied();
-            }
+            return impls.impls_for_simplified_self_ty(&simp).iter().copied();
         }
 
         [].iter().copied()
     }
 
+    /// Returns the impls of `trait_def_id` whose self type has roughly the same shape
+    /// as `self_ty`, ignoring references, generic arguments and the exact kind of
+    /// numeric types. Blanket impls are always returned.
+    ///
+    /// This is meant for diagnostics which look for "similar" impls, and returns a
+    /// superset of the impls they end up mentioning: the shapes mirror the type
+    /// categories of `fuzzy_match_tys` in `rustc_trait_selection`, so filtering by
+    /// them never drops an impl which that function considers similar. Unlike
+    /// `for_each_relevant_impl`, this does not look at every impl of the trait unless
+    /// `self_ty` cannot be simplified.
+    ///
+    /// `trait_def_id` MUST BE the `DefId` of a trait.
+    pub fn impls_with_similar_self_ty(
+        self,
+        trait_def_id: DefId,
+        self_ty: Ty<'tcx>,
+    ) -> impl Iterator<Item = DefId> {
+        let impls = self.trait_impls_of(trait_def_id);
+        let mut self_ty = self_ty;
+        while let ty::Ref(_, inner, _) | ty::RawPtr(inner, _) = *self_ty.kind() {
+            self_ty = inner;
+        }
+        let shape = fast_reject::simplify_type(self, self_ty, TreatParams::InstantiateWithInfer)
+            .map(|simp| FuzzyShape::of(self, simp));
+        impls.blanket_impls.iter().copied().chain(impls.non_blanket_impls_matching(move |simp| {
+            match (shape, FuzzyShape::of(self, simp)) {
+                // We have no idea what the self type looks like, or the impl is for
+                // some reference whose pointee could be anything.
+                (None, _) | (_, FuzzyShape::Indirection) => true,
+                (Some(shape), impl_shape) => shape == impl_shape,
+            }
+        }))
+    }
+
     /// Returns an iterator containing all impls for `trait_def_id`.
     ///
     /// `trait_def_id` MUST BE the `DefId` of a trait.
@@ -197,6 +246,58 @@ pub fn all_impls(self, trait_def_id: DefId) -> impl Iterator<Item = DefId> {
     }
 }
 
+/// A coarser version of [`SimplifiedType`] used to find impls for "similar" self types.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FuzzyShape {
+    /// `str` and `String`.
+    String,
+    /// Any integer or float type.
+    Numeric,
+    /// References and raw pointers, whose pointee we don't know.
+    Indirection,
+    /// Arrays and slices.
+    Sequence,
+    /// Tuples of any arity.
+    Tuple,
+    /// Function definitions, function pointers and closures.
+    Callable,
+    /// Trait objects, regardless of their principal trait.
+    TraitObject,
+    Coroutine,
+    CoroutineWitness,
+    /// Types which are only similar to types with the same simplified type.
+    Exact(SimplifiedType),
+}
+
+impl FuzzyShape {
+    fn of(tcx: TyCtxt<'_>, simp: SimplifiedType) -> FuzzyShape {
+        match simp {
+            SimplifiedType::Str => FuzzyShape::String,
+            SimplifiedType::Adt(def_id) if tcx.is_lang_item(def_id, LangItem::String) => {
+                FuzzyShape::String
+            }
+            SimplifiedType::Int(_) | SimplifiedType::Uint(_) | SimplifiedType::Float(_) => {
+                FuzzyShape::Numeric
+            }
+            SimplifiedType::Ref(_) | SimplifiedType::Ptr(_) => FuzzyShape::Indirection,
+            SimplifiedType::Array | SimplifiedType::Slice => FuzzyShape::Sequence,
+            SimplifiedType::Tuple(_) => FuzzyShape::Tuple,
+            SimplifiedType::Function(_) | SimplifiedType::Closure(_) => FuzzyShape::Callable,
+            SimplifiedType::Trait(_) | SimplifiedType::MarkerTraitObject => FuzzyShape::TraitObject,
+            SimplifiedType::Coroutine(_) => FuzzyShape::Coroutine,
+            SimplifiedType::CoroutineWitness(_) => FuzzyShape::CoroutineWitness,
+            SimplifiedType::Bool
+            | SimplifiedType::Char
+            | SimplifiedType::Adt(_)
+            | SimplifiedType::Foreign(_)
+            | SimplifiedType::Never
+            | SimplifiedType::UnsafeBinder
+            | SimplifiedType::Placeholder
+            | SimplifiedType::Error => FuzzyShape::Exact(simp),
+        }
+    }
+}
+
 /// Query provider for `trait_impls_of`.
 pub(super) fn trait_impls_of_provider(tcx: TyCtxt<'_>, trait_id: DefId) -> TraitImpls {
     let mut impls = TraitImpls::default();
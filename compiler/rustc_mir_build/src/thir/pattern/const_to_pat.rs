@@ -18,8 +18,8 @@
 };
 use rustc_span::def_id::DefId;
 use rustc_span::{DUMMY_SP, Span};
-use rustc_trait_selection::traits::ObligationCause;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt;
+use rustc_trait_selection::traits::{ObligationCause, ObligationCtxt};
 use tracing::{debug, instrument, trace};
 
 use super::PatCtxt;
@@ -430,7 +430,23 @@ fn visit_ty(&mut self, ty: Ty<'tcx>) -> Self::Result {
         manual: FxHashSet::default(),
         without: FxHashSet::default(),
     };
-    if v.visit_ty(ty).is_break() {
+    // Only look at the types whose bounds actually failed to hold, e.g. only `A` and not `B`
+    // for `(Option<A>, PhantomData<B>)`.
+    let mut visited = false;
+    for leaf in partial_eq_failure_leaves(tcx, typing_env, ty) {
+        if tcx.is_lang_item(leaf.def_id(), hir::LangItem::PartialEq) {
+            if v.visit_ty(leaf.self_ty()).is_break() {
+                return;
+            }
+        } else {
+            err.note(format!(
+                "`{ty}` does not implement `PartialEq` because the trait bound `{leaf}` is not \
+                 satisfied"
+            ));
+        }
+        visited = true;
+    }
+    if !visited && v.visit_ty(ty).is_break() {
         return;
     }
     #[allow(rustc::potential_query_instability)] // Span labels will be sorted by the rendering
@@ -462,6 +478,33 @@ fn visit_ty(&mut self, ty: Ty<'tcx>) -> Self::Result {
     }
 }
 
+/// Uses the trait solver to find the nested trait bounds which make `ty: PartialEq` not hold.
+fn partial_eq_failure_leaves<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    typing_env: ty::TypingEnv<'tcx>,
+    ty: Ty<'tcx>,
+) -> Vec<ty::TraitPredicate<'tcx>> {
+    let (infcx, param_env) = tcx.infer_ctxt().build_with_typing_env(typing_env);
+    let ocx = ObligationCtxt::new_with_diagnostics(&infcx);
+    let partial_eq_trait_id = tcx.require_lang_item(hir::LangItem::PartialEq, DUMMY_SP);
+    ocx.register_obligation(Obligation::new(
+        tcx,
+        ObligationCause::dummy(),
+        param_env,
+        ty::TraitRef::new(tcx, partial_eq_trait_id, [ty, ty]),
+    ));
+    let mut leaves = vec![];
+    for error in ocx.evaluate_obligations_error_on_ambiguity() {
+        if let Some(pred) = error.obligation.predicate.as_trait_clause()
+            && let pred = infcx.resolve_vars_if_possible(pred.skip_binder())
+            && !leaves.contains(&pred)
+        {
+            leaves.push(pred);
+        }
+    }
+    leaves
+}
+
 #[derive(Debug)]
 struct PartialEqImplStatus {
     has_impl: bool,
@@ -90,4 +90,8 @@ fn is_transmutable(
         src: <Self::Interner as Interner>::Ty,
         assume: <Self::Interner as Interner>::Const,
     ) -> Result<Certainty, NoSolution>;
+
+    /// Randomly reorders the assembled candidates of a goal, if requested.
+    /// This is used to detect order-dependence when merging candidates.
+    fn shuffle_candidates<T>(&self, candidates: &mut [T]);
 }
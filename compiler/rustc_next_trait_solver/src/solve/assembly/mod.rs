@@ -6,6 +6,7 @@
 use std::ops::ControlFlow;
 
 use derive_where::derive_where;
+use rustc_type_ir::data_structures::HashSet;
 use rustc_type_ir::inherent::*;
 use rustc_type_ir::lang_items::SolverTraitLangItem;
 use rustc_type_ir::search_graph::CandidateHeadUsages;
@@ -468,9 +469,33 @@ pub(super) fn assemble_and_evaluate_candidates<G: GoalKind<D>>(
             }
         }
 
+        if self.cx().solver_validation() {
+            self.validate_assembled_candidates(goal, &candidates);
+        }
+
+        self.shuffle_candidates(&mut candidates);
         (candidates, failed_candidate_info)
     }
 
+    /// Checks that each impl has been considered at most once.
+    /// Only used with `-Zsolver-validation`.
+    fn validate_assembled_candidates<G: GoalKind<D>>(
+        &self,
+        goal: Goal<I, G>,
+        candidates: &[Candidate<I>],
+    ) {
+        let mut impls = HashSet::default();
+        for candidate in candidates {
+            if let CandidateSource::Impl(impl_def_id) = candidate.source
+                && !impls.insert(impl_def_id)
+            {
+                self.cx().delay_bug(format!(
+                    "impl candidate {impl_def_id:?} assembled multiple times for {goal:?}"
+                ));
+            }
+        }
+    }
+
     pub(super) fn forced_ambiguity(
         &mut self,
         cause: MaybeCause,
@@ -177,6 +177,17 @@ fn root_goal_may_hold_with_depth(
         goal: Goal<Self::Interner, <Self::Interner as Interner>::Predicate>,
     ) -> bool;
 
+    /// Evaluates `goal` with a depth of `root_depth` instead of the recursion
+    /// limit, discarding all inference constraints.
+    ///
+    /// This is used when reporting overflow errors to check whether increasing
+    /// the recursion limit would help.
+    fn probe_root_goal_with_depth(
+        &self,
+        root_depth: usize,
+        goal: Goal<Self::Interner, <Self::Interner as Interner>::Predicate>,
+    ) -> Result<Certainty, NoSolution>;
+
     // FIXME: This is only exposed because we need to use it in `analyse.rs`
     // which is not yet uplifted. Once that's done, we should remove this.
     fn evaluate_root_goal_for_proof_tree(
@@ -202,7 +213,9 @@ fn evaluate_root_goal(
         stalled_on: Option<GoalStalledOn<I>>,
     ) -> Result<GoalEvaluation<I>, NoSolution> {
         EvalCtxt::enter_root(self, self.cx().recursion_limit(), span, |ecx| {
-            ecx.evaluate_goal(GoalSource::Misc, goal, stalled_on)
+            let result = ecx.evaluate_goal(GoalSource::Misc, goal, stalled_on);
+            let exceeded_memory_limit = ecx.search_graph.exceeded_memory_limit();
+            result.map(|evaluation| GoalEvaluation { exceeded_memory_limit, ..evaluation })
         })
     }
 
@@ -238,6 +251,20 @@ fn root_goal_may_hold_with_depth(
         .is_ok()
     }
 
+    #[instrument(level = "debug", skip(self), ret)]
+    fn probe_root_goal_with_depth(
+        &self,
+        root_depth: usize,
+        goal: Goal<Self::Interner, <Self::Interner as Interner>::Predicate>,
+    ) -> Result<Certainty, NoSolution> {
+        self.probe(|| {
+            EvalCtxt::enter_root(self, root_depth, I::Span::dummy(), |ecx| {
+                ecx.evaluate_goal(GoalSource::Misc, goal, None)
+            })
+        })
+        .map(|r| r.certainty)
+    }
+
     #[instrument(level = "debug", skip(self))]
     fn evaluate_root_goal_for_proof_tree(
         &self,
@@ -313,7 +340,7 @@ pub(super) fn enter_root<R>(
         origin_span: I::Span,
         f: impl FnOnce(&mut EvalCtxt<'_, D>) -> R,
     ) -> R {
-        let mut search_graph = SearchGraph::new(root_depth);
+        let mut search_graph = SearchGraph::new(root_depth, delegate.cx().solver_memory_limit());
 
         let mut ecx = EvalCtxt {
             delegate,
@@ -455,6 +482,7 @@ pub(super) fn evaluate_goal_raw(
                     certainty: stalled_certainty,
                     has_changed: HasChanged::No,
                     stalled_on,
+                    exceeded_memory_limit: false,
                 },
             ));
         }
@@ -557,7 +585,13 @@ pub(super) fn evaluate_goal_raw(
 
         Ok((
             normalization_nested_goals,
-            GoalEvaluation { goal, certainty, has_changed, stalled_on },
+            GoalEvaluation {
+                goal,
+                certainty,
+                has_changed,
+                stalled_on,
+                exceeded_memory_limit: false,
+            },
         ))
     }
 
@@ -675,7 +709,13 @@ fn evaluate_added_goals_step(&mut self) -> Result<Option<Certainty>, NoSolution>
 
                 let (
                     NestedNormalizationGoals(nested_goals),
-                    GoalEvaluation { goal, certainty, stalled_on, has_changed: _ },
+                    GoalEvaluation {
+                        goal,
+                        certainty,
+                        stalled_on,
+                        has_changed: _,
+                        exceeded_memory_limit: _,
+                    },
                 ) = self.evaluate_goal_raw(source, unconstrained_goal, stalled_on)?;
                 // Add the nested goals from normalization to our own nested goals.
                 trace!(?nested_goals);
@@ -728,8 +768,13 @@ fn evaluate_added_goals_step(&mut self) -> Result<Option<Certainty>, NoSolution>
                     }
                 }
             } else {
-                let GoalEvaluation { goal, certainty, has_changed, stalled_on } =
-                    self.evaluate_goal(source, goal, stalled_on)?;
+                let GoalEvaluation {
+                    goal,
+                    certainty,
+                    has_changed,
+                    stalled_on,
+                    exceeded_memory_limit: _,
+                } = self.evaluate_goal(source, goal, stalled_on)?;
                 if has_changed == HasChanged::Yes {
                     unchanged_certainty = None;
                 }
@@ -1177,6 +1222,10 @@ pub(super) fn is_transmutable(
         self.delegate.is_transmutable(dst, src, assume)
     }
 
+    pub(super) fn shuffle_candidates<T>(&self, candidates: &mut [T]) {
+        self.delegate.shuffle_candidates(candidates)
+    }
+
     pub(super) fn replace_bound_vars<T: TypeFoldable<I>>(
         &self,
         t: T,
@@ -1308,9 +1357,32 @@ pub(in crate::solve) fn evaluate_added_goals_and_make_canonical_response(
             },
         );
 
+        if self.cx().solver_validation() {
+            self.validate_canonical_response(canonical);
+        }
+
         Ok(canonical)
     }
 
+    /// Checks that the canonicalized `response` does not refer to anything
+    /// local to the current query. Only used with `-Zsolver-validation`.
+    fn validate_canonical_response(&self, response: CanonicalResponse<I>) {
+        let cx = self.cx();
+        if response.value.has_infer() || response.value.has_placeholders() {
+            cx.delay_bug(format!("response is not fully canonicalized: {response:?}"));
+        }
+        if response.value.var_values.len() != self.variables.len() {
+            cx.delay_bug(format!(
+                "response has {} var values but the input has {} variables: {response:?}",
+                response.value.var_values.len(),
+                self.variables.len(),
+            ));
+        }
+        if response.variables.iter().any(|var| var.universe() > response.max_universe) {
+            cx.delay_bug(format!("response variable outside of its max universe: {response:?}"));
+        }
+    }
+
     /// Constructs a totally unconstrained, ambiguous response to a goal.
     ///
     /// Take care when using this, since often it's useful to respond with
@@ -1486,6 +1558,7 @@ pub fn evaluate_root_goal_for_proof_tree_raw_provider<
     let canonical_result = SearchGraph::<D>::evaluate_root_goal_for_proof_tree(
         cx,
         cx.recursion_limit(),
+        cx.solver_memory_limit(),
         canonical_goal,
         &mut inspect,
     );
@@ -1510,6 +1583,20 @@ pub(super) fn evaluate_root_goal_for_proof_tree<D: SolverDelegate<Interner = I>,
     let (canonical_result, final_revision) =
         delegate.cx().evaluate_root_goal_for_proof_tree_raw(canonical_goal);
 
+    // Building the proof tree must not change the result of the goal.
+    if delegate.cx().solver_validation() {
+        let expected = delegate
+            .probe(|| delegate.evaluate_root_goal(goal, origin_span, None))
+            .map(|eval| eval.certainty);
+        let found = canonical_result.map(|response| response.value.certainty);
+        if expected != found {
+            delegate.cx().delay_bug(format!(
+                "proof tree result does not match evaluation: \
+                goal={goal:?}, expected={expected:?}, found={found:?}"
+            ));
+        }
+    }
+
     let proof_tree = inspect::GoalEvaluation {
         uncanonicalized_goal: goal,
         orig_values,
@@ -405,6 +405,9 @@ pub struct GoalEvaluation<I: Interner> {
     /// If the [`Certainty`] was `Maybe`, then keep track of whether the goal has changed
     /// before rerunning it.
     pub stalled_on: Option<GoalStalledOn<I>>,
+    /// Whether evaluating this goal exceeded the memory limit of the solver, causing
+    /// some of its nested goals to overflow. This is only ever set for root goals.
+    pub exceeded_memory_limit: bool,
 }
 
 /// The conditions that must change for a goal to warrant
@@ -223,14 +223,19 @@ fn consider_impl_candidate(
             return Err(NoSolution);
         }
 
-        // We have to ignore negative impls when projecting.
+        // We have to ignore negative impls when projecting. Reservation impls only
+        // exist during coherence, where they are ambiguous.
         let impl_polarity = cx.impl_polarity(impl_def_id);
-        match impl_polarity {
+        let is_reservation = match impl_polarity {
             ty::ImplPolarity::Negative => return Err(NoSolution),
-            ty::ImplPolarity::Reservation => {
-                unimplemented!("reservation impl for trait with assoc item: {:?}", goal)
-            }
-            ty::ImplPolarity::Positive => {}
+            ty::ImplPolarity::Reservation => match ecx.typing_mode() {
+                ty::TypingMode::Coherence => true,
+                ty::TypingMode::Analysis { .. }
+                | ty::TypingMode::Borrowck { .. }
+                | ty::TypingMode::PostBorrowckAnalysis { .. }
+                | ty::TypingMode::PostAnalysis => return Err(NoSolution),
+            },
+            ty::ImplPolarity::Positive => false,
         };
 
         ecx.probe_trait_candidate(CandidateSource::Impl(impl_def_id)).enter(|ecx| {
@@ -251,6 +256,10 @@ fn consider_impl_candidate(
             // See <https://github.com/rust-lang/trait-system-refactor-initiative/issues/185>.
             ecx.try_evaluate_added_goals()?;
 
+            if is_reservation {
+                return ecx.evaluate_added_goals_and_make_canonical_response(Certainty::AMBIGUOUS);
+            }
+
             // Add GAT where clauses from the trait's definition. This is necessary
             // for soundness until we properly handle implied bounds on binders,
             // see tests/ui/generic-associated-types/must-prove-where-clauses-on-norm.rs.
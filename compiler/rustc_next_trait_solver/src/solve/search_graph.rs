@@ -1,4 +1,3 @@
-use std::convert::Infallible;
 use std::marker::PhantomData;
 
 use rustc_type_ir::data_structures::ensure_sufficient_stack;
@@ -26,12 +25,25 @@ impl<D, I> search_graph::Delegate for SearchGraphDelegate<D>
     type Cx = D::Interner;
 
     const ENABLE_PROVISIONAL_CACHE: bool = true;
-    type ValidationScope = Infallible;
-    fn enter_validation_scope(
-        _cx: Self::Cx,
-        _input: CanonicalInput<I>,
-    ) -> Option<Self::ValidationScope> {
-        None
+    // With `-Zsolver-validation` we never use the global cache and instead
+    // recompute every goal, checking that the result matches the cached one.
+    // As the global cache is disabled for all goals, we don't need to track
+    // anything in the scope.
+    type ValidationScope = ();
+    fn enter_validation_scope(cx: I, _input: CanonicalInput<I>) -> Option<Self::ValidationScope> {
+        cx.solver_validation().then_some(())
+    }
+
+    fn on_cache_mismatch(
+        cx: I,
+        input: CanonicalInput<I>,
+        cached: QueryResult<I>,
+        computed: QueryResult<I>,
+    ) {
+        cx.delay_bug(format!(
+            "global cache entry does not match reevaluation: \
+            input={input:?}, cached={cached:?}, computed={computed:?}"
+        ));
     }
 
     const FIXPOINT_STEP_LIMIT: usize = FIXPOINT_STEP_LIMIT;
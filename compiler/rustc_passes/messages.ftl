@@ -96,6 +96,12 @@ passes_deprecated_annotation_has_no_effect =
 passes_deprecated_attribute =
     deprecated attribute must be paired with either stable or unstable attribute
 
+passes_diagnostic_diagnostic_inferred_from_self_does_not_expect_args =
+    `#[diagnostic::inferred_from_self]` does not expect any arguments
+
+passes_diagnostic_diagnostic_inferred_from_self_only_for_trait_params =
+    `#[diagnostic::inferred_from_self]` can only be applied to type parameters of trait definitions
+
 passes_diagnostic_diagnostic_on_const_only_for_trait_impls =
     `#[diagnostic::on_const]` can only be applied to trait impls
     .label = not a trait impl
@@ -60,6 +60,14 @@
 #[diag(passes_diagnostic_diagnostic_on_unimplemented_only_for_traits)]
 struct DiagnosticOnUnimplementedOnlyForTraits;
 
+#[derive(LintDiagnostic)]
+#[diag(passes_diagnostic_diagnostic_inferred_from_self_only_for_trait_params)]
+struct DiagnosticInferredFromSelfOnlyForTraitParams;
+
+#[derive(LintDiagnostic)]
+#[diag(passes_diagnostic_diagnostic_inferred_from_self_does_not_expect_args)]
+struct DiagnosticInferredFromSelfDoesNotExpectArgs;
+
 #[derive(LintDiagnostic)]
 #[diag(passes_diagnostic_diagnostic_on_const_only_for_trait_impls)]
 struct DiagnosticOnConstOnlyForTraitImpls {
@@ -317,6 +325,9 @@ fn check_attributes(
                         [sym::diagnostic, sym::on_const, ..] => {
                             self.check_diagnostic_on_const(attr.span(), hir_id, target, item)
                         }
+                        [sym::diagnostic, sym::inferred_from_self, ..] => {
+                            self.check_diagnostic_inferred_from_self(attr.span(), hir_id, attr)
+                        }
                         [sym::rustc_clean, ..]
                         | [sym::rustc_dirty, ..]
                         | [sym::rustc_if_this_changed, ..]
@@ -601,6 +612,40 @@ fn check_diagnostic_on_const(
         );
     }
 
+    /// Checks if `#[diagnostic::inferred_from_self]` is applied to a type parameter of a trait
+    /// definition and that it has no arguments.
+    fn check_diagnostic_inferred_from_self(
+        &self,
+        attr_span: Span,
+        hir_id: HirId,
+        attr: &Attribute,
+    ) {
+        let is_trait_param =
+            matches!(
+                self.tcx.hir_node(hir_id),
+                hir::Node::GenericParam(hir::GenericParam {
+                    kind: hir::GenericParamKind::Type { .. },
+                    ..
+                })
+            ) && matches!(self.tcx.def_kind(self.tcx.hir_get_parent_item(hir_id)), DefKind::Trait);
+        if !is_trait_param {
+            self.tcx.emit_node_span_lint(
+                MISPLACED_DIAGNOSTIC_ATTRIBUTES,
+                hir_id,
+                attr_span,
+                DiagnosticInferredFromSelfOnlyForTraitParams,
+            );
+        }
+        if !attr.is_word() {
+            self.tcx.emit_node_span_lint(
+                MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                hir_id,
+                attr_span,
+                DiagnosticInferredFromSelfDoesNotExpectArgs,
+            );
+        }
+    }
+
     /// Checks if an `#[inline]` is applied to a function or a closure.
     fn check_inline(&self, hir_id: HirId, attr_span: Span, kind: &InlineAttr, target: Target) {
         match target {
@@ -55,4 +55,10 @@ pub fn get<Tcx: DepContext>(&self, tcx: Tcx) -> T {
         tcx.dep_graph().read_index(self.dep_node);
         self.cached_value.clone()
     }
+
+    /// Returns the cached value together with the dep-node it depends on,
+    /// without adding a read of that dep-node to the current task.
+    pub fn get_untracked(&self) -> (&T, DepNodeIndex) {
+        (&self.cached_value, self.dep_node)
+    }
 }
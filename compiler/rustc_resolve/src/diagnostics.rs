@@ -1425,21 +1425,25 @@ fn lookup_import_candidates_from_module<FilterFn>(
                     }
 
                     if candidates.iter().all(|v: &ImportSuggestion| v.did != did) {
-                        // See if we're recommending TryFrom, TryInto, or FromIterator and add
-                        // a note about editions
-                        let note = if let Some(did) = did {
-                            let requires_note = !did.is_local()
-                                && this.tcx.get_attrs(did, sym::rustc_diagnostic_item).any(
-                                    |attr| {
-                                        [sym::TryInto, sym::TryFrom, sym::FromIterator]
-                                            .map(|x| Some(x))
-                                            .contains(&attr.value_str())
-                                    },
-                                );
-
-                            requires_note.then(|| {
+                        // See if we're recommending TryFrom, TryInto, FromIterator or
+                        // IntoFuture and add a note about editions
+                        let note = if let Some(did) = did
+                            && !did.is_local()
+                        {
+                            let edition = this
+                                .tcx
+                                .get_attrs(did, sym::rustc_diagnostic_item)
+                                .find_map(|attr| match attr.value_str()? {
+                                    sym::TryInto | sym::TryFrom | sym::FromIterator => {
+                                        Some(Edition::Edition2021)
+                                    }
+                                    sym::IntoFuture => Some(Edition::Edition2024),
+                                    _ => None,
+                                });
+
+                            edition.map(|edition| {
                                 format!(
-                                    "'{}' is included in the prelude starting in Edition 2021",
+                                    "'{}' is included in the prelude starting in Edition {edition}",
                                     path_names_to_string(&path)
                                 )
                             })
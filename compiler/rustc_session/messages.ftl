@@ -111,6 +111,8 @@ session_sanitizer_not_supported = {$us} sanitizer is not supported for this targ
 
 session_sanitizers_not_supported = {$us} sanitizers are not supported for this target
 
+session_shuffling_obligations = shuffling obligations using seed {$seed}, pass `-Zshuffle-obligations-seed={$seed}` to reproduce this compilation
+
 session_skipping_const_checks = skipping const checks
 
 session_soft_float_deprecated =
@@ -996,6 +996,18 @@ pub struct NextSolverConfig {
     pub globally: bool = false,
 }
 
+/// The goal to explain with `-Zexplain-goal`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ExplainGoal {
+    /// The predicate of the goal, as printed in diagnostics.
+    pub predicate: String,
+    /// The suffix of the path of the file containing the goal, if given.
+    pub file: Option<String>,
+    /// The 1-based line and column of a position inside of the span of the goal.
+    pub line: usize,
+    pub col: usize,
+}
+
 #[derive(Clone)]
 pub enum Input {
     /// Load source code from a file.
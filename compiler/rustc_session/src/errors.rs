@@ -522,6 +522,12 @@ pub(crate) struct FailedToCreateProfiler {
 #[note(session_soft_float_deprecated_issue)]
 pub(crate) struct SoftFloatDeprecated;
 
+#[derive(Diagnostic)]
+#[diag(session_shuffling_obligations)]
+pub(crate) struct ShufflingObligations {
+    pub(crate) seed: u64,
+}
+
 #[derive(LintDiagnostic)]
 #[diag(session_unexpected_builtin_cfg)]
 #[note(session_controlled_by)]
@@ -826,6 +826,8 @@ mod desc {
     pub(crate) const parse_instrument_xray: &str = "either a boolean (`yes`, `no`, `on`, `off`, etc), or a comma separated list of settings: `always` or `never` (mutually exclusive), `ignore-loops`, `instruction-threshold=N`, `skip-entry`, `skip-exit`";
     pub(crate) const parse_unpretty: &str = "`string` or `string=string`";
     pub(crate) const parse_treat_err_as_bug: &str = "either no value or a non-negative number";
+    pub(crate) const parse_explain_goal: &str =
+        "a predicate and a position of the form `<pred>@[<file>:]<line>:<col>`";
     pub(crate) const parse_next_solver_config: &str =
         "either `globally` (when used without an argument), `coherence` (default) or `no`";
     pub(crate) const parse_lto: &str =
@@ -1654,6 +1656,26 @@ pub(crate) fn parse_treat_err_as_bug(
         }
     }
 
+    pub(crate) fn parse_explain_goal(slot: &mut Option<ExplainGoal>, v: Option<&str>) -> bool {
+        let Some((predicate, position)) = v.and_then(|v| v.rsplit_once('@')) else {
+            return false;
+        };
+        let mut parts = position.rsplitn(3, ':');
+        let (Some(col), Some(line)) = (parts.next(), parts.next()) else {
+            return false;
+        };
+        let (Ok(line), Ok(col)) = (line.parse(), col.parse()) else {
+            return false;
+        };
+        *slot = Some(ExplainGoal {
+            predicate: predicate.trim().to_owned(),
+            file: parts.next().map(str::to_owned),
+            line,
+            col,
+        });
+        true
+    }
+
     pub(crate) fn parse_next_solver_config(slot: &mut NextSolverConfig, v: Option<&str>) -> bool {
         if let Some(config) = v {
             *slot = match config {
@@ -2307,6 +2329,9 @@ pub(crate) fn parse_align(slot: &mut Option<Align>, v: Option<&str>) -> bool {
     dump_dep_graph: bool = (false, parse_bool, [UNTRACKED],
         "dump the dependency graph to $RUST_DEP_GRAPH (default: /tmp/dep_graph.gv) \
         (default: no)"),
+    dump_goal_env: bool = (false, parse_bool, [UNTRACKED],
+        "note the where-clauses which were considered when proving the goal \
+        responsible for an unsatisfied trait bound (default: no)"),
     dump_mir: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "dump MIR state to file.
         `val` is used to select which passes and functions to dump. For example:
@@ -2330,6 +2355,12 @@ pub(crate) fn parse_align(slot: &mut Option<Align>, v: Option<&str>) -> bool {
         "output statistics about monomorphization collection"),
     dump_mono_stats_format: DumpMonoStatsFormat = (DumpMonoStatsFormat::Markdown, parse_dump_mono_stats, [UNTRACKED],
         "the format to use for -Z dump-mono-stats (`markdown` (default) or `json`)"),
+    dump_proof_tree: Vec<String> = (Vec::new(), parse_string_push, [UNTRACKED],
+        "print the proof trees of the where-clauses of the given item, e.g. `Trait::method`, \
+        when proven with the next-generation trait solver (can be passed multiple times)"),
+    dump_solver_cache: bool = (false, parse_bool, [UNTRACKED],
+        "print the global evaluation cache of the next-generation trait solver at the \
+        end of compilation (default: no)"),
     #[rustc_lint_opt_deny_field_access("use `Session::dwarf_version` instead of this field")]
     dwarf_version: Option<u32> = (None, parse_opt_number, [TRACKED],
         "version of DWARF debug information to emit (default: 2 or 4, depending on platform)"),
@@ -2352,8 +2383,15 @@ pub(crate) fn parse_align(slot: &mut Option<Align>, v: Option<&str>) -> bool {
         "Use WebAssembly error handling for wasm32-unknown-emscripten"),
     enforce_type_length_limit: bool = (false, parse_bool, [TRACKED],
         "enforce the type length limit when monomorphizing instances in codegen"),
+    error_refinement_budget: usize = (10_000, parse_number, [TRACKED],
+        "the maximum number of goals visited when refining trait errors of a single body \
+        with the next-generation trait solver, after which errors are reported for their \
+        root obligation instead (default: 10000)"),
     experimental_default_bounds: bool = (false, parse_bool, [TRACKED],
         "enable default bounds for experimental group of auto traits"),
+    explain_goal: Option<ExplainGoal> = (None, parse_explain_goal, [UNTRACKED],
+        "print how the next-generation trait solver proves the goal `<pred>` at the given \
+        position, e.g. `Vec<T>: Clone@src/lib.rs:4:5`"),
     export_executable_symbols: bool = (false, parse_bool, [TRACKED],
         "export symbols from executables, as if they were dynamic libraries"),
     external_clangrt: bool = (false, parse_bool, [UNTRACKED],
@@ -2504,6 +2542,12 @@ pub(crate) fn parse_align(slot: &mut Option<Align>, v: Option<&str>) -> bool {
         "allow crates to be namespaced by other crates (default: no)"),
     next_solver: NextSolverConfig = (NextSolverConfig::default(), parse_next_solver_config, [TRACKED],
         "enable and configure the next generation trait solver used by rustc"),
+    next_solver_infer_outlives: bool = (false, parse_bool, [TRACKED],
+        "use the next-generation trait solver to compute the outlives requirements of \
+        ADT fields which only consist of builtin types (default: no)"),
+    next_solver_memory_limit: usize = (1024, parse_number, [TRACKED],
+        "the approximate amount of memory in MiB the next-generation trait solver may use \
+        when proving a single goal (default: 1024)"),
     nll_facts: bool = (false, parse_bool, [UNTRACKED],
         "dump facts from NLL analysis into side files (default: no)"),
     nll_facts_dir: String = ("nll-facts".to_string(), parse_string, [UNTRACKED],
@@ -2651,11 +2695,22 @@ pub(crate) fn parse_align(slot: &mut Option<Align>, v: Option<&str>) -> bool {
         "make the current crate share its generic instantiations"),
     shell_argfiles: bool = (false, parse_bool, [UNTRACKED],
         "allow argument files to be specified with POSIX \"shell-style\" argument quoting"),
+    shuffle_obligations: bool = (false, parse_bool, [TRACKED],
+        "randomize the order in which the next-generation trait solver processes pending \
+        obligations and assembled candidates, printing the seed used (default: no)"),
+    shuffle_obligations_seed: Option<u64> = (None, parse_opt_number, [TRACKED],
+        "use the given seed for `-Zshuffle-obligations` instead of a random one"),
+    shuffle_obligations_verify: bool = (false, parse_bool, [TRACKED],
+        "evaluate pending obligations in two different shuffled orders and error if \
+        their results differ, implies `-Zshuffle-obligations` (default: no)"),
     simulate_remapped_rust_src_base: Option<PathBuf> = (None, parse_opt_pathbuf, [TRACKED],
         "simulate the effect of remap-debuginfo = true at bootstrapping by remapping path \
         to rust's source base directory. only meant for testing purposes"),
     small_data_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
         "Set the threshold for objects to be stored in a \"small data\" section"),
+    solver_validation: bool = (false, parse_bool, [TRACKED],
+        "check internal invariants of the next-generation trait solver while solving, \
+        reporting violations as delayed bugs. this is very slow (default: no)"),
     span_debug: bool = (false, parse_bool, [UNTRACKED],
         "forward proc_macro::Span's `Debug` impl to `Span`"),
     /// o/w tests have closure@path
@@ -2719,6 +2774,9 @@ pub(crate) fn parse_align(slot: &mut Option<Align>, v: Option<&str>) -> bool {
         "for every macro invocation, print its name and arguments (default: no)"),
     track_diagnostics: bool = (false, parse_bool, [UNTRACKED],
         "tracks where in rustc a diagnostic was emitted"),
+    trait_solver_progress_interval: usize = (0, parse_number, [UNTRACKED],
+        "call the `trait_solver_progress` hook every N goals solved by the trait solver, \
+        allowing external tools to cancel trait solving (default: 0, i.e. never)"),
     // Diagnostics are considered side-effects of a query (see `QuerySideEffect`) and are saved
     // alongside query results and changes to translation options can affect diagnostics - so
     // translation options should be tracked.
@@ -2,7 +2,7 @@
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicUsize};
 use std::{env, io};
 
 use rand::{RngCore, rng};
@@ -147,6 +147,14 @@ pub struct Session {
     target_filesearch: FileSearch,
     host_filesearch: FileSearch,
 
+    /// The seed used to shuffle pending obligations and candidates in the
+    /// next-generation trait solver, if `-Zshuffle-obligations` is enabled.
+    pub obligation_shuffle_seed: Option<u64>,
+
+    /// The number of goals solved by the trait solver so far, used to call the
+    /// `trait_solver_progress` hook if `-Ztrait-solver-progress-interval` is set.
+    pub solved_trait_goals: AtomicUsize,
+
     /// A random string generated per invocation of rustc.
     ///
     /// This is prepended to all temporary files so that they do not collide
@@ -1066,6 +1074,10 @@ pub fn build_session(
         .as_ref()
         .map(|_| rng().next_u32().to_base_fixed_len(CASE_INSENSITIVE).to_string());
 
+    let obligation_shuffle_seed = (sopts.unstable_opts.shuffle_obligations
+        || sopts.unstable_opts.shuffle_obligations_verify)
+        .then(|| sopts.unstable_opts.shuffle_obligations_seed.unwrap_or_else(|| rng().next_u64()));
+
     let timings = TimingSectionHandler::new(sopts.json_timings);
 
     let sess = Session {
@@ -1090,6 +1102,8 @@ pub fn build_session(
         using_internal_features,
         target_filesearch,
         host_filesearch,
+        obligation_shuffle_seed,
+        solved_trait_goals: AtomicUsize::new(0),
         invocation_temp,
     };
 
@@ -1351,6 +1365,13 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
             sess.dcx().emit_warn(errors::SoftFloatIgnored);
         }
     }
+
+    // Print the randomly chosen seed so that failures found this way can be reproduced.
+    if let Some(seed) = sess.obligation_shuffle_seed
+        && sess.opts.unstable_opts.shuffle_obligations_seed.is_none()
+    {
+        sess.dcx().emit_note(errors::ShufflingObligations { seed });
+    }
 }
 
 /// Holds data on the current incremental compilation session, if there is one.
@@ -881,6 +881,7 @@
         destruct,
         destructuring_assignment,
         diagnostic,
+        diagnostic_inferred_from_self,
         diagnostic_namespace,
         diagnostic_on_const,
         dialect,
@@ -1237,6 +1238,7 @@
         impl_trait_in_fn_trait_return,
         impl_trait_projections,
         implement_via_object,
+        implements,
         implied_by,
         import,
         import_name_type,
@@ -1254,6 +1256,7 @@
         index_mut,
         infer_outlives_requirements,
         infer_static_outlives_requirements,
+        inferred_from_self,
         inherent_associated_types,
         inherit,
         initial,
@@ -1944,6 +1947,7 @@
         rustc_dummy,
         rustc_dump_def_parents,
         rustc_dump_item_bounds,
+        rustc_dump_leaf_obligations,
         rustc_dump_predicates,
         rustc_dump_user_args,
         rustc_dump_vtable,
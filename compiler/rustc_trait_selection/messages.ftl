@@ -121,6 +121,11 @@ trait_selection_closure_kind_requirement = the requirement to implement `{$trait
 
 trait_selection_compare_impl_item_obligation = ...so that the definition in impl matches the definition from the trait
 trait_selection_consider_specifying_length = consider specifying the actual array length
+trait_selection_considered_where_clauses =
+    {$count ->
+        [0] no where-clauses were considered when proving `{$predicate}`
+        *[other] the following where-clauses were considered when proving `{$predicate}`:{$clauses}
+    }
 trait_selection_coro_closure_not_fn = {$coro_kind}closure does not implement `{$kind}` because it captures state from its environment
 
 trait_selection_data_flows = ...but data{$label_var1_exists ->
@@ -158,6 +163,8 @@ trait_selection_explicit_lifetime_required_with_ident = explicit lifetime requir
 trait_selection_explicit_lifetime_required_with_param_type = explicit lifetime required in parameter type
     .label = lifetime `{$named}` required
 
+trait_selection_feature_gated_builtin_impl = the builtin impl of `{$trait_name}` for `{$self_ty}` is unstable and requires the `{$feature}` feature
+
 trait_selection_fn_consider_casting = consider casting the fn item to a fn pointer: `{$casting}`
 
 trait_selection_fn_consider_casting_both = consider casting both fn items to fn pointers using `as {$sig}`
@@ -169,14 +176,27 @@ trait_selection_fps_cast_both = consider casting both fn items to fn pointers us
 trait_selection_fps_items_are_distinct = fn items are distinct from fn pointers
 trait_selection_fps_remove_ref = consider removing the reference
 trait_selection_fps_use_ref = consider using a reference
+trait_selection_fundamental_type_downstream_impls = {$wrapper ->
+    [reference] references are
+    *[other] `{$wrapper}` is
+} `#[fundamental]`, so downstream crates may implement traits for {$wrapper ->
+    [reference] references to their own types
+    *[other] `{$wrapper}` wrapping their own types
+}
+
 trait_selection_fulfill_req_lifetime = the type `{$ty}` does not fulfill the required lifetime
 
+trait_selection_goal_too_complex = the goal `{$predicate}` is too complex for the trait solver
+    .note = proving it requires more than approximately {$limit} MiB of memory
+    .help = consider increasing the limit using `-Znext-solver-memory-limit`
+
 trait_selection_ignored_diagnostic_option = `{$option_name}` is ignored due to previous definition of `{$option_name}`
     .other_label = `{$option_name}` is first declared here
     .label = `{$option_name}` is already declared here
 
 trait_selection_implicit_static_lifetime_note = this has an implicit `'static` lifetime requirement
 trait_selection_implicit_static_lifetime_suggestion = consider relaxing the implicit `'static` requirement
+trait_selection_inferred_from_self = the type parameter `{$param}` of `{$trait_name}` is usually inferred from the `Self` type, which is `{$self_ty}` here
 trait_selection_inherent_projection_normalization_overflow = overflow evaluating associated type `{$ty}`
 
 trait_selection_invalid_format_specifier = invalid format specifier
@@ -229,6 +249,9 @@ trait_selection_negative_positive_conflict = found both positive and negative im
     .positive_implementation_here = positive implementation here
     .positive_implementation_in_crate = positive implementation in crate `{$positive_impl_cname}`
 
+trait_selection_nightly_only_impl = a nightly-only implementation exists, which requires the unstable feature `{$feature}`
+trait_selection_nightly_only_impl_ignored = this implementation is ignored, as using it for inference would break once it gets stabilized
+
 trait_selection_nothing = {""}
 
 trait_selection_oc_cant_coerce_force_inline =
@@ -262,6 +285,9 @@ trait_selection_opaque_type_non_generic_param =
         *[other] this generic parameter must be used with a generic {$kind} parameter
     }
 
+trait_selection_order_dependent_obligations = the result of proving these obligations depends on the order in which they are processed
+    .note = found while shuffling obligations using seed {$seed}, pass `-Zshuffle-obligations-seed={$seed}` to reproduce this error
+
 trait_selection_outlives_bound = lifetime of the source pointer does not outlive lifetime bound of the object type
 trait_selection_outlives_content = lifetime of reference outlives lifetime of borrowed content...
 
@@ -282,6 +308,9 @@ trait_selection_reborrow = ...so that reference does not outlive borrowed conten
 trait_selection_ref_longer_than_data = in type `{$ty}`, reference has a longer lifetime than the data it references
 
 trait_selection_reference_outlives_referent = ...so that the reference type `{$name}` does not outlive the data it points at
+
+trait_selection_refinement_exceeded_limits = this error may be imprecise, as looking for its underlying cause exceeded the limits for error refinement
+
 trait_selection_region_explanation = {$pref_kind ->
     *[should_not_happen] [{$pref_kind}]
     [ref_valid_for] ...the reference is valid for
@@ -322,6 +351,11 @@ trait_selection_relate_param_bound = ...so that the type `{$name}` will meet its
 }
 trait_selection_relate_param_bound_2 = ...that is required by this bound
 trait_selection_relate_region_param_bound = ...so that the declared lifetime parameter bounds are satisfied
+
+trait_selection_required_in_external_macro = the unsatisfied bound above is required by this code in an external macro
+
+trait_selection_reserved_impl = this impl is reserved for future use and does not apply
+
 trait_selection_ril_because_of = because of this returned expression
 trait_selection_ril_introduced_by = requirement introduced by this return type
 trait_selection_ril_introduced_here = `'static` requirement introduced here
@@ -331,6 +365,8 @@ trait_selection_rustc_on_unimplemented_empty_on_clause = empty `on`-clause in `#
     .label = empty `on`-clause here
 trait_selection_rustc_on_unimplemented_expected_identifier = expected an identifier inside this `on`-clause
     .label = expected an identifier here, not `{$path}`
+trait_selection_rustc_on_unimplemented_expected_one_implements_clause = expected a single `param = "path::to::Trait"` in `implements(..)`
+    .label = unexpected `implements(..)` argument here
 trait_selection_rustc_on_unimplemented_expected_one_predicate_in_not = expected a single predicate in `not(..)`
     .label = unexpected quantity of predicates here
 trait_selection_rustc_on_unimplemented_invalid_flag = invalid flag in `on`-clause
@@ -338,7 +374,7 @@ trait_selection_rustc_on_unimplemented_invalid_flag = invalid flag in `on`-claus
 trait_selection_rustc_on_unimplemented_invalid_name = invalid name in `on`-clause
     .label = expected one of `cause`, `from_desugaring`, `Self` or any generic parameter of the trait, not `{$invalid_name}`
 trait_selection_rustc_on_unimplemented_invalid_predicate = this predicate is invalid
-    .label = expected one of `any`, `all` or `not` here, not `{$invalid_pred}`
+    .label = expected one of `any`, `all`, `not` or `implements` here, not `{$invalid_pred}`
 trait_selection_rustc_on_unimplemented_missing_value = this attribute must have a value
     .label = expected value here
     .note = e.g. `#[rustc_on_unimplemented(message="foo")]`
@@ -440,6 +476,8 @@ trait_selection_trait_placeholder_mismatch = implementation of `{$trait_def_id}`
     .label_where = due to a where-clause on `{$def_id}`...
     .label_dup = implementation of `{$trait_def_id}` is not general enough
 
+trait_selection_trait_solving_cancelled = trait solving was cancelled after solving {$solved_goals} goals
+
 trait_selection_try_cannot_convert = `?` operator cannot convert from `{$found}` to `{$expected}`
 
 trait_selection_tuple_trailing_comma = use a trailing comma to create a tuple with one element
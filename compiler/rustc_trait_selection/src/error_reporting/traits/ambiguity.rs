@@ -4,13 +4,14 @@
 use rustc_hir as hir;
 use rustc_hir::LangItem;
 use rustc_hir::def::{DefKind, Res};
-use rustc_hir::def_id::{CRATE_DEF_ID, DefId};
+use rustc_hir::def_id::{CRATE_DEF_ID, DefId, LocalDefId};
 use rustc_hir::intravisit::Visitor as _;
 use rustc_infer::infer::{BoundRegionConversionTime, InferCtxt};
 use rustc_infer::traits::util::elaborate;
 use rustc_infer::traits::{
     Obligation, ObligationCause, ObligationCauseCode, PolyTraitObligation, PredicateObligation,
 };
+use rustc_middle::ty::fast_reject::DeepRejectCtxt;
 use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitable as _, TypeVisitableExt as _};
 use rustc_session::parse::feature_err_unstable_feature_bound;
 use rustc_span::{DUMMY_SP, ErrorGuaranteed, Span};
@@ -18,7 +19,10 @@
 
 use crate::error_reporting::TypeErrCtxt;
 use crate::error_reporting::infer::need_type_info::TypeAnnotationNeeded;
-use crate::error_reporting::traits::{FindExprBySpan, to_pretty_impl_header};
+use crate::error_reporting::traits::{
+    CandidateSimilarity, FindExprBySpan, ImplCandidate, to_pretty_impl_header,
+};
+use crate::errors::InferredFromSelf;
 use crate::traits::ObligationCtxt;
 use crate::traits::query::evaluate_obligation::InferCtxtExt;
 
@@ -155,11 +159,54 @@ pub fn compute_applicable_impls_for_diagnostics<'tcx>(
     ambiguities
 }
 
+/// Reports an error if the where-clauses of `body_id` require different types for the
+/// projection of `def_id` with `args`, see `TypeErrCtxt::report_conflicting_projection_bounds`.
+pub(crate) fn check_conflicting_projection_bounds<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    (body_id, def_id, args): (LocalDefId, DefId, ty::GenericArgsRef<'tcx>),
+) -> Result<(), ErrorGuaranteed> {
+    let alias = ty::AliasTerm::new_from_args(tcx, def_id, args);
+    let bounds: Vec<_> = elaborate(tcx, tcx.predicates_of(body_id).instantiate_identity(tcx))
+        .filter_map(|(clause, span)| {
+            let data = clause.as_projection_clause()?.no_bound_vars()?;
+            let ty = data.term.as_type()?;
+            (tcx.erase_and_anonymize_regions(data.projection_term) == alias).then_some((ty, span))
+        })
+        .collect();
+    let drcx = DeepRejectCtxt::relate_infer_infer(tcx);
+    let Some((mut first, mut second)) = bounds.iter().enumerate().find_map(|(i, &first)| {
+        let &second =
+            bounds[i + 1..].iter().find(|&&(ty, _)| !drcx.types_may_unify(first.0, ty))?;
+        Some((first, second))
+    }) else {
+        return Ok(());
+    };
+    if second.1 < first.1 {
+        (first, second) = (second, first);
+    }
+
+    let mut long_ty_path = None;
+    let alias_str = tcx.short_string(alias.to_term(tcx), &mut long_ty_path);
+    let mut span = MultiSpan::from_spans(vec![first.1, second.1]);
+    for (ty, bound_span) in [first, second] {
+        let ty = tcx.short_string(ty, &mut long_ty_path);
+        span.push_span_label(bound_span, format!("`{alias_str}` is required to be `{ty}` here"));
+    }
+    Err(struct_span_code_err!(tcx.dcx(), span, E0284, "conflicting requirements on `{alias_str}`",)
+        .with_note(format!(
+            "`{alias_str}` can't be both of these types, so uses of it are ambiguous"
+        ))
+        .with_long_ty_path(long_ty_path)
+        .emit())
+}
+
 impl<'a, 'tcx> TypeErrCtxt<'a, 'tcx> {
-    #[instrument(skip(self), level = "debug")]
+    #[instrument(skip(self, decorate), level = "debug")]
     pub(super) fn maybe_report_ambiguity(
         &self,
         obligation: &PredicateObligation<'tcx>,
+        ambiguous_impls: &[DefId],
+        decorate: &dyn Fn(&mut Diag<'_>),
     ) -> ErrorGuaranteed {
         // Unable to successfully determine, probably means
         // insufficient type information, but could mean
@@ -211,15 +258,17 @@ pub(super) fn maybe_report_ambiguity(
                     Some(LangItem::Sized | LangItem::MetaSized)
                 ) {
                     return match self.tainted_by_errors() {
-                        None => self
-                            .emit_inference_failure_err(
+                        None => {
+                            let mut err = self.emit_inference_failure_err(
                                 obligation.cause.body_id,
                                 span,
                                 trait_pred.self_ty().skip_binder().into(),
                                 TypeAnnotationNeeded::E0282,
                                 false,
-                            )
-                            .emit(),
+                            );
+                            decorate(&mut err);
+                            err.emit()
+                        }
                         Some(e) => e,
                     };
                 }
@@ -236,13 +285,32 @@ pub(super) fn maybe_report_ambiguity(
 
                 // Pick the first generic parameter that still contains inference variables as the one
                 // we're going to emit an error for. If there are none (see above), fall back to
-                // a more general error.
-                let term = data
+                // a more general error. Parameters marked `#[diagnostic::inferred_from_self]` are
+                // only picked if nothing else is left to annotate, as they usually follow from
+                // the `Self` type.
+                let trait_generics = self.tcx.generics_of(data.trait_ref.def_id);
+                let is_inferred_from_self = |i: usize| {
+                    // Index 0 is `Self`, whose "parameter" is the trait itself.
+                    i > 0
+                        && self
+                            .tcx
+                            .is_inferred_from_self(trait_generics.param_at(i, self.tcx).def_id)
+                };
+                let infer_terms = data
                     .trait_ref
                     .args
                     .iter()
-                    .filter_map(ty::GenericArg::as_term)
-                    .find(|s| s.has_non_region_infer());
+                    .enumerate()
+                    .filter_map(|(i, arg)| Some((i, arg.as_term()?)))
+                    .filter(|(_, term)| term.has_non_region_infer());
+                let (term, inferred_from_self) = match infer_terms
+                    .clone()
+                    .find(|&(i, _)| !is_inferred_from_self(i))
+                    .or_else(|| infer_terms.clone().next())
+                {
+                    Some((i, term)) => (Some(term), is_inferred_from_self(i).then_some(i)),
+                    None => (None, None),
+                };
 
                 let mut err = if let Some(term) = term {
                     self.emit_inference_failure_err(
@@ -263,6 +331,16 @@ pub(super) fn maybe_report_ambiguity(
                     .with_long_ty_path(long_ty_path)
                 };
 
+                if let Some(i) = inferred_from_self {
+                    let param = trait_generics.param_at(i, self.tcx);
+                    let self_ty = self.tcx.short_string(data.self_ty(), err.long_ty_path());
+                    err.subdiagnostic(InferredFromSelf {
+                        param: param.name,
+                        trait_name: self.tcx.def_path_str(data.trait_ref.def_id),
+                        self_ty,
+                    });
+                }
+
                 let mut ambiguities = compute_applicable_impls_for_diagnostics(
                     self.infcx,
                     &obligation.with(self.tcx, trait_pred),
@@ -301,6 +379,18 @@ pub(super) fn maybe_report_ambiguity(
                         return e;
                     }
                     self.annotate_source_of_ambiguity(&mut err, &ambiguities, predicate);
+                } else if ambiguous_impls.len() > 1 {
+                    // The new solver found multiple impls which apply to this goal, which
+                    // is more precise than `compute_applicable_impls_for_diagnostics`.
+                    if let Some(e) = self.tainted_by_errors() {
+                        err.cancel();
+                        return e;
+                    }
+                    let ambiguities: Vec<_> = ambiguous_impls
+                        .iter()
+                        .map(|&def_id| CandidateSource::DefId(def_id))
+                        .collect();
+                    self.annotate_source_of_ambiguity(&mut err, &ambiguities, predicate);
                 } else {
                     if let Some(e) = self.tainted_by_errors() {
                         err.cancel();
@@ -308,8 +398,20 @@ pub(super) fn maybe_report_ambiguity(
                     }
                     let pred = self.tcx.short_string(predicate, &mut err.long_ty_path());
                     err.note(format!("cannot satisfy `{pred}`"));
-                    let impl_candidates =
-                        self.find_similar_impl_candidates(predicate.as_trait_clause().unwrap());
+                    // With the new solver, we only list the impls which may actually apply
+                    // if the self type were inferred to the impl's self type.
+                    let mut impl_candidates = if self.next_trait_solver()
+                        && has_non_region_infer
+                        && trait_pred.self_ty().skip_binder().is_ty_var()
+                    {
+                        self.impl_candidates_for_ambiguous_self_ty(obligation, trait_pred)
+                    } else {
+                        vec![]
+                    };
+                    if impl_candidates.is_empty() {
+                        impl_candidates =
+                            self.find_similar_impl_candidates(predicate.as_trait_clause().unwrap());
+                    }
                     if impl_candidates.len() < 40 {
                         self.report_similar_impl_candidates(
                             impl_candidates.as_slice(),
@@ -519,6 +621,9 @@ pub(super) fn maybe_report_ambiguity(
                 if let Some(e) = self.tainted_by_errors() {
                     return e;
                 }
+                if let Some(e) = self.report_conflicting_projection_bounds(obligation, predicate) {
+                    return e;
+                }
 
                 if let Err(guar) = self
                     .tcx
@@ -606,6 +711,9 @@ pub(super) fn maybe_report_ambiguity(
                 if let Some(e) = self.tainted_by_errors() {
                     return e;
                 }
+                if let Some(e) = self.report_conflicting_projection_bounds(obligation, predicate) {
+                    return e;
+                }
                 let alias = self.tcx.short_string(alias, &mut long_ty_path);
                 struct_span_code_err!(
                     self.dcx(),
@@ -641,6 +749,9 @@ pub(super) fn maybe_report_ambiguity(
                 if let Some(e) = self.tainted_by_errors() {
                     return e;
                 }
+                if let Some(e) = self.report_conflicting_projection_bounds(obligation, predicate) {
+                    return e;
+                }
                 let predicate = self.tcx.short_string(predicate, &mut long_ty_path);
                 struct_span_code_err!(
                     self.dcx(),
@@ -653,9 +764,80 @@ pub(super) fn maybe_report_ambiguity(
             }
         };
         self.note_obligation_cause(&mut err, obligation);
+        decorate(&mut err);
         err.emit()
     }
 
+    /// Projections are ambiguous if the where-clauses in scope require different types for
+    /// them, e.g. `T: Iterator<Item = u32>` elaborated from a supertrait together with
+    /// `T: Iterator<Item = String>`. Point at both where-clauses instead of asking for
+    /// type annotations which can't help.
+    fn report_conflicting_projection_bounds(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        predicate: ty::Predicate<'tcx>,
+    ) -> Option<ErrorGuaranteed> {
+        let tcx = self.tcx;
+        let alias = match predicate.kind().skip_binder() {
+            ty::PredicateKind::Clause(ty::ClauseKind::Projection(data)) => data.projection_term,
+            ty::PredicateKind::NormalizesTo(data) => data.alias,
+            ty::PredicateKind::AliasRelate(lhs, rhs, _) => {
+                lhs.to_alias_term().or_else(|| rhs.to_alias_term())?
+            }
+            _ => return None,
+        };
+        if alias.kind(tcx) != ty::AliasTermKind::ProjectionTy
+            || alias.has_non_region_infer()
+            || alias.has_escaping_bound_vars()
+        {
+            return None;
+        }
+        let body_id = obligation.cause.body_id;
+        if !tcx.def_kind(body_id).has_generics() {
+            return None;
+        }
+
+        let alias = tcx.erase_and_anonymize_regions(alias);
+        tcx.check_conflicting_projection_bounds((body_id, alias.def_id, alias.args)).err()
+    }
+
+    /// Returns all impls which may apply to `trait_pred` if its self type, which is
+    /// an inference variable, were inferred to the impl's self type. We only consider
+    /// non-blanket impls, as blanket impls don't tell us anything about the type the
+    /// user may want.
+    fn impl_candidates_for_ambiguous_self_ty(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) -> Vec<ImplCandidate<'tcx>> {
+        let tcx = self.tcx;
+        let trait_impls = tcx.trait_impls_of(trait_pred.def_id());
+        trait_impls
+            .non_blanket_impls()
+            .values()
+            .flatten()
+            .filter_map(|&impl_def_id| {
+                let imp = tcx.impl_trait_header(impl_def_id);
+                if imp.polarity != ty::ImplPolarity::Positive {
+                    return None;
+                }
+
+                let may_hold = self.probe(|_| {
+                    let impl_args = self.fresh_args_for_item(obligation.cause.span, impl_def_id);
+                    let impl_self_ty = tcx.type_of(impl_def_id).instantiate(tcx, impl_args);
+                    let trait_pred =
+                        trait_pred.map_bound(|p| p.with_replaced_self_ty(tcx, impl_self_ty));
+                    self.predicate_may_hold(&obligation.with(tcx, trait_pred))
+                });
+                may_hold.then(|| ImplCandidate {
+                    trait_ref: imp.trait_ref.skip_binder(),
+                    similarity: CandidateSimilarity::Exact { ignoring_lifetimes: false },
+                    impl_def_id,
+                })
+            })
+            .collect()
+    }
+
     fn annotate_source_of_ambiguity(
         &self,
         err: &mut Diag<'_>,
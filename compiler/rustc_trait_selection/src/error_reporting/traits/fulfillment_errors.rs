@@ -7,12 +7,12 @@
 use rustc_abi::ExternAbi;
 use rustc_ast::ast::LitKind;
 use rustc_ast::{LitIntType, TraitObjectSyntax};
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet};
 use rustc_data_structures::unord::UnordSet;
 use rustc_errors::codes::*;
 use rustc_errors::{
     Applicability, Diag, ErrorGuaranteed, Level, MultiSpan, StashKey, StringPart, Suggestions,
-    pluralize, struct_span_code_err,
+    listify, pluralize, struct_span_code_err,
 };
 use rustc_hir::def_id::{DefId, LOCAL_CRATE, LocalDefId};
 use rustc_hir::intravisit::Visitor;
@@ -26,13 +26,14 @@
 use rustc_middle::ty::error::{ExpectedFound, TypeError};
 use rustc_middle::ty::print::{
     PrintPolyTraitPredicateExt, PrintTraitPredicateExt as _, PrintTraitRefExt as _,
-    with_forced_trimmed_paths,
+    with_crate_prefix, with_forced_trimmed_paths, with_no_trimmed_paths,
 };
 use rustc_middle::ty::{
     self, GenericArgKind, TraitRef, Ty, TyCtxt, TypeFoldable, TypeFolder, TypeSuperFoldable,
-    TypeVisitableExt, Upcast,
+    TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypeVisitor, Upcast,
 };
 use rustc_middle::{bug, span_bug};
+use rustc_session::parse::add_feature_diagnostics;
 use rustc_span::{BytePos, DUMMY_SP, STDLIB_STABLE_CRATES, Span, Symbol, sym};
 use tracing::{debug, instrument};
 
@@ -40,36 +41,43 @@
 use super::suggestions::get_explanation_based_on_obligation;
 use super::{
     ArgKind, CandidateSimilarity, FindExprBySpan, GetSafeTransmuteErrorAndReason, ImplCandidate,
+    internal_marker_trait_requirement,
 };
 use crate::error_reporting::TypeErrCtxt;
 use crate::error_reporting::infer::TyCategory;
 use crate::error_reporting::traits::on_unimplemented::OnUnimplementedDirective;
 use crate::error_reporting::traits::report_dyn_incompatibility;
-use crate::errors::{ClosureFnMutLabel, ClosureFnOnceLabel, ClosureKindMismatch, CoroClosureNotFn};
+use crate::errors::{
+    ClosureFnMutLabel, ClosureFnOnceLabel, ClosureKindMismatch, CoroClosureNotFn,
+    FeatureGatedBuiltinImpl,
+};
 use crate::infer::{self, InferCtxt, InferCtxtExt as _};
 use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
 use crate::traits::{
-    MismatchedProjectionTypes, NormalizeExt, Obligation, ObligationCause, ObligationCauseCode,
-    ObligationCtxt, PredicateObligation, SelectionContext, SelectionError, elaborate,
-    specialization_graph,
+    ImplUnusable, MismatchedProjectionTypes, NormalizeExt, Obligation, ObligationCause,
+    ObligationCauseCode, ObligationCtxt, PredicateObligation, SelectionContext, SelectionError,
+    elaborate, impl_usable_from, param_env_diff, specialization_graph, supertraits,
 };
 
 impl<'a, 'tcx> TypeErrCtxt<'a, 'tcx> {
     /// The `root_obligation` parameter should be the `root_obligation` field
     /// from a `FulfillmentError`. If no `FulfillmentError` is available,
     /// then it should be the same as `obligation`.
+    ///
+    /// `decorate` is used to add further notes to the reported error.
     pub fn report_selection_error(
         &self,
         mut obligation: PredicateObligation<'tcx>,
         root_obligation: &PredicateObligation<'tcx>,
         error: &SelectionError<'tcx>,
+        decorate: &dyn Fn(&mut Diag<'_>),
     ) -> ErrorGuaranteed {
         let tcx = self.tcx;
         let mut span = obligation.cause.span;
         let mut long_ty_file = None;
 
         let mut err = match *error {
-            SelectionError::Unimplemented => {
+            SelectionError::Unimplemented | SelectionError::FeatureGated(_) => {
                 // If this obligation was generated as a result of well-formedness checking, see if we
                 // can get a better error message by performing HIR-based well-formedness checking.
                 if let ObligationCauseCode::WellFormed(Some(wf_loc)) =
@@ -85,6 +93,33 @@ pub fn report_selection_error(
                     }
                 }
 
+                // With the new solver, the leaf obligation may have been derived from
+                // a where-clause of the impl item. Still point at that where-clause, as
+                // it's the one which isn't entailed by the trait item.
+                if self.next_trait_solver()
+                    && let ObligationCauseCode::CompareImplItem {
+                        impl_item_def_id,
+                        trait_item_def_id,
+                        kind: _,
+                    } = *obligation.cause.code().peel_derives()
+                {
+                    let root_predicate = self.resolve_vars_if_possible(root_obligation.predicate);
+                    let mut err = self.report_extra_impl_obligation(
+                        root_obligation.cause.span,
+                        impl_item_def_id,
+                        trait_item_def_id,
+                        &format!("`{root_predicate}`"),
+                    );
+                    let leaf_predicate = self.resolve_vars_if_possible(obligation.predicate);
+                    if leaf_predicate != root_predicate {
+                        err.note(format!(
+                            "`{root_predicate}` does not hold as `{leaf_predicate}` is not satisfied"
+                        ));
+                    }
+                    decorate(&mut err);
+                    return err.emit();
+                }
+
                 if let ObligationCauseCode::CompareImplItem {
                     impl_item_def_id,
                     trait_item_def_id,
@@ -92,19 +127,22 @@ pub fn report_selection_error(
                 } = *obligation.cause.code()
                 {
                     debug!("ObligationCauseCode::CompareImplItemObligation");
-                    return self.report_extra_impl_obligation(
+                    let mut err = self.report_extra_impl_obligation(
                         span,
                         impl_item_def_id,
                         trait_item_def_id,
                         &format!("`{}`", obligation.predicate),
-                    )
-                    .emit()
+                    );
+                    decorate(&mut err);
+                    return err.emit();
                 }
 
                 // Report a const-param specific error
                 if let ObligationCauseCode::ConstParam(ty) = *obligation.cause.code().peel_derives()
                 {
-                    return self.report_const_param_not_wf(ty, &obligation).emit();
+                    let mut err = self.report_const_param_not_wf(ty, &obligation);
+                    decorate(&mut err);
+                    return err.emit();
                 }
 
                 let bound_predicate = obligation.predicate.kind();
@@ -149,6 +187,8 @@ pub fn report_selection_error(
                             // The root trait is not `Unsize`, as to avoid talking about it in
                             // `tests/ui/coercion/coerce-issue-49593-box-never.rs`.
                             && !self.tcx.is_lang_item(root_pred.def_id(), LangItem::Unsize)
+                            // Users don't know about the solver-internal marker traits.
+                            && internal_marker_trait_requirement(self.tcx, root_pred.def_id()).is_none()
                         {
                             (
                                 self.resolve_vars_if_possible(
@@ -278,6 +318,15 @@ pub fn report_selection_error(
                         let mut err = struct_span_code_err!(self.dcx(), span, E0277, "{}", err_msg);
                         *err.long_ty_path() = long_ty_file;
 
+                        if let SelectionError::FeatureGated(feature) = *error {
+                            err.subdiagnostic(FeatureGatedBuiltinImpl {
+                                trait_name: self.tcx.item_name(leaf_trait_predicate.def_id()),
+                                self_ty: leaf_trait_predicate.skip_binder().self_ty(),
+                                feature,
+                            });
+                            add_feature_diagnostics(&mut err, self.tcx.sess, feature);
+                        }
+
                         let mut suggested = false;
                         let mut noted_missing_impl = false;
                         if is_try_conversion || is_question_mark {
@@ -343,6 +392,7 @@ pub fn report_selection_error(
                             have_alt_message,
                         ) {
                             self.note_obligation_cause(&mut err, &obligation);
+                            decorate(&mut err);
                             return err.emit();
                         }
 
@@ -417,6 +467,14 @@ pub fn report_selection_error(
                         self.suggest_floating_point_literal(&obligation, &mut err, leaf_trait_predicate);
                         self.suggest_dereferencing_index(&obligation, &mut err, leaf_trait_predicate);
                         suggested |= self.suggest_dereferences(&obligation, &mut err, leaf_trait_predicate);
+                        if !suggested {
+                            suggested |= self.suggest_borrowing_binop_operand(&obligation, &mut err, leaf_trait_predicate);
+                        }
+                        if !suggested {
+                            suggested |= self.suggest_borrowing_deref_target(&obligation, &mut err, leaf_trait_predicate);
+                        }
+                        self.label_binop_operand_types(&obligation, &mut err, leaf_trait_predicate);
+                        self.suggest_debug_formatting(&obligation, &mut err, leaf_trait_predicate);
                         suggested |= self.suggest_fn_call(&obligation, &mut err, leaf_trait_predicate);
                         let impl_candidates = self.find_similar_impl_candidates(leaf_trait_predicate);
                         suggested = if let &[cand] = &impl_candidates[..] {
@@ -472,9 +530,18 @@ pub fn report_selection_error(
                         );
                         self.note_different_trait_with_same_name(&mut err, &obligation, leaf_trait_predicate);
                         self.note_adt_version_mismatch(&mut err, leaf_trait_predicate);
+                        self.note_sealed_trait(&obligation, &mut err, leaf_trait_predicate);
                         self.suggest_remove_await(&obligation, &mut err);
                         self.suggest_derive(&obligation, &mut err, leaf_trait_predicate);
 
+                        if is_try_conversion && !suggested {
+                            self.suggest_question_mark_conversion(
+                                &obligation,
+                                main_trait_predicate,
+                                &mut err,
+                            );
+                        }
+
                         if tcx.is_lang_item(leaf_trait_predicate.def_id(), LangItem::Try) {
                             self.suggest_await_before_try(
                                 &mut err,
@@ -484,11 +551,17 @@ pub fn report_selection_error(
                             );
                         }
 
+                        if tcx.is_lang_item(leaf_trait_predicate.def_id(), LangItem::Unpin) {
+                            self.note_unpin_failure(&mut err, &obligation, leaf_trait_predicate);
+                        }
+
                         if self.suggest_add_clone_to_arg(&obligation, &mut err, leaf_trait_predicate) {
+                            decorate(&mut err);
                             return err.emit();
                         }
 
                         if self.suggest_impl_trait(&mut err, &obligation, leaf_trait_predicate) {
+                            decorate(&mut err);
                             return err.emit();
                         }
 
@@ -533,7 +606,12 @@ pub fn report_selection_error(
                         // Changing mutability doesn't make a difference to whether we have
                         // an `Unsize` impl (Fixes ICE in #71036)
                         if !is_unsize {
-                            self.suggest_change_mut(&obligation, &mut err, leaf_trait_predicate);
+                            self.suggest_reference_variant(
+                                &obligation,
+                                &mut err,
+                                leaf_trait_predicate,
+                                suggested,
+                            );
                         }
 
                         // If this error is due to `!: Trait` not implemented but `(): Trait` is
@@ -581,6 +659,7 @@ pub fn report_selection_error(
                                 Some(sym::Debug | sym::Display)
                             )
                         {
+                            decorate(&mut err);
                             return err.emit();
                         }
 
@@ -775,7 +854,14 @@ pub fn report_selection_error(
             }
         };
 
+        if self.next_trait_solver() {
+            self.note_unresolved_vars(&mut err, obligation.predicate);
+        }
+        if tcx.sess.verbose_internals() {
+            self.note_param_env_diff(&mut err, &obligation);
+        }
         self.note_obligation_cause(&mut err, &obligation);
+        decorate(&mut err);
         err.emit()
     }
 }
@@ -868,7 +954,7 @@ fn report_host_effect_error(
                         format!("trait `{trait_name}` is implemented but not `const`"),
                     );
 
-                    let (condition_options, format_args) = self.on_unimplemented_components(
+                    let (mut condition_options, format_args) = self.on_unimplemented_components(
                         trait_ref,
                         main_obligation,
                         diag.long_ty_path(),
@@ -876,6 +962,12 @@ fn report_host_effect_error(
 
                     if let Ok(Some(command)) = OnUnimplementedDirective::of_item(self.tcx, impl_did)
                     {
+                        condition_options.implemented_traits = self.implemented_condition_traits(
+                            &command,
+                            impl_did,
+                            impl_data.args,
+                            main_obligation,
+                        );
                         let note = command.evaluate(
                             self.tcx,
                             predicate.skip_binder().trait_ref,
@@ -1061,14 +1153,11 @@ fn detect_negative_literal(
     /// When the `E` of the resulting `Result<T, E>` in an expression `foo().bar().baz()?`,
     /// identify those method chain sub-expressions that could or could not have been annotated
     /// with `?`.
-    fn try_conversion_context(
+    /// Looks for the (direct) sub-expression of the `?` whose desugaring introduced `obligation`.
+    fn question_mark_operand(
         &self,
         obligation: &PredicateObligation<'tcx>,
-        trait_pred: ty::PolyTraitPredicate<'tcx>,
-        err: &mut Diag<'_>,
-    ) -> (bool, bool) {
-        let span = obligation.cause.span;
-        /// Look for the (direct) sub-expr of `?`, and return it if it's a `.` method call.
+    ) -> Option<&'tcx hir::Expr<'tcx>> {
         struct FindMethodSubexprOfTry {
             search_span: Span,
         }
@@ -1086,10 +1175,19 @@ fn visit_expr(&mut self, ex: &'v hir::Expr<'v>) -> Self::Result {
             }
         }
         let hir_id = self.tcx.local_def_id_to_hir_id(obligation.cause.body_id);
-        let Some(body_id) = self.tcx.hir_node(hir_id).body_id() else { return (false, false) };
-        let ControlFlow::Break(expr) =
-            (FindMethodSubexprOfTry { search_span: span }).visit_body(self.tcx.hir_body(body_id))
-        else {
+        let body_id = self.tcx.hir_node(hir_id).body_id()?;
+        (FindMethodSubexprOfTry { search_span: obligation.cause.span })
+            .visit_body(self.tcx.hir_body(body_id))
+            .break_value()
+    }
+
+    fn try_conversion_context(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+        err: &mut Diag<'_>,
+    ) -> (bool, bool) {
+        let Some(expr) = self.question_mark_operand(obligation) else {
             return (false, false);
         };
         let Some(typeck) = &self.typeck_results else {
@@ -1270,6 +1368,69 @@ fn visit_expr(&mut self, ex: &'v hir::Expr<'v>) -> Self::Result {
         (suggested, noted_missing_impl)
     }
 
+    /// When `?` failed to convert the error of type `found_ty` to `self_ty`, suggests either
+    /// implementing `From<found_ty>` for `self_ty` if one of them is local, or converting the
+    /// error with `map_err` otherwise.
+    fn suggest_question_mark_conversion(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+        err: &mut Diag<'_>,
+    ) {
+        let trait_pred =
+            self.tcx.erase_and_anonymize_regions(self.resolve_vars_if_possible(trait_pred));
+        let self_ty = trait_pred.skip_binder().self_ty();
+        let Some(found_ty) =
+            trait_pred.skip_binder().trait_ref.args.get(1).and_then(|a| a.as_type())
+        else {
+            return;
+        };
+        if (self_ty, found_ty).has_param()
+            || (self_ty, found_ty).has_aliases()
+            || (self_ty, found_ty).has_non_region_infer()
+            || (self_ty, found_ty).references_error()
+        {
+            return;
+        }
+        let local_adt = |ty: Ty<'tcx>| match *ty.kind() {
+            ty::Adt(def, args) if args.is_empty() => def.did().as_local(),
+            _ => None,
+        };
+        if let Some(def_id) = local_adt(self_ty).or_else(|| local_adt(found_ty)) {
+            let span = self.tcx.def_span(def_id);
+            let item_span = self.tcx.hir_expect_item(def_id).span;
+            if item_span.from_expansion() {
+                return;
+            }
+            let indent = self.tcx.sess.source_map().indentation_before(span).unwrap_or_default();
+            err.span_suggestion_verbose(
+                self.tcx.sess.source_map().span_extend_to_line(item_span).shrink_to_hi(),
+                format!("consider implementing `From<{found_ty}>` for `{self_ty}`"),
+                format!(
+                    "\n\n{indent}impl From<{found_ty}> for {self_ty} {{\n\
+                     {indent}    fn from(err: {found_ty}) -> Self {{\n\
+                     {indent}        todo!()\n\
+                     {indent}    }}\n\
+                     {indent}}}"
+                ),
+                Applicability::HasPlaceholders,
+            );
+        } else if let Some(expr) = self.question_mark_operand(obligation)
+            && matches!(
+                expr.kind,
+                hir::ExprKind::Call(..) | hir::ExprKind::MethodCall(..) | hir::ExprKind::Path(..)
+            )
+            && !expr.span.from_expansion()
+        {
+            err.span_suggestion_verbose(
+                expr.span.shrink_to_hi(),
+                "consider converting the error with `map_err`",
+                format!(".map_err(|err| /* convert `{found_ty}` into `{self_ty}` */)"),
+                Applicability::HasPlaceholders,
+            );
+        }
+    }
+
     fn note_missing_impl_for_question_mark(
         &self,
         err: &mut Diag<'_>,
@@ -1503,6 +1664,7 @@ pub(super) fn report_projection_error(
         &self,
         obligation: &PredicateObligation<'tcx>,
         error: &MismatchedProjectionTypes<'tcx>,
+        decorate: &dyn Fn(&mut Diag<'_>),
     ) -> ErrorGuaranteed {
         let predicate = self.resolve_vars_if_possible(obligation.predicate);
 
@@ -1715,6 +1877,7 @@ pub(super) fn report_projection_error(
                 Some(span),
             );
             self.note_obligation_cause(&mut diag, obligation);
+            decorate(&mut diag);
             diag.emit()
         })
     }
@@ -1802,6 +1965,9 @@ pub fn fuzzy_match_tys(
     ) -> Option<CandidateSimilarity> {
         /// returns the fuzzy category of a given type, or None
         /// if the type can be equated to any type.
+        ///
+        /// Keep in sync with `FuzzyShape` in `rustc_middle::ty::trait_def`, which is
+        /// used to prefilter the impls passed to this function.
         fn type_category(tcx: TyCtxt<'_>, t: Ty<'_>) -> Option<u32> {
             match t.kind() {
                 ty::Bool => Some(0),
@@ -1931,7 +2097,7 @@ pub(super) fn find_similar_impl_candidates(
     ) -> Vec<ImplCandidate<'tcx>> {
         let mut candidates: Vec<_> = self
             .tcx
-            .all_impls(trait_pred.def_id())
+            .impls_with_similar_self_ty(trait_pred.def_id(), trait_pred.skip_binder().self_ty())
             .filter_map(|def_id| {
                 let imp = self.tcx.impl_trait_header(def_id);
                 if imp.polarity != ty::ImplPolarity::Positive
@@ -1955,6 +2121,100 @@ pub(super) fn find_similar_impl_candidates(
         candidates
     }
 
+    /// Notes that there is an implementation of `trait_def_id` in another crate, which we
+    /// don't mention as its trait or self type can't be named from `body_def_id`.
+    fn note_private_impl(
+        &self,
+        trait_def_id: DefId,
+        body_def_id: LocalDefId,
+        err: &mut Diag<'_>,
+    ) -> bool {
+        let private_crate = self
+            .tcx
+            .all_impls(trait_def_id)
+            .filter(|&def_id| {
+                self.tcx.impl_polarity(def_id) == ty::ImplPolarity::Positive
+                    && !self.tcx.do_not_recommend_impl(def_id)
+            })
+            .find_map(|def_id| match impl_usable_from(self.tcx, def_id, body_def_id.to_def_id()) {
+                Err(ImplUnusable::Private(item))
+                    if !item.is_local() && self.tcx.is_user_visible_dep(item.krate) =>
+                {
+                    Some(item.krate)
+                }
+                _ => None,
+            });
+        let Some(krate) = private_crate else {
+            return false;
+        };
+        err.note(format!(
+            "an implementation of `{}` exists but is private to crate `{}`",
+            self.tcx.def_path_str(trait_def_id),
+            self.tcx.crate_name(krate),
+        ));
+        true
+    }
+
+    /// Notes that the trait of `trait_pred` is sealed, i.e. that it is defined in
+    /// another crate and requires a supertrait which can't be named from the body
+    /// and isn't implemented for the self type. Adding an impl of the trait can't
+    /// fix the error in this case, so we tell the user.
+    fn note_sealed_trait(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut Diag<'_>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let trait_def_id = trait_pred.def_id();
+        if trait_def_id.is_local() {
+            return;
+        }
+        // Only local types could otherwise implement a foreign trait, for
+        // anything else the user has to add a bound or use a different type.
+        let ty::Adt(def, _) = trait_pred.self_ty().skip_binder().kind() else {
+            return;
+        };
+        if !def.did().is_local() {
+            return;
+        }
+        let body_module = tcx.parent_module_from_def_id(obligation.cause.body_id).to_def_id();
+        let parent_map = tcx.visible_parent_map(());
+        let sealing_trait =
+            supertraits(tcx, trait_pred.map_bound(|p| p.trait_ref)).skip(1).find(|&supertrait| {
+                let def_id = supertrait.def_id();
+                let nameable = if def_id.is_local() {
+                    tcx.visibility(def_id).is_accessible_from(body_module, tcx)
+                } else {
+                    parent_map.contains_key(&def_id)
+                };
+                !nameable
+                    && !self.predicate_may_hold(&obligation.with(
+                        tcx,
+                        supertrait.map_bound(|trait_ref| ty::TraitPredicate {
+                            trait_ref,
+                            polarity: ty::PredicatePolarity::Positive,
+                        }),
+                    ))
+            });
+        let Some(sealing_trait) = sealing_trait else {
+            return;
+        };
+        let sealing_def_id = sealing_trait.def_id();
+        err.span_note(
+            tcx.def_span(trait_def_id),
+            format!(
+                "this trait is sealed and cannot be implemented outside crate `{}`",
+                tcx.crate_name(sealing_def_id.krate),
+            ),
+        );
+        err.note(format!(
+            "implementing `{}` requires `{}`, which is not accessible",
+            tcx.def_path_str(trait_def_id),
+            with_no_trimmed_paths!(tcx.def_path_str(sealing_def_id)),
+        ));
+    }
+
     pub(super) fn report_similar_impl_candidates(
         &self,
         impl_candidates: &[ImplCandidate<'tcx>],
@@ -2302,7 +2562,10 @@ pub(super) fn report_similar_impl_candidates(
                 // Mentioning implementers of `Copy`, `Debug` and friends is not useful.
                 return false;
             }
-            return report(alternative_candidates(def_id), err);
+            if report(alternative_candidates(def_id), err) {
+                return true;
+            }
+            return self.note_private_impl(def_id, body_def_id, err);
         }
 
         // Sort impl candidates so that ordering is consistent for UI tests.
@@ -2401,6 +2664,15 @@ fn get_parent_trait_ref(
                 let parent_trait_ref = self.resolve_vars_if_possible(data.parent_trait_pred);
                 match self.get_parent_trait_ref(&data.parent_code) {
                     Some(t) => Some(t),
+                    // Solver-internal marker traits are explained in a note instead.
+                    None if internal_marker_trait_requirement(
+                        self.tcx,
+                        parent_trait_ref.def_id(),
+                    )
+                    .is_some() =>
+                    {
+                        None
+                    }
                     None => {
                         let ty = parent_trait_ref.skip_binder().self_ty();
                         let span = TyCategory::from_ty(self.tcx, ty)
@@ -2569,11 +2841,49 @@ fn check_same_name_different_path(
                 self.tcx.def_path_str(other_trait_def_id),
                 trait_pred.print_modifiers_and_trait_path()
             ));
+            self.suggest_importing_other_trait(err, trait_def_id, other_trait_def_id);
             suggested = true;
         }
         suggested
     }
 
+    /// If the unsatisfied trait has been brought into scope by a single `use` item of
+    /// the local crate, suggest importing the similarly named trait which does hold instead.
+    fn suggest_importing_other_trait(
+        &self,
+        err: &mut Diag<'_>,
+        trait_def_id: DefId,
+        other_trait_def_id: DefId,
+    ) {
+        let mut imports = self.tcx.hir_crate_items(()).free_items().filter_map(|item_id| {
+            let item = self.tcx.hir_item(item_id);
+            let hir::ItemKind::Use(path, hir::UseKind::Single(_)) = item.kind else {
+                return None;
+            };
+            path.res
+                .type_ns
+                .is_some_and(|res| res.opt_def_id() == Some(trait_def_id))
+                .then_some((item.owner_id.def_id, path.span))
+        });
+        let Some((use_def_id, span)) = imports.next() else {
+            return;
+        };
+        if imports.next().is_some() || span.from_expansion() {
+            return;
+        }
+        let module = self.tcx.parent_module_from_def_id(use_def_id);
+        if !self.tcx.visibility(other_trait_def_id).is_accessible_from(module, self.tcx) {
+            return;
+        }
+        let path = with_crate_prefix!(self.tcx.def_path_str(other_trait_def_id));
+        err.span_suggestion_verbose(
+            span,
+            format!("consider importing `{path}` instead"),
+            path,
+            Applicability::MaybeIncorrect,
+        );
+    }
+
     /// If the `Self` type of the unsatisfied trait `trait_ref` implements a trait
     /// with the same path as `trait_ref`, a help message about a multiple different
     /// versions of the same crate is added to `err`. Otherwise if it implements another
@@ -2688,6 +2998,91 @@ pub fn note_obligation_cause(
         }
     }
 
+    /// Unresolved inference variables are printed as `_`, which isn't helpful
+    /// when looking at the leaf obligation of an error. Point at the expressions
+    /// whose type is unknown and the const parameters the const inference variables
+    /// were created for instead.
+    fn note_unresolved_vars(&self, err: &mut Diag<'_>, predicate: ty::Predicate<'tcx>) {
+        let predicate = self.resolve_vars_if_possible(predicate);
+        if !predicate.has_non_region_infer() {
+            return;
+        }
+
+        let mut collector = UnresolvedVarCollector {
+            infcx: self,
+            ty_vars: Default::default(),
+            const_vars: Default::default(),
+        };
+        let _ = predicate.visit_with(&mut collector);
+        // Multiple type variables may originate from the same expression, e.g. when
+        // calling a function with multiple type parameters, so group them by span.
+        let mut params_by_span: FxIndexMap<Span, Vec<Symbol>> = Default::default();
+        for vid in collector.ty_vars {
+            let origin = self.type_var_origin(vid);
+            if origin.span.is_dummy() || err.span.primary_spans().contains(&origin.span) {
+                continue;
+            }
+
+            let params = params_by_span.entry(origin.span).or_default();
+            if let Some(param_def_id) = origin.param_def_id {
+                params.push(self.tcx.item_name(param_def_id));
+            }
+        }
+        for (span, params) in params_by_span {
+            match listify(&params, |param| format!("`{param}`")) {
+                Some(names) => err.span_label(
+                    span,
+                    format!(
+                        "cannot infer the type{s} of the type parameter{s} {names}",
+                        s = pluralize!(params.len())
+                    ),
+                ),
+                None => err.span_label(span, "cannot infer the type of this"),
+            };
+        }
+        for vid in collector.const_vars {
+            if let Some(origin) = self.const_var_origin(vid)
+                && let Some(param_def_id) = origin.param_def_id
+            {
+                err.span_note(
+                    self.tcx.def_span(param_def_id),
+                    format!(
+                        "the const parameter `{}` declared here cannot be inferred",
+                        self.tcx.item_name(param_def_id)
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Checks whether `obligation` would hold in the environment of the item the
+    /// current item is checked against, e.g. the trait item of an impl item, and
+    /// notes the where-clauses which are missing from the current environment.
+    fn note_param_env_diff(&self, err: &mut Diag<'_>, obligation: &PredicateObligation<'tcx>) {
+        let Some((sibling_def_id, sibling_env)) =
+            param_env_diff::sibling_param_env(self.tcx, obligation.cause.body_id.to_def_id())
+        else {
+            return;
+        };
+        let predicate = self.resolve_vars_if_possible(obligation.predicate);
+        let Some(missing) = param_env_diff::missing_where_clauses(
+            self,
+            predicate,
+            obligation.param_env,
+            sibling_env,
+        ) else {
+            return;
+        };
+
+        let clauses = listify(&missing, |clause| format!("`{clause}`")).unwrap();
+        err.note(format!(
+            "`{predicate}` holds in the environment of `{}`, which additionally has the \
+             where-clause{} {clauses}",
+            self.tcx.def_path_str(sibling_def_id),
+            pluralize!(missing.len()),
+        ));
+    }
+
     pub(super) fn is_recursive_obligation(
         &self,
         obligated_types: &mut Vec<Ty<'tcx>>,
@@ -3586,3 +3981,37 @@ fn report_not_const_evaluatable_error(
         }
     }
 }
+
+/// Collects the unresolved inference variables of a value in the order in which
+/// they first occur.
+struct UnresolvedVarCollector<'a, 'tcx> {
+    infcx: &'a InferCtxt<'tcx>,
+    ty_vars: FxIndexSet<ty::TyVid>,
+    const_vars: FxIndexSet<ty::ConstVid>,
+}
+
+impl<'tcx> TypeVisitor<TyCtxt<'tcx>> for UnresolvedVarCollector<'_, 'tcx> {
+    fn visit_ty(&mut self, ty: Ty<'tcx>) {
+        match *ty.kind() {
+            ty::Infer(ty::TyVar(vid)) => {
+                self.ty_vars.insert(self.infcx.root_var(vid));
+            }
+            // The generic arguments of closures and coroutines aren't printed,
+            // so their inference variables never show up as `_` in the error.
+            ty::Closure(..)
+            | ty::CoroutineClosure(..)
+            | ty::Coroutine(..)
+            | ty::CoroutineWitness(..) => {}
+            _ if ty.has_non_region_infer() => ty.super_visit_with(self),
+            _ => {}
+        }
+    }
+
+    fn visit_const(&mut self, ct: ty::Const<'tcx>) {
+        if let ty::ConstKind::Infer(ty::InferConst::Var(vid)) = ct.kind() {
+            self.const_vars.insert(self.infcx.root_const_var(vid));
+        } else if ct.has_non_region_infer() {
+            ct.super_visit_with(self)
+        }
+    }
+}
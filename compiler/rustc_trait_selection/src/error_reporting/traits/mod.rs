@@ -9,25 +9,33 @@
 
 use std::{fmt, iter};
 
+use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::{FxIndexMap, FxIndexSet};
+use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_data_structures::unord::UnordSet;
 use rustc_errors::{Applicability, Diag, E0038, E0276, MultiSpan, struct_span_code_err};
 use rustc_hir::def_id::{DefId, LOCAL_CRATE, LocalDefId};
 use rustc_hir::intravisit::Visitor;
-use rustc_hir::{self as hir, AmbigArg};
+use rustc_hir::{self as hir, AmbigArg, LangItem};
+use rustc_infer::infer::TypeFreshener;
 use rustc_infer::traits::solve::Goal;
 use rustc_infer::traits::{
     DynCompatibilityViolation, Obligation, ObligationCause, ObligationCauseCode,
     PredicateObligation, SelectionError,
 };
+use rustc_middle::ty::fast_reject::DeepRejectCtxt;
 use rustc_middle::ty::print::{PrintTraitRefExt as _, with_no_trimmed_paths};
-use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitableExt as _};
+use rustc_middle::ty::{self, Ty, TyCtxt, TypeFoldable, TypeVisitableExt as _};
 use rustc_session::cstore::{ExternCrate, ExternCrateSource};
 use rustc_span::{DesugaringKind, ErrorGuaranteed, ExpnKind, Span};
 use tracing::{info, instrument};
 
 pub use self::overflow::*;
 use crate::error_reporting::TypeErrCtxt;
+use crate::errors::{
+    ConsideredWhereClauses, GoalTooComplex, NightlyOnlyImpl, RefinementExceededLimits,
+    RequiredInExternalMacro, ReservedImpl,
+};
 use crate::traits::{FulfillmentError, FulfillmentErrorCode};
 
 // When outputting impl candidates, prefer showing those that are more similar.
@@ -170,7 +178,7 @@ enum ErrorSortKey {
             Coerce,
             WellFormed,
         }
-        errors.sort_by_key(|e| {
+        let sort_key = |e: &FulfillmentError<'tcx>| {
             let maybe_sizedness_did = match e.obligation.predicate.kind().skip_binder() {
                 ty::PredicateKind::Clause(ty::ClauseKind::Trait(pred)) => Some(pred.def_id()),
                 ty::PredicateKind::Clause(ty::ClauseKind::HostEffect(pred)) => Some(pred.def_id()),
@@ -203,7 +211,31 @@ enum ErrorSortKey {
                 }
                 _ => ErrorSortKey::OtherKind,
             }
-        });
+        };
+        if self.tcx.sess.threads() > 1 {
+            // The parallel frontend may collect errors in a different order each time, so
+            // also sort them by their span and predicate to keep the output deterministic.
+            errors.sort_by_cached_key(|e| {
+                (
+                    sort_key(e),
+                    e.obligation.cause.span,
+                    self.predicate_fingerprint(e.obligation.predicate),
+                )
+            });
+        } else {
+            errors.sort_by_key(sort_key);
+        }
+
+        // Point at the field instead of the `derive` attribute when a derive macro's generated
+        // code requires a bound that the field's type doesn't satisfy. Errors for the same
+        // field are then deduplicated like any other errors sharing a span. Derive macros
+        // usually generate several bodies using each field, the identical errors reported
+        // for each of them get deduplicated when emitting them.
+        for error in &mut errors {
+            if let Some(span) = self.derive_field_span(&error.obligation) {
+                error.obligation.cause.span = span;
+            }
+        }
 
         for (index, error) in errors.iter().enumerate() {
             // We want to ignore desugarings here: spans are equivalent even
@@ -286,12 +318,118 @@ enum ErrorSortKey {
         reported.unwrap_or_else(|| self.dcx().delayed_bug("failed to report fulfillment errors"))
     }
 
+    /// For an obligation from code generated by a `derive` macro with the span of the
+    /// `derive` attribute, as is usual for proc-macro derives, returns the span of the type of
+    /// the only field which requires the unsatisfied bound, if any.
+    fn derive_field_span(&self, obligation: &PredicateObligation<'tcx>) -> Option<Span> {
+        let span = obligation.cause.span;
+        if !span.in_derive_expansion() || !span.ctxt().outer_expn_data().call_site.contains(span) {
+            return None;
+        }
+        let leaf = self.resolve_vars_if_possible(obligation.predicate.as_trait_clause()?);
+        let (_, root) = obligation.cause.code().peel_derives_with_predicate();
+        let self_tys: Vec<_> = iter::once(leaf)
+            .chain(root.map(|root| self.resolve_vars_if_possible(root)))
+            .map(|pred| pred.skip_binder().self_ty().peel_refs())
+            .collect();
+
+        let body_def_id = self.tcx.typeck_root_def_id(obligation.cause.body_id.to_def_id());
+        let impl_def_id = self.tcx.impl_of_assoc(body_def_id)?;
+        let ty::Adt(adt, args) = *self.tcx.type_of(impl_def_id).instantiate_identity().kind()
+        else {
+            return None;
+        };
+        let mut fields =
+            adt.all_fields().filter(|field| self_tys.contains(&field.ty(self.tcx, args)));
+        let field = fields.next()?;
+        if fields.next().is_some() {
+            return None;
+        }
+        let Some(hir::Node::Field(field)) = self.tcx.hir_get_if_local(field.did) else {
+            return None;
+        };
+        Some(field.ty.span.with_ctxt(span.ctxt()))
+    }
+
+    /// A hash of `predicate` which doesn't depend on the order in which inference
+    /// variables have been created, used to sort errors deterministically.
+    fn predicate_fingerprint(&self, predicate: ty::Predicate<'tcx>) -> Fingerprint {
+        let predicate = self.resolve_vars_if_possible(predicate);
+        let predicate = predicate.fold_with(&mut TypeFreshener::new(self));
+        self.tcx.with_stable_hashing_context(|mut hcx| {
+            let mut hasher = StableHasher::new();
+            hcx.while_hashing_spans(false, |hcx| predicate.hash_stable(hcx, &mut hasher));
+            hasher.finish()
+        })
+    }
+
+    /// `-Zdump-goal-env`: Notes the where-clauses of the param-env of `obligation`
+    /// which may be used to prove it, i.e. the where-clauses the trait solver
+    /// considers as candidates.
+    fn note_considered_where_clauses(
+        &self,
+        err: &mut Diag<'_>,
+        obligation: &PredicateObligation<'tcx>,
+    ) {
+        let predicate = self.resolve_vars_if_possible(obligation.predicate);
+        let drcx = DeepRejectCtxt::relate_rigid_infer(self.tcx);
+        let clauses: Vec<_> = obligation
+            .param_env
+            .caller_bounds()
+            .iter()
+            .filter(|clause| match (predicate.kind().skip_binder(), clause.kind().skip_binder()) {
+                (
+                    ty::PredicateKind::Clause(ty::ClauseKind::Trait(goal)),
+                    ty::ClauseKind::Trait(assumption),
+                ) => {
+                    goal.def_id() == assumption.def_id()
+                        && goal.polarity == assumption.polarity
+                        && drcx.args_may_unify(assumption.trait_ref.args, goal.trait_ref.args)
+                }
+                (
+                    ty::PredicateKind::Clause(ty::ClauseKind::HostEffect(goal)),
+                    ty::ClauseKind::HostEffect(assumption),
+                ) => {
+                    goal.def_id() == assumption.def_id()
+                        && drcx.args_may_unify(assumption.trait_ref.args, goal.trait_ref.args)
+                }
+                (
+                    ty::PredicateKind::Clause(ty::ClauseKind::Projection(goal)),
+                    ty::ClauseKind::Projection(assumption),
+                ) => {
+                    goal.def_id() == assumption.def_id()
+                        && drcx.args_may_unify(
+                            assumption.projection_term.args,
+                            goal.projection_term.args,
+                        )
+                }
+                (ty::PredicateKind::NormalizesTo(goal), ty::ClauseKind::Projection(assumption)) => {
+                    goal.def_id() == assumption.def_id()
+                        && drcx.args_may_unify(assumption.projection_term.args, goal.alias.args)
+                }
+                _ => false,
+            })
+            .map(|clause| format!("\n  {clause}"))
+            .collect();
+        err.subdiagnostic(ConsideredWhereClauses {
+            predicate: predicate.to_string(),
+            count: clauses.len(),
+            clauses: clauses.concat(),
+        });
+    }
+
     #[instrument(skip(self), level = "debug")]
     fn report_fulfillment_error(&self, error: &FulfillmentError<'tcx>) -> ErrorGuaranteed {
         let mut error = FulfillmentError {
             obligation: error.obligation.clone(),
             code: error.code.clone(),
             root_obligation: error.root_obligation.clone(),
+            refinement_exceeded_limits: error.refinement_exceeded_limits,
+            ambiguous_impls: error.ambiguous_impls.clone(),
+            reservation_impl: error.reservation_impl,
+            nightly_only_impls: error.nightly_only_impls.clone(),
+            macro_span: error.macro_span,
+            exceeded_memory_limit: error.exceeded_memory_limit,
         };
         if matches!(
             error.code,
@@ -302,30 +440,48 @@ fn report_fulfillment_error(&self, error: &FulfillmentError<'tcx>) -> ErrorGuara
             error.code = FulfillmentErrorCode::Select(SelectionError::Unimplemented);
         }
 
+        let decorate = |err: &mut Diag<'_>| self.note_fulfillment_error_details(err, &error);
         match error.code {
             FulfillmentErrorCode::Select(ref selection_error) => self.report_selection_error(
                 error.obligation.clone(),
                 &error.root_obligation,
                 selection_error,
+                &decorate,
             ),
             FulfillmentErrorCode::Project(ref e) => {
-                self.report_projection_error(&error.obligation, e)
+                self.report_projection_error(&error.obligation, e, &decorate)
             }
             FulfillmentErrorCode::Ambiguity { overflow: None } => {
-                self.maybe_report_ambiguity(&error.obligation)
+                self.maybe_report_ambiguity(&error.obligation, &error.ambiguous_impls, &decorate)
             }
-            FulfillmentErrorCode::Ambiguity { overflow: Some(suggest_increasing_limit) } => {
-                self.report_overflow_no_abort(error.obligation.clone(), suggest_increasing_limit)
+            FulfillmentErrorCode::Ambiguity { overflow: Some(_) }
+                if error.exceeded_memory_limit =>
+            {
+                let mut err = self.dcx().create_err(GoalTooComplex {
+                    span: error.obligation.cause.span,
+                    predicate: self.resolve_vars_if_possible(error.obligation.predicate),
+                    limit: self.tcx.sess.opts.unstable_opts.next_solver_memory_limit,
+                });
+                decorate(&mut err);
+                err.emit()
             }
-            FulfillmentErrorCode::Subtype(ref expected_found, ref err) => self
-                .report_mismatched_types(
+            FulfillmentErrorCode::Ambiguity { overflow: Some(suggest_increasing_limit) } => self
+                .report_overflow_no_abort(
+                    error.obligation.clone(),
+                    suggest_increasing_limit,
+                    &decorate,
+                ),
+            FulfillmentErrorCode::Subtype(ref expected_found, ref err) => {
+                let mut diag = self.report_mismatched_types(
                     &error.obligation.cause,
                     error.obligation.param_env,
                     expected_found.expected,
                     expected_found.found,
                     *err,
-                )
-                .emit(),
+                );
+                decorate(&mut diag);
+                diag.emit()
+            }
             FulfillmentErrorCode::ConstEquate(ref expected_found, ref err) => {
                 let mut diag = self.report_mismatched_consts(
                     &error.obligation.cause,
@@ -348,12 +504,32 @@ fn report_fulfillment_error(&self, error: &FulfillmentError<'tcx>) -> ErrorGuara
                         &mut Default::default(),
                     );
                 }
+                decorate(&mut diag);
                 diag.emit()
             }
             FulfillmentErrorCode::Cycle(ref cycle) => self.report_overflow_obligation_cycle(cycle),
         }
     }
 
+    /// Adds the notes for the details of `error` which are not specific to its kind.
+    fn note_fulfillment_error_details(&self, err: &mut Diag<'_>, error: &FulfillmentError<'tcx>) {
+        if error.refinement_exceeded_limits {
+            err.subdiagnostic(RefinementExceededLimits);
+        }
+        if let Some((impl_def_id, message)) = error.reservation_impl {
+            err.subdiagnostic(ReservedImpl { span: self.tcx.def_span(impl_def_id), message });
+        }
+        for &(impl_def_id, feature) in &error.nightly_only_impls {
+            err.subdiagnostic(NightlyOnlyImpl { span: self.tcx.def_span(impl_def_id), feature });
+        }
+        if let Some(span) = error.macro_span {
+            err.subdiagnostic(RequiredInExternalMacro { span });
+        }
+        if self.tcx.sess.opts.unstable_opts.dump_goal_env {
+            self.note_considered_where_clauses(err, &error.obligation);
+        }
+    }
+
     /// If the crates of `expected_def_id` and `trait_def_id` are imported as extern crate
     /// under the same name (`extern crate foo as a` and `extern crate bar as a`) returns true,
     /// otherwise returns false.
@@ -449,6 +625,21 @@ pub fn check_same_definition_different_crate<F>(
     }
 }
 
+/// Describes what the solver-internal marker trait `def_id` requires of its self type, for
+/// use in place of its name, which users have never seen and can't do anything about.
+pub(crate) fn internal_marker_trait_requirement(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+) -> Option<&'static str> {
+    match tcx.as_lang_item(def_id)? {
+        LangItem::BikeshedGuaranteedNoDrop => {
+            Some("never need to be dropped, so it has to be `Copy`, a reference or `ManuallyDrop`")
+        }
+        LangItem::FnPtrTrait => Some("be a function pointer"),
+        _ => None,
+    }
+}
+
 /// Recovers the "impl X for Y" signature from `impl_def_id` and returns it as a
 /// string.
 pub(crate) fn to_pretty_impl_header(tcx: TyCtxt<'_>, impl_def_id: DefId) -> Option<String> {
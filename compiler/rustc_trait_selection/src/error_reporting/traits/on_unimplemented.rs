@@ -2,6 +2,7 @@
 use std::path::PathBuf;
 
 use rustc_ast::{LitKind, MetaItem, MetaItemInner, MetaItemKind, MetaItemLit};
+use rustc_data_structures::unord::UnordMap;
 use rustc_errors::codes::*;
 use rustc_errors::{ErrorGuaranteed, struct_span_code_err};
 use rustc_hir as hir;
@@ -11,7 +12,9 @@
 use rustc_macros::LintDiagnostic;
 use rustc_middle::bug;
 use rustc_middle::ty::print::PrintTraitRefExt;
-use rustc_middle::ty::{self, GenericArgsRef, GenericParamDef, GenericParamDefKind, TyCtxt};
+use rustc_middle::ty::{
+    self, GenericArgsRef, GenericParamDef, GenericParamDefKind, TyCtxt, TypeVisitableExt,
+};
 use rustc_session::lint::builtin::{
     MALFORMED_DIAGNOSTIC_ATTRIBUTES, MALFORMED_DIAGNOSTIC_FORMAT_LITERALS,
 };
@@ -28,6 +31,7 @@
 };
 use crate::errors::{InvalidOnClause, NoValueInOnUnimplemented};
 use crate::infer::InferCtxtExt;
+use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
 
 impl<'tcx> TypeErrCtxt<'_, 'tcx> {
     fn impl_similar_to(
@@ -104,10 +108,16 @@ pub fn on_unimplemented_note(
         if trait_pred.polarity() != ty::PredicatePolarity::Positive {
             return OnUnimplementedNote::default();
         }
-        let (condition_options, format_args) =
+        let (mut condition_options, format_args) =
             self.on_unimplemented_components(trait_pred, obligation, long_ty_path);
         if let Ok(Some(command)) = OnUnimplementedDirective::of_item(self.tcx, trait_pred.def_id())
         {
+            condition_options.implemented_traits = self.implemented_condition_traits(
+                &command,
+                trait_pred.def_id(),
+                trait_pred.skip_binder().trait_ref.args,
+                obligation,
+            );
             command.evaluate(
                 self.tcx,
                 trait_pred.skip_binder().trait_ref,
@@ -284,6 +294,7 @@ pub(crate) fn on_unimplemented_components(
             crate_local,
             direct,
             generic_args,
+            implemented_traits: Vec::new(),
         };
 
         // Unlike the generic_args earlier,
@@ -316,6 +327,59 @@ pub(crate) fn on_unimplemented_components(
         let format_args = FormatArgs { this, trait_sugared, generic_args, item_context };
         (condition_options, format_args)
     }
+
+    /// Speculatively checks the `implements(..)` conditions of `directive`, which is
+    /// attached to `item_def_id`, and returns the ones which hold for `args`.
+    pub(crate) fn implemented_condition_traits(
+        &self,
+        directive: &OnUnimplementedDirective,
+        item_def_id: DefId,
+        args: GenericArgsRef<'tcx>,
+        obligation: &PredicateObligation<'tcx>,
+    ) -> Vec<(Symbol, Symbol)> {
+        let tcx = self.tcx;
+        let generics = tcx.generics_of(item_def_id);
+        let mut implements = directive.implements();
+        implements.sort_by(|a, b| (a.0.as_str(), a.1.as_str()).cmp(&(b.0.as_str(), b.1.as_str())));
+        implements.dedup();
+        implements.retain(|&(param, trait_path)| {
+            let Some(param) = generics.own_params.iter().find(|p| p.name == param) else {
+                return false;
+            };
+            let Some(ty) = args[param.index as usize].as_type() else {
+                return false;
+            };
+            let ty = self.resolve_vars_if_possible(ty);
+            if ty.has_escaping_bound_vars() {
+                return false;
+            }
+            // Like the values we match against, the trait has to be named by its
+            // canonical def path.
+            let Some(&trait_def_id) = tcx.traits_by_def_path(()).get(&trait_path) else {
+                return false;
+            };
+            if tcx.generics_of(trait_def_id).count() != 1 {
+                return false;
+            }
+            self.predicate_must_hold_modulo_regions(
+                &obligation.with(tcx, ty::TraitRef::new(tcx, trait_def_id, [ty])),
+            )
+        });
+        implements
+    }
+}
+
+pub(crate) fn traits_by_def_path(tcx: TyCtxt<'_>, (): ()) -> UnordMap<Symbol, DefId> {
+    let mut traits = UnordMap::default();
+    for def_id in tcx.all_traits_including_private() {
+        let path = ty::print::with_no_trimmed_paths!(ty::print::with_no_visible_paths!(
+            tcx.def_path_str(def_id)
+        ));
+        // If several crates define a trait with the same path, prefer the first one, which is
+        // the local crate's if it defines one.
+        traits.entry(Symbol::intern(&path)).or_insert(def_id);
+    }
+    traits
 }
 
 /// Represents a format string in a on_unimplemented attribute,
@@ -751,6 +815,16 @@ fn parse_attribute(
         result
     }
 
+    /// Returns the `implements(..)` conditions of this directive and its
+    /// subcommands, see [`OnUnimplementedCondition::implements`].
+    pub(crate) fn implements(&self) -> Vec<(Symbol, Symbol)> {
+        self.subcommands
+            .iter()
+            .flat_map(|command| command.implements())
+            .chain(self.condition.iter().flat_map(|condition| condition.implements()))
+            .collect()
+    }
+
     pub(crate) fn evaluate(
         &self,
         tcx: TyCtxt<'tcx>,
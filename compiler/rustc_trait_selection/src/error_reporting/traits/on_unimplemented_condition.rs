@@ -23,9 +23,20 @@ pub(crate) fn matches_predicate(&self, options: &ConditionOptions) -> bool {
                 let value = value.format(&options.generic_args);
                 options.contains(*name, value)
             }
+            FlagOrNv::Implements(Implements { param, trait_path }) => {
+                options.implemented_traits.contains(&(*param, *trait_path))
+            }
         })
     }
 
+    /// Returns all `implements(..)` conditions of this filter, as pairs of the
+    /// generic parameter and the path of the trait it has to implement.
+    pub(crate) fn implements(&self) -> Vec<(Symbol, Symbol)> {
+        let mut implements = Vec::new();
+        self.pred.collect_implements(&mut implements);
+        implements
+    }
+
     pub(crate) fn parse(
         input: &MetaItemInner,
         generics: &[Symbol],
@@ -46,6 +57,8 @@ enum Predicate {
     Flag(Flag),
     /// A match, like `on(Rhs = "Whatever")`.
     Match(NameValue),
+    /// A trait implementation, like `on(implements(T = "core::marker::Copy"))`.
+    Implements(Implements),
     /// Negation, like `on(not($pred))`.
     Not(Box<Predicate>),
     /// True if all predicates are true, like `on(all($a, $b, $c))`.
@@ -74,6 +87,15 @@ fn parse(input: &MetaItemInner, generics: &[Symbol]) -> Result<Self, InvalidOnCl
             MetaItemKind::List(ref mis) => match predicate.name {
                 sym::any => Ok(Predicate::Any(Predicate::parse_sequence(mis, generics)?)),
                 sym::all => Ok(Predicate::All(Predicate::parse_sequence(mis, generics)?)),
+                sym::implements => match &**mis {
+                    [one] => Ok(Predicate::Implements(Implements::parse(one, generics)?)),
+                    [first, .., last] => Err(InvalidOnClause::ExpectedOneImplementsClause {
+                        span: first.span().to(last.span()),
+                    }),
+                    [] => {
+                        Err(InvalidOnClause::ExpectedOneImplementsClause { span: meta_item.span })
+                    }
+                },
                 sym::not => match &**mis {
                     [one] => Ok(Predicate::Not(Box::new(Predicate::parse(one, generics)?))),
                     [first, .., last] => Err(InvalidOnClause::ExpectedOnePredInNot {
@@ -109,11 +131,25 @@ fn eval(&self, eval: &mut impl FnMut(FlagOrNv<'_>) -> bool) -> bool {
         match self {
             Predicate::Flag(flag) => eval(FlagOrNv::Flag(flag)),
             Predicate::Match(nv) => eval(FlagOrNv::NameValue(nv)),
+            Predicate::Implements(implements) => eval(FlagOrNv::Implements(implements)),
             Predicate::Not(not) => !not.eval(eval),
             Predicate::All(preds) => preds.into_iter().all(|pred| pred.eval(eval)),
             Predicate::Any(preds) => preds.into_iter().any(|pred| pred.eval(eval)),
         }
     }
+
+    fn collect_implements(&self, implements: &mut Vec<(Symbol, Symbol)>) {
+        match self {
+            Predicate::Flag(_) | Predicate::Match(_) => {}
+            Predicate::Implements(Implements { param, trait_path }) => {
+                implements.push((*param, *trait_path))
+            }
+            Predicate::Not(not) => not.collect_implements(implements),
+            Predicate::All(preds) | Predicate::Any(preds) => {
+                preds.iter().for_each(|pred| pred.collect_implements(implements))
+            }
+        }
+    }
 }
 
 /// Represents a `MetaWord` in an `on`-filter.
@@ -173,10 +209,44 @@ fn parse(Ident { name, span }: Ident, generics: &[Symbol]) -> Result<Self, Inval
     }
 }
 
+/// An `implements(..)` predicate in an `on`-filter.
+///
+/// For example, `#[rustc_on_unimplemented(on(implements(T = "core::marker::Copy"), note = "hello"))]`.
+/// This is checked by trying to prove that the generic argument for `param` implements
+/// the trait, which has to be given with its full path and must not have any generic
+/// parameters besides `Self`.
+#[derive(Debug, Clone, Copy)]
+struct Implements {
+    /// `Self` or the name of a generic parameter.
+    param: Symbol,
+    trait_path: Symbol,
+}
+
+impl Implements {
+    fn parse(input: &MetaItemInner, generics: &[Symbol]) -> Result<Self, InvalidOnClause> {
+        let invalid = || InvalidOnClause::ExpectedOneImplementsClause { span: input.span() };
+        let meta_item = input.meta_item().ok_or_else(invalid)?;
+        let (Some(param), MetaItemKind::NameValue(MetaItemLit { symbol: trait_path, .. })) =
+            (meta_item.ident(), &meta_item.kind)
+        else {
+            return Err(invalid());
+        };
+        match param.name {
+            kw::SelfUpper => {}
+            generic if generics.contains(&generic) => {}
+            invalid_name => {
+                return Err(InvalidOnClause::InvalidName { invalid_name, span: param.span });
+            }
+        }
+        Ok(Implements { param: param.name, trait_path: *trait_path })
+    }
+}
+
 #[derive(Debug, Clone)]
 enum FlagOrNv<'p> {
     Flag(&'p Flag),
     NameValue(&'p NameValue),
+    Implements(&'p Implements),
 }
 
 /// Represents a value inside an `on` filter.
@@ -286,6 +356,7 @@ fn format(&self, generic_args: &[(Symbol, String)]) -> String {
 ///         ("R", "core::option::Option<core::convert::Infallible>"),
 ///         ("R", "core::option::Option<T>" ),
 ///     ],
+///     implemented_traits: [],
 /// }
 /// ```
 #[derive(Debug)]
@@ -301,6 +372,9 @@ pub(crate) struct ConditionOptions {
     pub(crate) direct: bool,
     // A list of the generic arguments and their reified types.
     pub(crate) generic_args: Vec<(Symbol, String)>,
+    /// The `implements(..)` conditions which hold, as pairs of the generic
+    /// parameter and the path of the trait.
+    pub(crate) implemented_traits: Vec<(Symbol, Symbol)>,
 }
 
 impl ConditionOptions {
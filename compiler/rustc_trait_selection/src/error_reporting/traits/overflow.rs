@@ -4,6 +4,8 @@
 use rustc_hir::def::Namespace;
 use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_hir::limit::Limit;
+use rustc_infer::traits::query::NoSolution;
+use rustc_infer::traits::solve::{Certainty, MaybeCause};
 use rustc_infer::traits::{Obligation, PredicateObligation};
 use rustc_middle::ty::print::{FmtPrinter, Print};
 use rustc_middle::ty::{self, TyCtxt, Upcast};
@@ -11,20 +13,36 @@
 use tracing::debug;
 
 use crate::error_reporting::TypeErrCtxt;
+use crate::solve::{SolverDelegate, SolverDelegateEvalExt as _, nonterminating_impl};
 
 pub enum OverflowCause<'tcx> {
     DeeplyNormalize(ty::AliasTerm<'tcx>),
     TraitSolver(ty::Predicate<'tcx>),
 }
 
+/// The number of times we double the recursion limit when looking for a
+/// limit which avoids an overflow, see `TypeErrCtxt::sufficient_recursion_limit`.
+const MAX_RECURSION_LIMIT_DOUBLINGS: usize = 2;
+
+fn double_limit(limit: Limit) -> Limit {
+    match limit {
+        Limit(0) => Limit(2),
+        limit => limit * 2,
+    }
+}
+
 pub fn suggest_new_overflow_limit<'tcx, G: EmissionGuarantee>(
     tcx: TyCtxt<'tcx>,
     err: &mut Diag<'_, G>,
 ) {
-    let suggested_limit = match tcx.recursion_limit() {
-        Limit(0) => Limit(2),
-        limit => limit * 2,
-    };
+    suggest_overflow_limit(tcx, err, double_limit(tcx.recursion_limit()));
+}
+
+fn suggest_overflow_limit<'tcx, G: EmissionGuarantee>(
+    tcx: TyCtxt<'tcx>,
+    err: &mut Diag<'_, G>,
+    suggested_limit: Limit,
+) {
     err.help(format!(
         "consider increasing the recursion limit by adding a \
          `#![recursion_limit = \"{}\"]` attribute to your crate (`{}`)",
@@ -176,14 +194,49 @@ pub fn report_overflow_no_abort(
         &self,
         obligation: PredicateObligation<'tcx>,
         suggest_increasing_limit: bool,
+        decorate: &dyn Fn(&mut Diag<'_>),
     ) -> ErrorGuaranteed {
         let obligation = self.resolve_vars_if_possible(obligation);
+        // The new solver allows us to check which limit is actually needed.
+        let check_limit = suggest_increasing_limit && self.next_trait_solver();
         let mut err = self.build_overflow_error(
             OverflowCause::TraitSolver(obligation.predicate),
             obligation.cause.span,
-            suggest_increasing_limit,
+            suggest_increasing_limit && !check_limit,
         );
+        if check_limit {
+            if let Some(impl_def_id) = nonterminating_impl(self, &obligation) {
+                err.span_note(
+                    self.tcx.def_span(impl_def_id),
+                    "this recursion will never terminate, caused by this impl",
+                );
+            } else if let Some(limit) = self.sufficient_recursion_limit(&obligation) {
+                suggest_overflow_limit(self.tcx, &mut err, limit);
+            } else {
+                err.note(
+                    "increasing the recursion limit does not help, \
+                     this is likely caused by an infinite recursion",
+                );
+            }
+        }
         self.note_obligation_cause(&mut err, &obligation);
+        decorate(&mut err);
         err.emit()
     }
+
+    /// Returns the smallest limit, doubling the recursion limit of the crate at most
+    /// [`MAX_RECURSION_LIMIT_DOUBLINGS`] times, with which `obligation` no longer
+    /// overflows. This must only be used with the new solver.
+    fn sufficient_recursion_limit(&self, obligation: &PredicateObligation<'tcx>) -> Option<Limit> {
+        let delegate = <&SolverDelegate<'tcx>>::from(&**self);
+        let mut limit = self.tcx.recursion_limit();
+        for _ in 0..MAX_RECURSION_LIMIT_DOUBLINGS {
+            limit = double_limit(limit);
+            match delegate.probe_root_goal_with_depth(limit.0, obligation.as_goal()) {
+                Ok(Certainty::Maybe { cause: MaybeCause::Overflow { .. }, .. }) => {}
+                Ok(_) | Err(NoSolution) => return Some(limit),
+            }
+        }
+        None
+    }
 }
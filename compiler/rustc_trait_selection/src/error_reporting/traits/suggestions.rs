@@ -7,11 +7,11 @@
 
 use itertools::{EitherOrBoth, Itertools};
 use rustc_abi::ExternAbi;
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashSet, FxIndexSet};
 use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_errors::codes::*;
 use rustc_errors::{
-    Applicability, Diag, EmissionGuarantee, MultiSpan, Style, SuggestionStyle, pluralize,
+    Applicability, Diag, EmissionGuarantee, MultiSpan, Style, SuggestionStyle, listify, pluralize,
     struct_span_code_err,
 };
 use rustc_hir::def::{CtorOf, DefKind, Res};
@@ -37,6 +37,7 @@
 };
 use rustc_middle::{bug, span_bug};
 use rustc_span::def_id::LocalDefId;
+use rustc_span::edition::Edition;
 use rustc_span::{
     BytePos, DUMMY_SP, DesugaringKind, ExpnKind, Ident, MacroKind, Span, Symbol, kw, sym,
 };
@@ -44,7 +45,7 @@
 
 use super::{
     DefIdOrName, FindExprBySpan, ImplCandidate, Obligation, ObligationCause, ObligationCauseCode,
-    PredicateObligation,
+    PredicateObligation, internal_marker_trait_requirement,
 };
 use crate::error_reporting::TypeErrCtxt;
 use crate::errors;
@@ -729,6 +730,104 @@ pub(super) fn suggest_dereferences(
         false
     }
 
+    /// Walk the `Deref` chain of a function argument whose type doesn't satisfy a trait bound,
+    /// e.g. an `Arc<T>` passed where `T: Trait` would hold, and point out the first target that
+    /// satisfies it. This complements `suggest_dereferences`, which only suggests derefs that
+    /// move the target out, by suggesting to borrow it, or by noting the target if the bound
+    /// can only be satisfied by value.
+    pub(super) fn suggest_borrowing_deref_target(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut Diag<'_>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) -> bool {
+        let ObligationCauseCode::FunctionArg { arg_hir_id, call_hir_id, .. } =
+            obligation.cause.code()
+        else {
+            return false;
+        };
+        let Some(typeck_results) = &self.typeck_results else {
+            return false;
+        };
+        let hir::Node::Expr(expr) = self.tcx.hir_node(*arg_hir_id) else {
+            return false;
+        };
+        let Some(arg_ty) = typeck_results.expr_ty_adjusted_opt(expr) else {
+            return false;
+        };
+        let trait_pred = self.resolve_vars_if_possible(trait_pred);
+        // Auto traits are usually not implemented because of the smart pointer itself, e.g.
+        // `Rc<T>: !Send`, so borrowing its target is rarely what the user wants. The same
+        // holds for the traits `suggest_add_reference_to_arg` never suggests borrowing for.
+        if trait_pred.has_non_region_infer()
+            || self.tcx.trait_is_auto(trait_pred.def_id())
+            || matches!(
+                self.tcx.as_lang_item(trait_pred.def_id()),
+                Some(LangItem::Copy | LangItem::Clone | LangItem::Unpin | LangItem::Sized)
+            )
+        {
+            return false;
+        }
+        let self_ty = self.tcx.instantiate_bound_regions_with_erased(trait_pred.self_ty());
+        if !self.can_eq(obligation.param_env, self_ty, arg_ty) {
+            return false;
+        }
+
+        let holds = |ty, obligations: &[PredicateObligation<'tcx>]| {
+            // Remapping bound vars here
+            let obligation = self.mk_trait_obligation_with_new_self_ty(
+                obligation.param_env,
+                trait_pred.map_bound(|trait_pred| (trait_pred, ty)),
+            );
+            obligations
+                .iter()
+                .chain([&obligation])
+                .all(|obligation| self.predicate_must_hold_modulo_regions(obligation))
+        };
+        // The first step of dereferencing a reference is covered by `suggest_reference_variant`.
+        let skip = if self_ty.is_ref() { 2 } else { 1 };
+        let autoderef = (self.autoderef_steps)(self_ty);
+        for (steps, (ty, obligations)) in autoderef.into_iter().enumerate().skip(skip) {
+            let borrowed_ty = Ty::new_imm_ref(self.tcx, self.tcx.lifetimes.re_erased, ty);
+            if holds(borrowed_ty, &obligations) {
+                let derefs = "*".repeat(steps);
+                let is_receiver = matches!(
+                    self.tcx.hir_node(*call_hir_id),
+                    Node::Expr(hir::Expr {
+                        kind: hir::ExprKind::MethodCall(_, receiver_expr, ..),
+                        ..
+                    })
+                    if receiver_expr.hir_id == *arg_hir_id
+                );
+                let sugg = if is_receiver || expr_needs_parens(expr) {
+                    vec![
+                        (expr.span.shrink_to_lo(), format!("(&{derefs}")),
+                        (expr.span.shrink_to_hi(), ")".to_string()),
+                    ]
+                } else {
+                    vec![(expr.span.shrink_to_lo(), format!("&{derefs}"))]
+                };
+                err.multipart_suggestion_verbose(
+                    format!("consider borrowing the `{ty}` that `{self_ty}` dereferences to"),
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+                return true;
+            }
+            if self.type_is_sized_modulo_regions(obligation.param_env, ty)
+                && holds(ty, &obligations)
+            {
+                err.note(format!(
+                    "`{}` is implemented for `{ty}`, which `{self_ty}` dereferences to, \
+                     but it cannot be moved out of `{self_ty}`",
+                    trait_pred.print_modifiers_and_trait_path(),
+                ));
+                return true;
+            }
+        }
+        false
+    }
+
     /// Given a closure's `DefId`, return the given name of the closure.
     ///
     /// This doesn't account for reassignments, but it's only used for suggestions.
@@ -1406,10 +1505,17 @@ pub(super) fn suggest_add_reference_to_arg(
                 } else {
                     err.messages = vec![(rustc_errors::DiagMessage::from(msg), Style::NoStyle)];
                 }
-                err.span_label(
-                    span,
-                    format!("the trait `{trait_path}` is not implemented for `{self_ty_str}`"),
-                );
+                if self.tcx.is_lang_item(old_pred.def_id(), LangItem::MetaSized) {
+                    err.span_label(
+                        span,
+                        format!("the size of `{self_ty_str}` must be known, at least at run-time"),
+                    );
+                } else {
+                    err.span_label(
+                        span,
+                        format!("the trait `{trait_path}` is not implemented for `{self_ty_str}`"),
+                    );
+                }
             };
 
             let mut sugg_prefixes = vec![];
@@ -1742,81 +1848,117 @@ pub(super) fn suggest_remove_await(
         }
     }
 
-    /// Check if the trait bound is implemented for a different mutability and note it in the
-    /// final error.
-    pub(super) fn suggest_change_mut(
+    /// Speculatively checks which reference variants of the self type of `trait_pred` would
+    /// satisfy it, see [`ReferenceVariants`]. Returns `None` if the self type isn't a reference
+    /// or the predicate still contains inference variables.
+    fn reference_variants(
+        &self,
+        param_env: ty::ParamEnv<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) -> Option<ReferenceVariants<'tcx>> {
+        let trait_pred = self.resolve_vars_if_possible(trait_pred);
+        if trait_pred.has_non_region_infer() {
+            // Do not ICE while trying to find if a reborrow would succeed on a trait with
+            // unresolved bindings.
+            return None;
+        }
+        let ty::Ref(region, pointee, current) = *trait_pred.skip_binder().self_ty().kind() else {
+            return None;
+        };
+        // Skipping binder here, remapping below
+        let holds = |variant: Option<hir::Mutability>| {
+            if variant == Some(current) {
+                return false;
+            }
+            let ty = match variant {
+                None => pointee,
+                Some(mutability) => Ty::new_ref(self.tcx, region, pointee, mutability),
+            };
+            // Remapping bound vars here
+            let obligation = self.mk_trait_obligation_with_new_self_ty(
+                param_env,
+                trait_pred.map_bound(|trait_pred| (trait_pred, ty)),
+            );
+            self.evaluate_obligation_no_overflow(&obligation).must_apply_modulo_regions()
+        };
+        Some(ReferenceVariants {
+            region,
+            pointee,
+            current,
+            owned: holds(None),
+            shared: holds(Some(hir::Mutability::Not)),
+            mutable: holds(Some(hir::Mutability::Mut)),
+        })
+    }
+
+    /// Check if the trait bound is implemented for a different reference variant of the
+    /// self type and point it out in the final error, suggesting to change the borrow's
+    /// mutability if possible. Whether the variant without the reference holds is only
+    /// mentioned if we haven't `suggested` a fix already, e.g. by removing the borrow.
+    pub(super) fn suggest_reference_variant(
         &self,
         obligation: &PredicateObligation<'tcx>,
         err: &mut Diag<'_>,
         trait_pred: ty::PolyTraitPredicate<'tcx>,
+        suggested: bool,
     ) {
         let points_at_arg =
             matches!(obligation.cause.code(), ObligationCauseCode::FunctionArg { .. },);
 
         let span = obligation.cause.span;
-        if let Ok(snippet) = self.tcx.sess.source_map().span_to_snippet(span) {
-            let refs_number =
-                snippet.chars().filter(|c| !c.is_whitespace()).take_while(|c| *c == '&').count();
-            if let Some('\'') = snippet.chars().filter(|c| !c.is_whitespace()).nth(refs_number) {
-                // Do not suggest removal of borrow from type arguments.
-                return;
-            }
-            let trait_pred = self.resolve_vars_if_possible(trait_pred);
-            if trait_pred.has_non_region_infer() {
-                // Do not ICE while trying to find if a reborrow would succeed on a trait with
-                // unresolved bindings.
-                return;
-            }
-
-            // Skipping binder here, remapping below
-            if let ty::Ref(region, t_type, mutability) = *trait_pred.skip_binder().self_ty().kind()
-            {
-                let suggested_ty = match mutability {
-                    hir::Mutability::Mut => Ty::new_imm_ref(self.tcx, region, t_type),
-                    hir::Mutability::Not => Ty::new_mut_ref(self.tcx, region, t_type),
-                };
-
-                // Remapping bound vars here
-                let trait_pred_and_suggested_ty =
-                    trait_pred.map_bound(|trait_pred| (trait_pred, suggested_ty));
+        let Ok(snippet) = self.tcx.sess.source_map().span_to_snippet(span) else {
+            return;
+        };
+        let refs_number =
+            snippet.chars().filter(|c| !c.is_whitespace()).take_while(|c| *c == '&').count();
+        if let Some('\'') = snippet.chars().filter(|c| !c.is_whitespace()).nth(refs_number) {
+            // Do not suggest removal of borrow from type arguments.
+            return;
+        }
+        let Some(variants) = self.reference_variants(obligation.param_env, trait_pred) else {
+            return;
+        };
+        let mutability = variants.current;
+        let self_ty = self.resolve_vars_if_possible(trait_pred.self_ty().skip_binder());
+        let trait_path = trait_pred.print_modifiers_and_trait_path();
 
-                let new_obligation = self.mk_trait_obligation_with_new_self_ty(
-                    obligation.param_env,
-                    trait_pred_and_suggested_ty,
-                );
-                let suggested_ty_would_satisfy_obligation = self
-                    .evaluate_obligation_no_overflow(&new_obligation)
-                    .must_apply_modulo_regions();
-                if suggested_ty_would_satisfy_obligation {
-                    let sp = self
-                        .tcx
-                        .sess
-                        .source_map()
-                        .span_take_while(span, |c| c.is_whitespace() || *c == '&');
-                    if points_at_arg && mutability.is_not() && refs_number > 0 {
-                        // If we have a call like foo(&mut buf), then don't suggest foo(&mut mut buf)
-                        if snippet
-                            .trim_start_matches(|c: char| c.is_whitespace() || c == '&')
-                            .starts_with("mut")
-                        {
-                            return;
-                        }
-                        err.span_suggestion_verbose(
-                            sp,
-                            "consider changing this borrow's mutability",
-                            "&mut ",
-                            Applicability::MachineApplicable,
-                        );
-                    } else {
-                        err.note(format!(
-                            "`{}` is implemented for `{}`, but not for `{}`",
-                            trait_pred.print_modifiers_and_trait_path(),
-                            suggested_ty,
-                            trait_pred.skip_binder().self_ty(),
-                        ));
-                    }
+        if variants.holds(Some(mutability.invert())) {
+            let sp = self
+                .tcx
+                .sess
+                .source_map()
+                .span_take_while(span, |c| c.is_whitespace() || *c == '&');
+            if points_at_arg && mutability.is_not() && refs_number > 0 {
+                // If we have a call like foo(&mut buf), then don't suggest foo(&mut mut buf)
+                if snippet
+                    .trim_start_matches(|c: char| c.is_whitespace() || c == '&')
+                    .starts_with("mut")
+                {
+                    return;
                 }
+                err.span_suggestion_verbose(
+                    sp,
+                    "consider changing this borrow's mutability",
+                    "&mut ",
+                    Applicability::MachineApplicable,
+                );
+            } else {
+                err.note(format!(
+                    "`{trait_path}` is implemented for `{}`, but not for `{self_ty}`",
+                    variants.ty(self.tcx, Some(mutability.invert())),
+                ));
             }
+        } else if variants.holds(None)
+            && !suggested
+            && !self_ty.has_escaping_bound_vars()
+            && !self_ty.has_placeholders()
+        {
+            // Dropping a higher-ranked reference changes more than the self type, so only
+            // point out the owned variant for references with a concrete lifetime.
+            err.note(format!(
+                "`{trait_path}` is implemented for `{}`, but not for `{self_ty}`",
+                variants.pointee,
+            ));
         }
     }
 
@@ -2819,6 +2961,94 @@ fn note_obligation_cause_for_async_await<G: EmissionGuarantee>(
         );
     }
 
+    /// If `item_def_id` is a generic associated type, recovers the generic arguments
+    /// it has been instantiated with from the failing `predicate` by matching it
+    /// against the where-clause at `span`, e.g. `Trait::Assoc<'a> where 'a = 'static`.
+    fn describe_gat_instantiation(
+        &self,
+        item_def_id: DefId,
+        span: Span,
+        predicate: ty::Predicate<'tcx>,
+    ) -> Option<String> {
+        let tcx = self.tcx;
+        let generics = tcx.generics_of(item_def_id);
+        if tcx.def_kind(item_def_id) != DefKind::AssocTy || generics.own_params.is_empty() {
+            return None;
+        }
+
+        let (clause, _) =
+            tcx.predicates_of(item_def_id).predicates.iter().find(|&&(_, s)| s == span)?;
+        let clause_args = |kind: ty::ClauseKind<'tcx>| -> Option<Vec<ty::GenericArg<'tcx>>> {
+            Some(match kind {
+                ty::ClauseKind::Trait(pred) => pred.trait_ref.args.to_vec(),
+                ty::ClauseKind::Projection(pred) => {
+                    pred.projection_term.args.iter().chain([pred.term.into()]).collect()
+                }
+                ty::ClauseKind::TypeOutlives(pred) => {
+                    vec![pred.0.into(), pred.1.into()]
+                }
+                ty::ClauseKind::RegionOutlives(pred) => {
+                    vec![pred.0.into(), pred.1.into()]
+                }
+                _ => return None,
+            })
+        };
+        let expected = clause_args(clause.kind().skip_binder())?;
+        let ty::PredicateKind::Clause(kind) = predicate.kind().skip_binder() else {
+            return None;
+        };
+        let actual = clause_args(kind)?;
+        if expected.len() != actual.len() {
+            return None;
+        }
+
+        let mut own_args = vec![None; generics.own_params.len()];
+        for (expected, actual) in iter::zip(expected, actual) {
+            let mut actual = actual.walk();
+            for expected in expected.walk() {
+                let actual_arg = actual.next()?;
+                let index = match expected.kind() {
+                    ty::GenericArgKind::Type(ty) => match *ty.kind() {
+                        ty::Param(param) => param.index,
+                        _ => continue,
+                    },
+                    ty::GenericArgKind::Const(ct) => match ct.kind() {
+                        ty::ConstKind::Param(param) => param.index,
+                        _ => continue,
+                    },
+                    ty::GenericArgKind::Lifetime(region) => match region.kind() {
+                        ty::ReEarlyParam(param) => param.index,
+                        _ => continue,
+                    },
+                };
+                actual.skip_current_subtree();
+                if let Some(own_index) = (index as usize).checked_sub(generics.parent_count) {
+                    own_args[own_index] = Some(actual_arg);
+                }
+            }
+        }
+
+        let bindings: Vec<_> = iter::zip(&generics.own_params, own_args)
+            .filter_map(|(param, arg)| {
+                let arg = arg?;
+                if let ty::GenericArgKind::Lifetime(region) = arg.kind()
+                    && !region.is_named(tcx)
+                {
+                    return None;
+                }
+                Some(format!("`{} = {arg}`", param.name))
+            })
+            .collect();
+        let params: Vec<_> =
+            generics.own_params.iter().map(|param| param.name.to_string()).collect();
+        Some(format!(
+            "required for `{}<{}>` where {}",
+            tcx.def_path_str(item_def_id),
+            params.join(", "),
+            listify(&bindings, |binding| binding.clone())?,
+        ))
+    }
+
     pub(super) fn note_obligation_cause_code<G: EmissionGuarantee, T>(
         &self,
         body_id: LocalDefId,
@@ -3082,6 +3312,12 @@ pub(super) fn note_obligation_cause_code<G: EmissionGuarantee, T>(
                 if let Some(help) = help {
                     err.help(help);
                 }
+                if self.next_trait_solver()
+                    && let Some(instantiation) =
+                        self.describe_gat_instantiation(item_def_id, span, predicate)
+                {
+                    err.note(instantiation);
+                }
             }
             ObligationCauseCode::WhereClause(..)
             | ObligationCauseCode::WhereClauseInExpr(..)
@@ -3406,7 +3642,17 @@ pub(super) fn note_obligation_cause_code<G: EmissionGuarantee, T>(
                 let is_builtin_async_fn_trait =
                     tcx.async_fn_trait_kind_from_def_id(data.parent_trait_pred.def_id()).is_some();
 
-                if !is_upvar_tys_infer_tuple && !is_builtin_async_fn_trait {
+                let internal_requirement =
+                    internal_marker_trait_requirement(tcx, data.parent_trait_pred.def_id());
+                if let Some(requirement) = internal_requirement
+                    && let Some(trait_pred) = predicate.as_trait_clause()
+                    && trait_pred.self_ty().skip_binder() == ty
+                {
+                    // The solver-internal marker trait was proven by requiring another trait
+                    // of the same type, so there's no type to point at.
+                    let ty_str = tcx.short_string(ty, err.long_ty_path());
+                    err.note(format!("required because `{ty_str}` must {requirement}"));
+                } else if !is_upvar_tys_infer_tuple && !is_builtin_async_fn_trait {
                     let mut msg = || {
                         let ty_str = tcx.short_string(ty, err.long_ty_path());
                         format!("required because it appears within the type `{ty_str}`")
@@ -3601,7 +3847,7 @@ pub(super) fn note_obligation_cause_code<G: EmissionGuarantee, T>(
 
                 let mut parent_predicate = parent_trait_pred;
                 let mut data = &data.derived;
-                let mut count = 0;
+                let mut hidden = vec![];
                 seen_requirements.insert(parent_def_id);
                 if is_auto_trait {
                     // We don't want to point at the ADT saying "required because it appears within
@@ -3626,12 +3872,43 @@ pub(super) fn note_obligation_cause_code<G: EmissionGuarantee, T>(
                     if seen_requirements.insert(child_def_id) {
                         break;
                     }
-                    count += 1;
+                    hidden.push(child_trait_pred);
                     data = &child.derived;
                     parent_predicate = child_trait_pred.upcast(tcx);
                     parent_trait_pred = child_trait_pred;
                 }
-                if count > 0 {
+                if self.is_adapter_chain(parent_def_id, &hidden) {
+                    // Collapse chains of adapters, such as iterator combinators, which all
+                    // implement the trait by requiring it of the type they wrap.
+                    let (collapsed, shown) = if tcx.sess.verbose_internals() {
+                        (&[][..], &hidden[..])
+                    } else {
+                        hidden.split_at(hidden.len() - 1)
+                    };
+                    if !collapsed.is_empty() {
+                        let adapters: FxIndexSet<_> = collapsed
+                            .iter()
+                            .filter_map(|pred| pred.skip_binder().self_ty().ty_adt_def())
+                            .map(|adt_def| format!("`{}`", tcx.item_name(adt_def.did())))
+                            .collect();
+                        err.note(format!(
+                            "... through {} more {} adapter{} ...",
+                            collapsed.len(),
+                            adapters.iter().join("/"),
+                            pluralize!(collapsed.len())
+                        ));
+                    }
+                    for pred in shown {
+                        let self_ty =
+                            tcx.short_string(pred.skip_binder().self_ty(), err.long_ty_path());
+                        let trait_path = tcx.short_string(
+                            pred.print_modifiers_and_trait_path(),
+                            err.long_ty_path(),
+                        );
+                        err.note(format!("required for `{self_ty}` to implement `{trait_path}`"));
+                    }
+                } else if !hidden.is_empty() {
+                    let count = hidden.len();
                     err.note(format!(
                         "{} redundant requirement{} hidden",
                         count,
@@ -3849,6 +4126,129 @@ pub(super) fn note_obligation_cause_code<G: EmissionGuarantee, T>(
         }
     }
 
+    /// Whether the requirements hidden when rendering derived causes form a chain of at
+    /// least two adapters, i.e. they are all for the trait `trait_def_id` and each of them
+    /// is for an ADT wrapping the type of the previous one, e.g. `Map<Filter<I, P>, F>`.
+    fn is_adapter_chain(
+        &self,
+        trait_def_id: DefId,
+        hidden: &[ty::PolyTraitPredicate<'tcx>],
+    ) -> bool {
+        if hidden.len() < 2 {
+            return false;
+        }
+        let mut inner = None;
+        for pred in hidden {
+            let self_ty = pred.skip_binder().self_ty();
+            let ty::Adt(_, args) = *self_ty.kind() else {
+                return false;
+            };
+            if pred.def_id() != trait_def_id
+                || inner.is_some_and(|inner| !args.types().any(|ty| ty == inner))
+            {
+                return false;
+            }
+            inner = Some(self_ty);
+        }
+        true
+    }
+
+    /// Explains why a type is not `Unpin` by pointing at the static coroutine or the field
+    /// which makes it `!Unpin`, and suggests pinning a mutably borrowed argument of a call
+    /// with `pin!` if that would satisfy the obligation introduced by the argument.
+    pub(super) fn note_unpin_failure(
+        &self,
+        err: &mut Diag<'_>,
+        obligation: &PredicateObligation<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let self_ty = self.resolve_vars_if_possible(trait_pred.self_ty().skip_binder());
+        let mut derived_code = obligation.cause.code();
+        while let ObligationCauseCode::FunctionArg { parent_code, .. } = derived_code {
+            derived_code = parent_code;
+        }
+        if let ty::Coroutine(def_id, _) = *self_ty.kind()
+            && tcx.coroutine_movability(def_id) == hir::Movability::Static
+        {
+            err.span_note(
+                tcx.def_span(def_id),
+                format!(
+                    "this {} is not `Unpin`, as it may hold references to its own local \
+                     variables across suspension points",
+                    tcx.def_descr(def_id),
+                ),
+            );
+        } else if let ObligationCauseCode::BuiltinDerived(data) = derived_code
+            && let parent_ty =
+                self.resolve_vars_if_possible(data.parent_trait_pred.self_ty().skip_binder())
+            && let ty::Adt(adt_def, args) = *parent_ty.kind()
+            && let Some(field) = adt_def.all_fields().find(|field| {
+                tcx.erase_and_anonymize_regions(field.ty(tcx, args))
+                    == tcx.erase_and_anonymize_regions(self_ty)
+            })
+            && field.did.is_local()
+        {
+            err.span_note(
+                tcx.def_span(field.did),
+                format!(
+                    "`{parent_ty}` is not `Unpin` because this field of type `{self_ty}` is not"
+                ),
+            );
+        }
+
+        // Look for the argument of the call which introduced the root obligation.
+        let mut code = obligation.cause.code();
+        let arg_hir_id = loop {
+            match code {
+                ObligationCauseCode::FunctionArg { arg_hir_id, .. } => break *arg_hir_id,
+                _ => match code.parent() {
+                    Some(parent) => code = parent,
+                    None => return,
+                },
+            }
+        };
+        let Some(typeck_results) = self.typeck_results.as_ref() else {
+            return;
+        };
+        let Node::Expr(arg) = tcx.hir_node(arg_hir_id) else {
+            return;
+        };
+        let root_trait_pred =
+            obligation.cause.code().peel_derives_with_predicate().1.unwrap_or(trait_pred);
+        let root_self_ty = self.resolve_vars_if_possible(root_trait_pred.self_ty().skip_binder());
+        // Passing a value pinned by `pin!` instead of the value itself changes the meaning
+        // of the call, so we only suggest it in place of a mutable borrow.
+        if let hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Mut, local) = arg.kind
+            && let ty::Ref(_, pinned_ty, hir::Mutability::Mut) = *root_self_ty.kind()
+            && let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = local.kind
+            && let Res::Local(_) = path.res
+            && let [segment] = path.segments
+            && typeck_results.expr_ty_adjusted_opt(arg).is_some_and(|arg_ty| {
+                tcx.erase_and_anonymize_regions(arg_ty)
+                    == tcx.erase_and_anonymize_regions(root_self_ty)
+            })
+            && let Some(pin_ty) = Ty::new_lang_item(
+                tcx,
+                Ty::new_mut_ref(tcx, tcx.lifetimes.re_erased, pinned_ty),
+                LangItem::Pin,
+            )
+            && self.predicate_must_hold_modulo_regions(&self.mk_trait_obligation_with_new_self_ty(
+                obligation.param_env,
+                root_trait_pred.map_bound(|root_trait_pred| (root_trait_pred, pin_ty)),
+            ))
+        {
+            err.multipart_suggestion_verbose(
+                format!("consider pinning `{}` in place with the `pin!` macro", segment.ident),
+                vec![
+                    (arg.span.until(local.span), "std::pin::pin!(".to_string()),
+                    (local.span.shrink_to_hi(), ")".to_string()),
+                ],
+                Applicability::MaybeIncorrect,
+            );
+        }
+    }
+
     #[instrument(
         level = "debug", skip(self, err), fields(trait_pred.self_ty = ?trait_pred.self_ty())
     )]
@@ -4043,9 +4443,227 @@ pub(super) fn suggest_dereferencing_index(
                 '*',
                 Applicability::MachineApplicable,
             );
+        } else if let (_, root_pred) = obligation.cause.code().peel_derives_with_predicate()
+            && let trait_pred = root_pred.unwrap_or(trait_pred)
+            && self.tcx.is_lang_item(trait_pred.def_id(), LangItem::Index)
+            && let index_ty = trait_pred.skip_binder().trait_ref.args.type_at(1)
+            && !index_ty.has_non_region_infer()
+            && !obligation.cause.span.from_expansion()
+        {
+            // Check whether indexing with `*idx` or `&idx` would have worked instead.
+            let holds_with_index = |ty: Ty<'tcx>| {
+                let trait_pred = trait_pred.map_bound(|pred| ty::TraitPredicate {
+                    trait_ref: ty::TraitRef::new(self.tcx, pred.def_id(), [pred.self_ty(), ty]),
+                    ..pred
+                });
+                self.predicate_must_hold_modulo_regions(&obligation.with(self.tcx, trait_pred))
+            };
+            if let ty::Ref(_, inner_ty, _) = index_ty.kind()
+                && holds_with_index(*inner_ty)
+            {
+                err.span_suggestion_verbose(
+                    obligation.cause.span.shrink_to_lo(),
+                    "dereference this index",
+                    '*',
+                    Applicability::MaybeIncorrect,
+                );
+            } else if holds_with_index(Ty::new_imm_ref(
+                self.tcx,
+                self.tcx.lifetimes.re_erased,
+                index_ty,
+            )) {
+                err.span_suggestion_verbose(
+                    obligation.cause.span.shrink_to_lo(),
+                    "borrow this index",
+                    '&',
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+
+    /// Returns the operands of the binary operator expression which caused `obligation`, along
+    /// with the trait predicate the operator was checked with, if that predicate is for one of
+    /// the binary operator traits.
+    fn binop_operands(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) -> Option<(&'tcx hir::Expr<'tcx>, &'tcx hir::Expr<'tcx>, ty::PolyTraitPredicate<'tcx>)> {
+        let (ObligationCauseCode::BinOp { lhs_hir_id, rhs_hir_id, .. }, root_pred) =
+            obligation.cause.code().peel_derives_with_predicate()
+        else {
+            return None;
+        };
+        let trait_pred = root_pred.unwrap_or(trait_pred);
+        if !hir::lang_items::BINARY_OPERATORS
+            .iter()
+            .filter_map(|&op| self.tcx.lang_items().get(op))
+            .any(|op| op == trait_pred.def_id())
+        {
+            return None;
+        }
+        let hir::Node::Expr(lhs) = self.tcx.hir_node(*lhs_hir_id) else { return None };
+        let hir::Node::Expr(rhs) = self.tcx.hir_node(*rhs_hir_id) else { return None };
+        Some((lhs, rhs, trait_pred))
+    }
+
+    /// When the unsatisfied obligation was derived from the one of a binary operator, the
+    /// primary message talks about types other than the ones the user wrote, so point at both
+    /// operands with their types.
+    pub(super) fn label_binop_operand_types(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut Diag<'_>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) {
+        let (_, Some(_)) = obligation.cause.code().peel_derives_with_predicate() else {
+            return;
+        };
+        let Some(typeck_results) = &self.typeck_results else {
+            return;
+        };
+        let Some((lhs, rhs, _)) = self.binop_operands(obligation, trait_pred) else {
+            return;
+        };
+        if lhs.span.from_expansion() || rhs.span.from_expansion() {
+            return;
+        }
+        for expr in [lhs, rhs] {
+            if let Some(ty) = typeck_results.expr_ty_opt(expr) {
+                let ty = self.resolve_vars_if_possible(ty);
+                let ty = self.tcx.short_string(ty, err.long_ty_path());
+                err.span_label(expr.span, ty);
+            }
         }
     }
 
+    /// Suggest borrowing one of the operands of a binary operator if the operator trait is
+    /// implemented for the borrowed operand instead.
+    pub(super) fn suggest_borrowing_binop_operand(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut Diag<'_>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) -> bool {
+        let Some((lhs, rhs, trait_pred)) = self.binop_operands(obligation, trait_pred) else {
+            return false;
+        };
+        let trait_pred = self.resolve_vars_if_possible(trait_pred);
+        let Some(rhs_ty) = trait_pred.skip_binder().trait_ref.args.get(1).and_then(|a| a.as_type())
+        else {
+            return false;
+        };
+        let lhs_ty = trait_pred.skip_binder().self_ty();
+        if (lhs_ty, rhs_ty).has_non_region_infer() {
+            return false;
+        }
+        let holds_with = |lhs_ty: Ty<'tcx>, rhs_ty: Ty<'tcx>| {
+            let trait_pred = trait_pred.map_bound(|pred| ty::TraitPredicate {
+                trait_ref: ty::TraitRef::new_from_args(
+                    self.tcx,
+                    pred.def_id(),
+                    self.tcx.mk_args(
+                        &[&[lhs_ty.into(), rhs_ty.into()], &pred.trait_ref.args[2..]].concat(),
+                    ),
+                ),
+                ..pred
+            });
+            self.predicate_must_hold_modulo_regions(&obligation.with(self.tcx, trait_pred))
+        };
+        let borrowed = |ty| Ty::new_imm_ref(self.tcx, self.tcx.lifetimes.re_erased, ty);
+        let expr = if holds_with(lhs_ty, borrowed(rhs_ty)) {
+            rhs
+        } else if holds_with(borrowed(lhs_ty), rhs_ty) {
+            lhs
+        } else {
+            return false;
+        };
+        if expr.span.from_expansion() {
+            return false;
+        }
+        let sugg = if expr_needs_parens(expr) {
+            vec![
+                (expr.span.shrink_to_lo(), "&(".to_string()),
+                (expr.span.shrink_to_hi(), ")".to_string()),
+            ]
+        } else {
+            vec![(expr.span.shrink_to_lo(), "&".to_string())]
+        };
+        err.multipart_suggestion_verbose(
+            "consider borrowing here",
+            sugg,
+            Applicability::MaybeIncorrect,
+        );
+        true
+    }
+
+    /// When a format argument doesn't implement `Display` but does implement `Debug`, suggest
+    /// using `{:?}` at the formatting parameter that introduced the requirement.
+    pub(super) fn suggest_debug_formatting(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut Diag<'_>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) {
+        // Find the formatting parameter, along with the predicate it required, which might not be
+        // the leaf one when the failure was derived from it.
+        let mut code = obligation.cause.code();
+        let call_hir_id = loop {
+            if let ObligationCauseCode::FunctionArg { call_hir_id, .. } = code {
+                break *call_hir_id;
+            }
+            let Some((parent_code, _)) = code.parent_with_predicate() else { return };
+            code = parent_code;
+        };
+        let (_, root_pred) = obligation.cause.code().peel_derives_with_predicate();
+        let trait_pred = self.resolve_vars_if_possible(root_pred.unwrap_or(trait_pred));
+        if !self.tcx.is_diagnostic_item(sym::Display, trait_pred.def_id()) {
+            return;
+        }
+        let hir::Node::Expr(hir::Expr { kind: hir::ExprKind::Call(callee, _), .. }) =
+            self.tcx.hir_node(call_hir_id)
+        else {
+            return;
+        };
+        // Only the placeholders written in a string literal in the source can be edited.
+        let span = callee.span;
+        if !span.is_desugaring(DesugaringKind::FormatLiteral { source: true }) {
+            return;
+        }
+        let self_ty = trait_pred.skip_binder().self_ty();
+        if self_ty.is_ty_var() {
+            return;
+        }
+        // Paths have a dedicated note pointing at `.display()`, which is what people want.
+        if let ty::Adt(def, _) = self_ty.peel_refs().kind()
+            && (self.tcx.is_diagnostic_item(sym::Path, def.did())
+                || self.tcx.is_diagnostic_item(sym::PathBuf, def.did()))
+        {
+            return;
+        }
+        let Some(debug_def_id) = self.tcx.get_diagnostic_item(sym::Debug) else { return };
+        let debug_pred = trait_pred.map_bound(|pred| ty::TraitPredicate {
+            trait_ref: ty::TraitRef::new(self.tcx, debug_def_id, [pred.self_ty()]),
+            ..pred
+        });
+        if !self.predicate_may_hold(&obligation.with(self.tcx, debug_pred)) {
+            return;
+        }
+        let Ok(snippet) = self.tcx.sess.source_map().span_to_snippet(span) else { return };
+        if !snippet.starts_with('{') || !snippet.ends_with('}') {
+            return;
+        }
+        // `{}` and `{x}` need a `:` before the `?`, while `{:>5}` and `{x:#}` already have one.
+        let sugg = if snippet.contains(':') { "?" } else { ":?" };
+        err.span_suggestion_verbose(
+            span.with_lo(span.hi() - BytePos(1)).shrink_to_lo(),
+            format!("use the `Debug` implementation of `{self_ty}` instead"),
+            sugg,
+            Applicability::MaybeIncorrect,
+        );
+    }
+
     fn note_function_argument_obligation<G: EmissionGuarantee>(
         &self,
         body_id: LocalDefId,
@@ -4345,6 +4963,84 @@ fn suggest_option_method_if_applicable<G: EmissionGuarantee>(
         }
     }
 
+    /// Method calls which skip some trait candidates in older editions, e.g. `array.into_iter()`
+    /// using `IntoIterator for &[T; N]` before Rust 2021, resolve to a different method in
+    /// later editions. If the skipped method applies, the error likely goes away when
+    /// using it instead, so we point this out and suggest calling it explicitly.
+    fn note_edition_dependent_method_call<G: EmissionGuarantee>(
+        &self,
+        call_expr: &hir::Expr<'_>,
+        path_segment: &hir::PathSegment<'_>,
+        rcvr_expr: &hir::Expr<'_>,
+        typeck_results: &TypeckResults<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+        err: &mut Diag<'_, G>,
+    ) {
+        let tcx = self.tcx;
+        let Some(method_def_id) = typeck_results.type_dependent_def_id(call_expr.hir_id) else {
+            return;
+        };
+        let Some(trait_def_id) = tcx.trait_of_assoc(method_def_id) else {
+            return;
+        };
+        let Some(rcvr_ty) = typeck_results.expr_ty_opt(rcvr_expr) else {
+            return;
+        };
+        let rcvr_ty = self.resolve_vars_if_possible(rcvr_ty);
+        let trait_def = tcx.trait_def(trait_def_id);
+        let span = path_segment.ident.span;
+        let edition = if rcvr_ty.is_array()
+            && trait_def.skip_array_during_method_dispatch
+            && !span.at_least_rust_2021()
+        {
+            Edition::Edition2021
+        } else if rcvr_ty.boxed_ty().is_some_and(Ty::is_slice)
+            && trait_def.skip_boxed_slice_during_method_dispatch
+            && !span.at_least_rust_2024()
+        {
+            Edition::Edition2024
+        } else {
+            return;
+        };
+        // The method has only been skipped if we had to autoref the receiver.
+        let Some(adjusted_rcvr_ty) = typeck_results.expr_ty_adjusted_opt(rcvr_expr) else {
+            return;
+        };
+        if !adjusted_rcvr_ty.is_ref()
+            || !self
+                .type_implements_trait(trait_def_id, [rcvr_ty], param_env)
+                .must_apply_modulo_regions()
+        {
+            return;
+        }
+
+        let rcvr_ty = with_forced_trimmed_paths!(self.ty_to_string(rcvr_ty));
+        let adjusted_rcvr_ty = with_forced_trimmed_paths!(self.ty_to_string(adjusted_rcvr_ty));
+        let method = path_segment.ident;
+        err.span_note(
+            span,
+            format!(
+                "this calls `{method}` on `{adjusted_rcvr_ty}` in the current edition, while in \
+                 edition {edition} and later it is called on `{rcvr_ty}`",
+            ),
+        );
+        let hir::ExprKind::MethodCall(_, _, [], _) = call_expr.kind else {
+            return;
+        };
+        let Some(args_span) = call_expr.span.trim_start(rcvr_expr.span) else {
+            return;
+        };
+        let trait_name = tcx.def_path_str(trait_def_id);
+        err.multipart_suggestion_verbose(
+            format!("call `{method}` on `{rcvr_ty}` explicitly, as in edition {edition}"),
+            vec![
+                (rcvr_expr.span.shrink_to_lo(), format!("{trait_name}::{method}(")),
+                (args_span, ")".to_string()),
+            ],
+            Applicability::MaybeIncorrect,
+        );
+    }
+
     fn look_for_iterator_item_mistakes<G: EmissionGuarantee>(
         &self,
         assocs_in_this_method: &[Option<(Span, (DefId, Ty<'tcx>))>],
@@ -4472,6 +5168,14 @@ fn point_at_chain<G: EmissionGuarantee>(
             typeck_results.expr_ty_adjusted_opt(expr).unwrap_or(Ty::new_misc_error(tcx)),
         );
         while let hir::ExprKind::MethodCall(path_segment, rcvr_expr, args, span) = expr.kind {
+            self.note_edition_dependent_method_call(
+                expr,
+                path_segment,
+                rcvr_expr,
+                typeck_results,
+                param_env,
+                err,
+            );
             // Point at every method call in the chain with the resulting type.
             // vec![1, 2, 3].iter().map(mapper).sum<i32>()
             //               ^^^^^^ ^^^^^^^^^^^
@@ -5258,6 +5962,38 @@ pub(crate) fn suggest_swapping_lhs_and_rhs<T>(
     }
 }
 
+/// Which reference variants of the self type `&'a T` or `&'a mut T` of an unsatisfied
+/// trait predicate would satisfy it, i.e. which of `T`, `&'a T` and `&'a mut T`.
+#[derive(Clone, Copy, Debug)]
+struct ReferenceVariants<'tcx> {
+    region: ty::Region<'tcx>,
+    /// The self type without its reference.
+    pointee: Ty<'tcx>,
+    /// The mutability of the reference of the self type.
+    current: hir::Mutability,
+    owned: bool,
+    shared: bool,
+    mutable: bool,
+}
+
+impl<'tcx> ReferenceVariants<'tcx> {
+    /// Whether the variant with the given mutability, or `pointee` for `None`, holds.
+    fn holds(&self, variant: Option<hir::Mutability>) -> bool {
+        match variant {
+            None => self.owned,
+            Some(hir::Mutability::Not) => self.shared,
+            Some(hir::Mutability::Mut) => self.mutable,
+        }
+    }
+
+    fn ty(&self, tcx: TyCtxt<'tcx>, variant: Option<hir::Mutability>) -> Ty<'tcx> {
+        match variant {
+            None => self.pointee,
+            Some(mutability) => Ty::new_ref(tcx, self.region, self.pointee, mutability),
+        }
+    }
+}
+
 /// Add a hint to add a missing borrow or remove an unnecessary one.
 fn hint_missing_borrow<'tcx>(
     infcx: &InferCtxt<'tcx>,
@@ -5513,7 +6249,16 @@ pub(super) fn get_explanation_based_on_obligation<'tcx>(
             Some(desc) => format!(" {desc}"),
             None => String::new(),
         };
-        if let ty::PredicatePolarity::Positive = trait_predicate.polarity() {
+        if let ty::PredicatePolarity::Positive = trait_predicate.polarity()
+            && tcx.is_lang_item(trait_predicate.def_id(), LangItem::MetaSized)
+        {
+            // Users know `Sized`, but the traits further down the sizedness hierarchy are
+            // an implementation detail, so describe what they require instead.
+            format!(
+                "{pre_message}the size of `{}` must be known, at least at run-time",
+                tcx.short_string(trait_predicate.self_ty().skip_binder(), long_ty_path),
+            )
+        } else if let ty::PredicatePolarity::Positive = trait_predicate.polarity() {
             format!(
                 "{pre_message}the trait `{}` is not implemented for{desc} `{}`",
                 trait_predicate.print_modifiers_and_trait_path(),
@@ -56,6 +56,12 @@ pub enum InvalidOnClause {
         span: Span,
         path: Path,
     },
+    #[diag(trait_selection_rustc_on_unimplemented_expected_one_implements_clause, code = E0232)]
+    ExpectedOneImplementsClause {
+        #[primary_span]
+        #[label]
+        span: Span,
+    },
     #[diag(trait_selection_rustc_on_unimplemented_invalid_predicate, code = E0232)]
     InvalidPredicate {
         #[primary_span]
@@ -134,6 +140,100 @@ pub struct InherentProjectionNormalizationOverflow {
     pub ty: String,
 }
 
+#[derive(Subdiagnostic)]
+#[note(trait_selection_refinement_exceeded_limits)]
+pub struct RefinementExceededLimits;
+
+#[derive(Subdiagnostic)]
+#[note(trait_selection_fundamental_type_downstream_impls)]
+pub struct FundamentalTypeDownstreamImpls {
+    /// The name of the `#[fundamental]` type, or `reference`.
+    pub wrapper: String,
+}
+
+#[derive(Subdiagnostic)]
+#[note(trait_selection_inferred_from_self)]
+pub struct InferredFromSelf {
+    pub param: Symbol,
+    pub trait_name: String,
+    pub self_ty: String,
+}
+
+#[derive(Subdiagnostic)]
+#[note(trait_selection_feature_gated_builtin_impl)]
+pub struct FeatureGatedBuiltinImpl<'tcx> {
+    pub trait_name: Symbol,
+    pub self_ty: Ty<'tcx>,
+    pub feature: Symbol,
+}
+
+#[derive(Diagnostic)]
+#[diag(trait_selection_goal_too_complex)]
+#[note]
+#[help]
+pub struct GoalTooComplex<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub predicate: ty::Predicate<'tcx>,
+    pub limit: usize,
+}
+
+#[derive(Diagnostic)]
+#[diag(trait_selection_trait_solving_cancelled)]
+pub struct TraitSolvingCancelled {
+    pub solved_goals: usize,
+}
+
+pub struct ReservedImpl {
+    pub span: Span,
+    pub message: Symbol,
+}
+
+impl Subdiagnostic for ReservedImpl {
+    fn add_to_diag<G: EmissionGuarantee>(self, diag: &mut Diag<'_, G>) {
+        diag.span_note(self.span, fluent::trait_selection_reserved_impl);
+        diag.note(self.message.to_string());
+    }
+}
+
+pub struct NightlyOnlyImpl {
+    pub span: Span,
+    pub feature: Symbol,
+}
+
+impl Subdiagnostic for NightlyOnlyImpl {
+    fn add_to_diag<G: EmissionGuarantee>(self, diag: &mut Diag<'_, G>) {
+        diag.arg("feature", self.feature);
+        let msg = diag.eagerly_translate(fluent::trait_selection_nightly_only_impl);
+        diag.span_note(self.span, msg);
+        diag.note(fluent::trait_selection_nightly_only_impl_ignored);
+    }
+}
+
+#[derive(Subdiagnostic)]
+#[note(trait_selection_required_in_external_macro)]
+pub struct RequiredInExternalMacro {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Subdiagnostic)]
+#[note(trait_selection_considered_where_clauses)]
+pub struct ConsideredWhereClauses {
+    pub predicate: String,
+    pub clauses: String,
+    pub count: usize,
+}
+
+#[derive(Diagnostic)]
+#[diag(trait_selection_order_dependent_obligations)]
+#[note]
+pub struct OrderDependentObligations {
+    #[primary_span]
+    pub span: Span,
+    pub seed: u64,
+}
+
 pub enum AdjustSignatureBorrow {
     Borrow { to_borrow: Vec<(Span, String)> },
     RemoveBorrow { remove_borrow: Vec<(Span, String)> },
@@ -1,13 +1,17 @@
 pub use rustc_next_trait_solver::solve::*;
 
 mod delegate;
+mod dump_cache;
 mod fulfill;
 pub mod inspect;
+mod nontermination;
 mod normalize;
 mod select;
 
 pub(crate) use delegate::SolverDelegate;
+pub use dump_cache::dump_evaluation_cache;
 pub use fulfill::{FulfillmentCtxt, NextSolverError, StalledOnCoroutines};
+pub(crate) use nontermination::nonterminating_impl;
 pub(crate) use normalize::deeply_normalize_for_diagnostics;
 pub use normalize::{
     deeply_normalize, deeply_normalize_with_skipped_universes,
@@ -314,4 +314,8 @@ fn is_transmutable(
             rustc_transmute::Answer::No(_) | rustc_transmute::Answer::If(_) => Err(NoSolution),
         }
     }
+
+    fn shuffle_candidates<T>(&self, candidates: &mut [T]) {
+        self.0.shuffle_if_requested(candidates)
+    }
 }
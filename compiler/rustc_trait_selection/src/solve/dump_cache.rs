@@ -0,0 +1,57 @@
+//! `-Zdump-solver-cache`: prints the global evaluation cache of the new solver.
+
+use rustc_middle::traits::solve::{Certainty, NoSolution};
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::search_graph::GlobalCacheEntry;
+
+/// Prints all entries of the global evaluation cache of the new solver.
+///
+/// Each entry is printed on its own line, containing the canonical goal, its
+/// typing mode, the cached result, the depth required to compute that result,
+/// and the index of the dep-node the result depends on. The lines are sorted
+/// so that the output does not depend on the order in which goals have been
+/// evaluated.
+pub fn dump_evaluation_cache(tcx: TyCtxt<'_>) {
+    let cache = tcx.new_solver_evaluation_cache.lock();
+    let mut lines = with_no_trimmed_paths!(
+        cache
+            .entries()
+            .map(|(input, entry)| {
+                let GlobalCacheEntry { result, required_depth, encountered_overflow } = entry;
+                let (result, dep_node) = result.get_untracked();
+                let goal = input.canonical.value.goal;
+                let env = if goal.param_env.caller_bounds().is_empty() {
+                    String::new()
+                } else {
+                    let clauses: Vec<_> = goal
+                        .param_env
+                        .caller_bounds()
+                        .iter()
+                        .map(|clause| clause.to_string())
+                        .collect();
+                    format!(" in [{}]", clauses.join(", "))
+                };
+                let result = match result.map(|response| response.value.certainty) {
+                    Ok(Certainty::Yes) => "yes",
+                    Ok(Certainty::Maybe { .. }) => "maybe",
+                    Err(NoSolution) => "no",
+                };
+                let overflow = if encountered_overflow { ", overflow" } else { "" };
+                format!(
+                    "`{}`{env} ({:?}) => {result} (depth {required_depth}{overflow}, \
+                        dep-node {})",
+                    goal.predicate,
+                    input.typing_mode,
+                    dep_node.as_u32(),
+                )
+            })
+            .collect::<Vec<_>>()
+    );
+    lines.sort();
+
+    println!("solver evaluation cache ({} entries):", lines.len());
+    for line in lines {
+        println!("    {line}");
+    }
+}
@@ -4,14 +4,14 @@
 
 use rustc_data_structures::thinvec::ExtractIf;
 use rustc_hir::def_id::LocalDefId;
-use rustc_infer::infer::InferCtxt;
+use rustc_infer::infer::{InferCtxt, TypeFreshener};
 use rustc_infer::traits::query::NoSolution;
 use rustc_infer::traits::{
     FromSolverError, PredicateObligation, PredicateObligations, TraitEngine,
 };
 use rustc_middle::ty::{
-    self, DelayedSet, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypeVisitor,
-    TypingMode,
+    self, DelayedSet, Ty, TyCtxt, TypeFoldable, TypeSuperVisitable, TypeVisitable,
+    TypeVisitableExt, TypeVisitor, TypingMode,
 };
 use rustc_next_trait_solver::delegate::SolverDelegate as _;
 use rustc_next_trait_solver::solve::{
@@ -25,9 +25,11 @@
 use super::Certainty;
 use super::delegate::SolverDelegate;
 use super::inspect::{self, InferCtxtProofTreeExt};
-use crate::traits::{FulfillmentError, ScrubbedTraitError};
+use crate::errors::OrderDependentObligations;
+use crate::traits::{self, FulfillmentError, ScrubbedTraitError};
 
 mod derive_errors;
+mod explain;
 
 // FIXME: Do we need to use a `ThinVec` here?
 type PendingObligations<'tcx> =
@@ -63,6 +65,11 @@ struct ObligationStorage<'tcx> {
     /// to avoid recomputing them each time `try_evaluate_obligations` is called.
     /// This also allows us to return the correct `FulfillmentError` for them.
     overflowed: Vec<PredicateObligation<'tcx>>,
+    /// Obligations whose evaluation exceeded the memory limit of the solver.
+    ///
+    /// We don't reevaluate them as doing so is expensive and would most likely
+    /// exceed the limit again.
+    too_complex: Vec<PredicateObligation<'tcx>>,
     pending: PendingObligations<'tcx>,
 }
 
@@ -76,13 +83,14 @@ fn register(
     }
 
     fn has_pending_obligations(&self) -> bool {
-        !self.pending.is_empty() || !self.overflowed.is_empty()
+        !self.pending.is_empty() || !self.overflowed.is_empty() || !self.too_complex.is_empty()
     }
 
     fn clone_pending(&self) -> PredicateObligations<'tcx> {
         let mut obligations: PredicateObligations<'tcx> =
             self.pending.iter().map(|(o, _)| o.clone()).collect();
         obligations.extend(self.overflowed.iter().cloned());
+        obligations.extend(self.too_complex.iter().cloned());
         obligations
     }
 
@@ -148,43 +156,34 @@ fn inspect_evaluated_obligation(
             (inspector)(infcx, &obligation, result);
         }
     }
-}
 
-impl<'tcx, E> TraitEngine<'tcx, E> for FulfillmentCtxt<'tcx, E>
-where
-    E: FromSolverError<'tcx, NextSolverError<'tcx>>,
-{
-    #[instrument(level = "trace", skip(self, infcx))]
-    fn register_predicate_obligation(
-        &mut self,
+    /// `-Zexplain-goal`: Prints the proof tree of `obligation` if requested.
+    ///
+    /// We only do so for fulfillment contexts outside of snapshots to avoid
+    /// explaining goals which are only proven speculatively, e.g. during
+    /// method probing.
+    fn explain_if_requested(
+        &self,
         infcx: &InferCtxt<'tcx>,
-        obligation: PredicateObligation<'tcx>,
+        obligation: &PredicateObligation<'tcx>,
     ) {
-        assert_eq!(self.usable_in_snapshot, infcx.num_open_snapshots());
-        self.obligations.register(obligation, None);
-    }
-
-    fn collect_remaining_errors(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
-        self.obligations
-            .pending
-            .drain(..)
-            .map(|(obligation, _)| NextSolverError::Ambiguity(obligation))
-            .chain(
-                self.obligations
-                    .overflowed
-                    .drain(..)
-                    .map(|obligation| NextSolverError::Overflow(obligation)),
-            )
-            .map(|e| E::from_solver_error(infcx, e))
-            .collect()
+        if self.usable_in_snapshot == 0 {
+            explain::explain_if_requested(infcx, obligation);
+        }
     }
+}
 
-    fn try_evaluate_obligations(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
-        assert_eq!(self.usable_in_snapshot, infcx.num_open_snapshots());
+impl<'tcx, E> FulfillmentCtxt<'tcx, E>
+where
+    E: FromSolverError<'tcx, NextSolverError<'tcx>>,
+{
+    fn evaluate_pending_obligations(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
         let mut errors = Vec::new();
         loop {
             let mut any_changed = false;
-            for (mut obligation, stalled_on) in self.obligations.drain_pending(|_| true) {
+            let mut pending = self.obligations.drain_pending(|_| true);
+            infcx.shuffle_if_requested(&mut pending);
+            for (mut obligation, stalled_on) in pending {
                 if !infcx.tcx.recursion_limit().value_within_limit(obligation.recursion_depth) {
                     self.obligations.on_fulfillment_overflow(infcx);
                     // Only return true errors that we have accumulated while processing.
@@ -212,10 +211,29 @@ fn try_evaluate_obligations(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
                 }
 
                 let result = delegate.evaluate_root_goal(goal, obligation.cause.span, stalled_on);
+                // Goals which are still stalled get evaluated again later on, so we only
+                // count them once we've made progress.
+                if !matches!(
+                    result,
+                    Ok(GoalEvaluation {
+                        certainty: Certainty::Maybe { .. },
+                        has_changed: HasChanged::No,
+                        ..
+                    })
+                ) {
+                    traits::note_solved_goal(infcx.tcx);
+                }
                 self.inspect_evaluated_obligation(infcx, &obligation, &result);
-                let GoalEvaluation { goal, certainty, has_changed, stalled_on } = match result {
+                let GoalEvaluation {
+                    goal,
+                    certainty,
+                    has_changed,
+                    stalled_on,
+                    exceeded_memory_limit,
+                } = match result {
                     Ok(result) => result,
                     Err(NoSolution) => {
+                        self.explain_if_requested(infcx, &obligation);
                         errors.push(E::from_solver_error(
                             infcx,
                             NextSolverError::TrueError(obligation),
@@ -241,6 +259,7 @@ fn try_evaluate_obligations(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
 
                 match certainty {
                     Certainty::Yes => {
+                        self.explain_if_requested(infcx, &obligation);
                         // Goals may depend on structural identity. Region uniquification at the
                         // start of MIR borrowck may cause things to no longer be so, potentially
                         // causing an ICE.
@@ -258,6 +277,9 @@ fn try_evaluate_obligations(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
                             infcx.push_hir_typeck_potentially_region_dependent_goal(obligation);
                         }
                     }
+                    Certainty::Maybe { .. } if exceeded_memory_limit => {
+                        self.obligations.too_complex.push(obligation)
+                    }
                     Certainty::Maybe { .. } => self.obligations.register(obligation, stalled_on),
                 }
             }
@@ -270,6 +292,83 @@ fn try_evaluate_obligations(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
         errors
     }
 
+    /// `-Zshuffle-obligations-verify`: Evaluates the pending obligations in two
+    /// different shuffled orders and reports an error if the results differ.
+    ///
+    /// We compare the number of errors, the number of obligations which remain
+    /// ambiguous, and the inferred values of the pending obligations.
+    fn verify_order_independence(&self, infcx: &InferCtxt<'tcx>) {
+        let evaluate_shuffled = || {
+            infcx.probe(|_| {
+                let mut fork = FulfillmentCtxt::<'tcx, ScrubbedTraitError<'tcx>>::new(infcx);
+                fork.obligations.pending = self.obligations.pending.clone();
+                let num_errors = fork.evaluate_pending_obligations(infcx).len();
+                let predicates: Vec<_> =
+                    self.obligations.pending.iter().map(|(o, _)| o.predicate).collect();
+                let predicates = predicates.fold_with(&mut TypeFreshener::new(infcx));
+                (num_errors, fork.obligations.pending.len(), predicates)
+            })
+        };
+
+        if evaluate_shuffled() != evaluate_shuffled() {
+            let (obligation, _) = &self.obligations.pending[0];
+            infcx.dcx().emit_err(OrderDependentObligations {
+                span: obligation.cause.span,
+                seed: infcx.tcx.sess.obligation_shuffle_seed.unwrap(),
+            });
+        }
+    }
+}
+
+impl<'tcx, E> TraitEngine<'tcx, E> for FulfillmentCtxt<'tcx, E>
+where
+    E: FromSolverError<'tcx, NextSolverError<'tcx>>,
+{
+    #[instrument(level = "trace", skip(self, infcx))]
+    fn register_predicate_obligation(
+        &mut self,
+        infcx: &InferCtxt<'tcx>,
+        obligation: PredicateObligation<'tcx>,
+    ) {
+        assert_eq!(self.usable_in_snapshot, infcx.num_open_snapshots());
+        self.obligations.register(obligation, None);
+    }
+
+    fn collect_remaining_errors(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
+        for (obligation, _) in &self.obligations.pending {
+            self.explain_if_requested(infcx, obligation);
+        }
+        self.obligations
+            .pending
+            .drain(..)
+            .map(|(obligation, _)| NextSolverError::Ambiguity(obligation))
+            .chain(
+                self.obligations
+                    .overflowed
+                    .drain(..)
+                    .map(|obligation| NextSolverError::Overflow(obligation)),
+            )
+            .chain(
+                self.obligations
+                    .too_complex
+                    .drain(..)
+                    .map(|obligation| NextSolverError::TooComplex(obligation)),
+            )
+            .map(|e| E::from_solver_error(infcx, e))
+            .collect()
+    }
+
+    fn try_evaluate_obligations(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
+        assert_eq!(self.usable_in_snapshot, infcx.num_open_snapshots());
+        if infcx.tcx.sess.opts.unstable_opts.shuffle_obligations_verify
+            && !self.obligations.pending.is_empty()
+        {
+            self.verify_order_independence(infcx);
+        }
+
+        self.evaluate_pending_obligations(infcx)
+    }
+
     fn has_pending_obligations(&self) -> bool {
         self.obligations.has_pending_obligations()
     }
@@ -373,6 +472,8 @@ pub enum NextSolverError<'tcx> {
     TrueError(PredicateObligation<'tcx>),
     Ambiguity(PredicateObligation<'tcx>),
     Overflow(PredicateObligation<'tcx>),
+    /// Evaluating the obligation exceeded the memory limit of the solver.
+    TooComplex(PredicateObligation<'tcx>),
 }
 
 impl<'tcx> FromSolverError<'tcx, NextSolverError<'tcx>> for FulfillmentError<'tcx> {
@@ -387,6 +488,9 @@ fn from_solver_error(infcx: &InferCtxt<'tcx>, error: NextSolverError<'tcx>) -> S
             NextSolverError::Overflow(obligation) => {
                 fulfillment_error_for_overflow(infcx, obligation)
             }
+            NextSolverError::TooComplex(obligation) => {
+                fulfillment_error_for_too_complex(infcx, obligation)
+            }
         }
     }
 }
@@ -395,9 +499,9 @@ impl<'tcx> FromSolverError<'tcx, NextSolverError<'tcx>> for ScrubbedTraitError<'
     fn from_solver_error(_infcx: &InferCtxt<'tcx>, error: NextSolverError<'tcx>) -> Self {
         match error {
             NextSolverError::TrueError(_) => ScrubbedTraitError::TrueError,
-            NextSolverError::Ambiguity(_) | NextSolverError::Overflow(_) => {
-                ScrubbedTraitError::Ambiguity
-            }
+            NextSolverError::Ambiguity(_)
+            | NextSolverError::Overflow(_)
+            | NextSolverError::TooComplex(_) => ScrubbedTraitError::Ambiguity,
         }
     }
 }
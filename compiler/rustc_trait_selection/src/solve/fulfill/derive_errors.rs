@@ -1,6 +1,8 @@
 use std::ops::ControlFlow;
 
-use rustc_hir::LangItem;
+use rustc_data_structures::sso::SsoHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{self as hir, LangItem};
 use rustc_infer::infer::InferCtxt;
 use rustc_infer::traits::solve::{CandidateSource, GoalSource, MaybeCause};
 use rustc_infer::traits::{
@@ -9,21 +11,45 @@
 };
 use rustc_middle::traits::query::NoSolution;
 use rustc_middle::ty::error::{ExpectedFound, TypeError};
-use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_middle::ty::{
+    self, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypeVisitor,
+};
 use rustc_middle::{bug, span_bug};
 use rustc_next_trait_solver::solve::{GoalEvaluation, SolverDelegateEvalExt as _};
+use rustc_span::{Span, Symbol, sym};
 use tracing::{instrument, trace};
 
 use crate::solve::delegate::SolverDelegate;
 use crate::solve::inspect::{self, InferCtxtProofTreeExt, ProofTreeVisitor};
 use crate::solve::{Certainty, deeply_normalize_for_diagnostics};
-use crate::traits::{FulfillmentError, FulfillmentErrorCode, wf};
+use crate::traits::{FulfillmentError, FulfillmentErrorCode, ObligationCtxt, wf};
+
+/// The maximum number of nested goals whose proof tree we build when looking for
+/// the best leaf obligation of a single error.
+///
+/// Building proof trees is a lot more expensive than evaluating goals, and for some
+/// pathological goals, e.g. ones with a huge number of nested goals, walking the
+/// proof tree can take longer than the rest of compilation. We only use it to improve
+/// diagnostics, so we instead stop and report the root obligation once we exceed this limit.
+const MAX_INSTANTIATED_GOALS_FOR_LEAF_OBLIGATION: usize = 256;
 
 pub(super) fn fulfillment_error_for_no_solution<'tcx>(
     infcx: &InferCtxt<'tcx>,
     root_obligation: PredicateObligation<'tcx>,
 ) -> FulfillmentError<'tcx> {
-    let obligation = find_best_leaf_obligation(infcx, &root_obligation, false);
+    let LeafObligation { obligation, refinement_exceeded_limits, ambiguous_impls, macro_span } =
+        match root_obligation.predicate.kind().skip_binder() {
+            // Type relations are reported as type mismatches using the cause of the root
+            // obligation, which carries the expectation of the expression, e.g. the return
+            // type or the other arm of an `if`. Their nested goals are never more helpful.
+            ty::PredicateKind::Subtype(_) | ty::PredicateKind::Coerce(_) => LeafObligation {
+                obligation: infcx.resolve_vars_if_possible(root_obligation.clone()),
+                refinement_exceeded_limits: false,
+                ambiguous_impls: vec![],
+                macro_span: None,
+            },
+            _ => find_best_leaf_obligation(infcx, &root_obligation, false),
+        };
 
     let code = match obligation.predicate.kind().skip_binder() {
         ty::PredicateKind::Clause(ty::ClauseKind::Projection(_)) => {
@@ -59,18 +85,28 @@ pub(super) fn fulfillment_error_for_no_solution<'tcx>(
             FulfillmentErrorCode::Project(MismatchedProjectionTypes { err: TypeError::Mismatch })
         }
         ty::PredicateKind::Subtype(pred) => {
-            let (a, b) = infcx.enter_forall_and_leak_universe(
+            let (a, b) = infcx.tcx.instantiate_bound_regions_with_erased(
                 obligation.predicate.kind().rebind((pred.a, pred.b)),
             );
-            let expected_found = ExpectedFound::new(a, b);
-            FulfillmentErrorCode::Subtype(expected_found, TypeError::Sorts(expected_found))
+            let expected_found = if pred.a_is_expected {
+                ExpectedFound::new(a, b)
+            } else {
+                ExpectedFound::new(b, a)
+            };
+            FulfillmentErrorCode::Subtype(
+                expected_found,
+                type_relation_error(infcx, &obligation, expected_found),
+            )
         }
         ty::PredicateKind::Coerce(pred) => {
-            let (a, b) = infcx.enter_forall_and_leak_universe(
+            let (a, b) = infcx.tcx.instantiate_bound_regions_with_erased(
                 obligation.predicate.kind().rebind((pred.a, pred.b)),
             );
             let expected_found = ExpectedFound::new(b, a);
-            FulfillmentErrorCode::Subtype(expected_found, TypeError::Sorts(expected_found))
+            FulfillmentErrorCode::Subtype(
+                expected_found,
+                type_relation_error(infcx, &obligation, expected_found),
+            )
         }
         ty::PredicateKind::Clause(_)
         | ty::PredicateKind::DynCompatible(_)
@@ -82,7 +118,148 @@ pub(super) fn fulfillment_error_for_no_solution<'tcx>(
         }
     };
 
-    FulfillmentError { obligation, code, root_obligation }
+    let code = match code {
+        FulfillmentErrorCode::Select(SelectionError::Unimplemented) => {
+            match feature_gated_builtin_impl(infcx, &obligation) {
+                Some(feature) => {
+                    FulfillmentErrorCode::Select(SelectionError::FeatureGated(feature))
+                }
+                None => code,
+            }
+        }
+        _ => code,
+    };
+
+    let reservation_impl = match code {
+        FulfillmentErrorCode::Select(SelectionError::Unimplemented) => {
+            applicable_reservation_impl(infcx, &obligation)
+        }
+        _ => None,
+    };
+
+    FulfillmentError {
+        obligation,
+        code,
+        root_obligation,
+        refinement_exceeded_limits,
+        ambiguous_impls,
+        reservation_impl,
+        nightly_only_impls: vec![],
+        macro_span,
+        exceeded_memory_limit: false,
+    }
+}
+
+/// Returns the unstable feature which, if it were enabled, would cause a builtin
+/// impl to apply to `obligation`. This mirrors the feature checks when assembling
+/// builtin candidates, which don't otherwise leave a trace in the proof tree.
+fn feature_gated_builtin_impl<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    obligation: &PredicateObligation<'tcx>,
+) -> Option<Symbol> {
+    let tcx = infcx.tcx;
+    let trait_pred = obligation.predicate.as_trait_clause()?.skip_binder();
+    if trait_pred.polarity != ty::PredicatePolarity::Positive {
+        return None;
+    }
+    match tcx.as_lang_item(trait_pred.def_id())? {
+        LangItem::Copy | LangItem::Clone => {}
+        _ => return None,
+    }
+    match *infcx.resolve_vars_if_possible(trait_pred.self_ty()).kind() {
+        ty::Coroutine(def_id, _)
+            if tcx.coroutine_movability(def_id) == hir::Movability::Movable
+                && !tcx.features().coroutine_clone() =>
+        {
+            Some(sym::coroutine_clone)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the reservation impl which would apply to the trait goal `obligation`
+/// if it were a normal impl, together with its `#[rustc_reservation_impl]` message.
+///
+/// Reservation impls never hold outside of coherence, so we mention them when
+/// reporting an unsatisfied trait bound to explain why the impl doesn't apply.
+fn applicable_reservation_impl<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    obligation: &PredicateObligation<'tcx>,
+) -> Option<(DefId, Symbol)> {
+    let tcx = infcx.tcx;
+    let trait_pred = obligation.predicate.as_trait_clause()?.no_bound_vars()?;
+    if trait_pred.polarity != ty::PredicatePolarity::Positive {
+        return None;
+    }
+
+    let mut reservation_impl = None;
+    tcx.for_each_relevant_impl(trait_pred.def_id(), trait_pred.self_ty(), |impl_def_id| {
+        if reservation_impl.is_some()
+            || tcx.impl_polarity(impl_def_id) != ty::ImplPolarity::Reservation
+        {
+            return;
+        }
+        let Some(message) =
+            tcx.get_attr(impl_def_id, sym::rustc_reservation_impl).and_then(|a| a.value_str())
+        else {
+            return;
+        };
+        let applies = infcx.probe(|_| {
+            let ocx = ObligationCtxt::new(infcx);
+            let impl_args = infcx.fresh_args_for_item(obligation.cause.span, impl_def_id);
+            let impl_trait_ref = tcx.impl_trait_ref(impl_def_id).instantiate(tcx, impl_args);
+            if ocx
+                .eq(&obligation.cause, obligation.param_env, trait_pred.trait_ref, impl_trait_ref)
+                .is_err()
+            {
+                return false;
+            }
+            ocx.register_obligations(
+                tcx.predicates_of(impl_def_id).instantiate(tcx, impl_args).into_iter().map(
+                    |(clause, _)| {
+                        Obligation::new(tcx, obligation.cause.clone(), obligation.param_env, clause)
+                    },
+                ),
+            );
+            ocx.try_evaluate_obligations().is_empty()
+        });
+        if applies {
+            reservation_impl = Some((impl_def_id, message));
+        }
+    });
+    reservation_impl
+}
+
+/// Relates the types of a failing `Subtype` or `Coerce` obligation again to get the
+/// actual error, e.g. a mismatch in mutability, like the old solver does.
+///
+/// Falls back to a mismatch of `expected_found` if the error refers to inference
+/// variables which only exist inside of the probe.
+fn type_relation_error<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    obligation: &PredicateObligation<'tcx>,
+    expected_found: ExpectedFound<Ty<'tcx>>,
+) -> TypeError<'tcx> {
+    let result = infcx.probe(|_| {
+        let result = match obligation.predicate.kind().skip_binder() {
+            ty::PredicateKind::Subtype(pred) => infcx.subtype_predicate(
+                &obligation.cause,
+                obligation.param_env,
+                obligation.predicate.kind().rebind(pred),
+            ),
+            ty::PredicateKind::Coerce(pred) => infcx.coerce_predicate(
+                &obligation.cause,
+                obligation.param_env,
+                obligation.predicate.kind().rebind(pred),
+            ),
+            _ => bug!("expected a type relation: {obligation:?}"),
+        };
+        match result {
+            Ok(Err(err)) if !err.has_infer() => Some(err),
+            Ok(_) | Err(_) => None,
+        }
+    });
+    result.unwrap_or(TypeError::Sorts(expected_found))
 }
 
 pub(super) fn fulfillment_error_for_stalled<'tcx>(
@@ -134,14 +311,74 @@ pub(super) fn fulfillment_error_for_stalled<'tcx>(
         }
     });
 
-    FulfillmentError {
-        obligation: if refine_obligation {
+    let LeafObligation { obligation, refinement_exceeded_limits, ambiguous_impls, macro_span } =
+        if refine_obligation {
             find_best_leaf_obligation(infcx, &root_obligation, true)
         } else {
-            root_obligation.clone()
-        },
+            LeafObligation {
+                obligation: root_obligation.clone(),
+                refinement_exceeded_limits: false,
+                ambiguous_impls: vec![],
+                macro_span: None,
+            }
+        };
+    let nightly_only_impls =
+        if refine_obligation { nightly_only_impls(infcx, &root_obligation) } else { vec![] };
+    FulfillmentError {
+        obligation,
         code,
         root_obligation,
+        refinement_exceeded_limits,
+        ambiguous_impls,
+        reservation_impl: None,
+        nightly_only_impls,
+        macro_span,
+        exceeded_memory_limit: false,
+    }
+}
+
+/// Returns the impls which may apply to the ambiguous trait goal `obligation` but
+/// are never selected as they require an unstable feature which isn't enabled,
+/// together with that feature.
+///
+/// These impls cause `obligation` to be ambiguous instead of using them for
+/// inference guidance, as that would break once the impl gets stabilized.
+fn nightly_only_impls<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    obligation: &PredicateObligation<'tcx>,
+) -> Vec<(DefId, Symbol)> {
+    if obligation.predicate.as_trait_clause().is_none() {
+        return vec![];
+    }
+    let mut visitor = NightlyOnlyImpls { span: obligation.cause.span, impls: vec![] };
+    infcx.probe(|_| infcx.visit_proof_tree(obligation.as_goal(), &mut visitor));
+    visitor.impls
+}
+
+struct NightlyOnlyImpls {
+    span: Span,
+    impls: Vec<(DefId, Symbol)>,
+}
+
+impl<'tcx> ProofTreeVisitor<'tcx> for NightlyOnlyImpls {
+    type Result = ();
+
+    fn span(&self) -> Span {
+        self.span
+    }
+
+    fn visit_goal(&mut self, goal: &inspect::InspectGoal<'_, 'tcx>) {
+        for candidate in goal.candidates() {
+            if let inspect::ProbeKind::TraitCandidate {
+                source: CandidateSource::Impl(impl_def_id),
+                result: _,
+            } = candidate.kind()
+                && candidate.result().is_ok()
+                && let Some(feature) = candidate.unstable_feature_bound()
+            {
+                self.impls.push((impl_def_id, feature));
+            }
+        }
     }
 }
 
@@ -149,46 +386,157 @@ pub(super) fn fulfillment_error_for_overflow<'tcx>(
     infcx: &InferCtxt<'tcx>,
     root_obligation: PredicateObligation<'tcx>,
 ) -> FulfillmentError<'tcx> {
+    let LeafObligation { obligation, refinement_exceeded_limits, ambiguous_impls, macro_span } =
+        find_best_leaf_obligation(infcx, &root_obligation, true);
     FulfillmentError {
-        obligation: find_best_leaf_obligation(infcx, &root_obligation, true),
+        obligation,
         code: FulfillmentErrorCode::Ambiguity { overflow: Some(true) },
         root_obligation,
+        refinement_exceeded_limits,
+        ambiguous_impls,
+        reservation_impl: None,
+        nightly_only_impls: vec![],
+        macro_span,
+        exceeded_memory_limit: false,
+    }
+}
+
+/// Unlike for other overflow errors we don't look for a better leaf obligation,
+/// as building the proof tree of `root_obligation` would exceed the memory limit
+/// of the solver again.
+pub(super) fn fulfillment_error_for_too_complex<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    root_obligation: PredicateObligation<'tcx>,
+) -> FulfillmentError<'tcx> {
+    FulfillmentError {
+        obligation: infcx.resolve_vars_if_possible(root_obligation.clone()),
+        code: FulfillmentErrorCode::Ambiguity { overflow: Some(false) },
+        root_obligation,
+        refinement_exceeded_limits: false,
+        ambiguous_impls: vec![],
+        reservation_impl: None,
+        nightly_only_impls: vec![],
+        macro_span: None,
+        exceeded_memory_limit: true,
     }
 }
 
+#[derive(Debug)]
+struct LeafObligation<'tcx> {
+    obligation: PredicateObligation<'tcx>,
+    /// Whether we stopped refining the obligation as doing so exceeded
+    /// [`MAX_INSTANTIATED_GOALS_FOR_LEAF_OBLIGATION`] or the `-Zerror-refinement-budget`
+    /// of the current body, in which case `obligation` is the root obligation itself.
+    refinement_exceeded_limits: bool,
+    /// If `obligation` is ambiguous as multiple impls apply, these impls.
+    ambiguous_impls: Vec<DefId>,
+    /// The span inside of a macro from another crate `obligation` pointed at, see
+    /// [`point_at_user_code`].
+    macro_span: Option<Span>,
+}
+
+/// Walks the proof tree of `obligation` to find the leaf obligation which is
+/// responsible for the error.
 #[instrument(level = "debug", skip(infcx), ret)]
 fn find_best_leaf_obligation<'tcx>(
     infcx: &InferCtxt<'tcx>,
     obligation: &PredicateObligation<'tcx>,
     consider_ambiguities: bool,
-) -> PredicateObligation<'tcx> {
+) -> LeafObligation<'tcx> {
     let obligation = infcx.resolve_vars_if_possible(obligation.clone());
+    // Once the budget of this body is exhausted, don't even bother building
+    // the proof tree.
+    if infcx.error_refinement_goals.get()
+        >= infcx.tcx.sess.opts.unstable_opts.error_refinement_budget
+    {
+        trace!("exhausted the error refinement budget of this body");
+        let mut obligation =
+            deeply_normalize_for_diagnostics(infcx, obligation.param_env, obligation);
+        let macro_span = point_at_user_code(infcx.tcx, &mut obligation);
+        return LeafObligation {
+            obligation,
+            refinement_exceeded_limits: true,
+            ambiguous_impls: vec![],
+            macro_span,
+        };
+    }
+    let mut visitor = BestObligation {
+        obligation: obligation.clone(),
+        root_predicate: obligation.predicate,
+        consider_ambiguities,
+        instantiated_goals: 0,
+        exceeded_limits: false,
+        ambiguous_impls: vec![],
+        visited_well_formed_terms: Default::default(),
+    };
     // FIXME: we use a probe here as the `BestObligation` visitor does not
     // check whether it uses candidates which get shadowed by where-bounds.
     //
     // We should probably fix the visitor to not do so instead, as this also
     // means the leaf obligation may be incorrect.
-    let obligation = infcx
-        .fudge_inference_if_ok(|| {
-            infcx
-                .visit_proof_tree(
-                    obligation.as_goal(),
-                    &mut BestObligation { obligation: obligation.clone(), consider_ambiguities },
-                )
+    let leaf_obligation = infcx
+        .probe_return_proof_tree(obligation.as_goal(), visitor.span(), |goal| {
+            visitor
+                .visit_goal(goal)
                 .break_value()
-                .ok_or(())
                 // walk around the fact that the cause in `Obligation` is ignored by folders so that
                 // we can properly fudge the infer vars in cause code.
                 .map(|o| (o.cause.clone(), o))
         })
-        .map(|(cause, o)| PredicateObligation { cause, ..o })
-        .unwrap_or(obligation);
-    deeply_normalize_for_diagnostics(infcx, obligation.param_env, obligation)
+        .map(|(cause, o)| PredicateObligation { cause, ..o });
+    let (obligation, ambiguous_impls) = match leaf_obligation {
+        Some(leaf_obligation) if !visitor.exceeded_limits => {
+            (leaf_obligation, visitor.ambiguous_impls)
+        }
+        Some(_) | None => (obligation, vec![]),
+    };
+    let mut obligation = deeply_normalize_for_diagnostics(infcx, obligation.param_env, obligation);
+    let macro_span = point_at_user_code(infcx.tcx, &mut obligation);
+    LeafObligation {
+        obligation,
+        refinement_exceeded_limits: visitor.exceeded_limits,
+        ambiguous_impls,
+        macro_span,
+    }
+}
+
+/// If the span of `obligation` points inside of the definition of a macro from
+/// another crate, points `obligation` at the closest call site in the macro
+/// backtrace which is in the current crate instead, and returns the original span.
+///
+/// The emitter would otherwise point at the outermost macro call, which may be far
+/// away from the code responsible for the error, and we couldn't suggest anything.
+fn point_at_user_code<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    obligation: &mut PredicateObligation<'tcx>,
+) -> Option<Span> {
+    let source_map = tcx.sess.source_map();
+    let span = obligation.cause.span;
+    if span.is_dummy() || !source_map.is_imported(span) {
+        return None;
+    }
+    let user_span = span
+        .macro_backtrace()
+        .map(|expn_data| expn_data.call_site)
+        .find(|&call_site| !call_site.is_dummy() && !source_map.is_imported(call_site))?;
+    obligation.cause.span = user_span;
+    Some(span)
 }
 
 struct BestObligation<'tcx> {
     obligation: PredicateObligation<'tcx>,
+    /// The predicate of the root obligation, i.e. the one written by the user.
+    root_predicate: ty::Predicate<'tcx>,
     consider_ambiguities: bool,
+    /// The number of nested goals whose proof tree we've built so far, see
+    /// [`MAX_INSTANTIATED_GOALS_FOR_LEAF_OBLIGATION`].
+    instantiated_goals: usize,
+    exceeded_limits: bool,
+    /// The impls which all apply to the ambiguous goal we stopped at, if any.
+    ambiguous_impls: Vec<DefId>,
+    /// The terms whose well-formedness we've already checked when falling back
+    /// to the sides of an alias-relate goal, see [`Self::visit_alias_relate_well_formed`].
+    visited_well_formed_terms: SsoHashSet<ty::Term<'tcx>>,
 }
 
 impl<'tcx> BestObligation<'tcx> {
@@ -205,21 +553,20 @@ fn with_derived_obligation(
 
     /// Filter out the candidates that aren't interesting to visit for the
     /// purposes of reporting errors. For ambiguities, we only consider
-    /// candidates that may hold. For errors, we only consider candidates that
-    /// *don't* hold and which have impl-where clauses that also don't hold.
+    /// candidates that may hold and aren't shadowed. For errors, we only
+    /// consider candidates that *don't* hold and which have impl-where
+    /// clauses that also don't hold.
     fn non_trivial_candidates<'a>(
         &self,
         goal: &'a inspect::InspectGoal<'a, 'tcx>,
     ) -> Vec<inspect::InspectCandidate<'a, 'tcx>> {
-        let mut candidates = goal.candidates();
         match self.consider_ambiguities {
-            true => {
-                // If we have an ambiguous obligation, we must consider *all* candidates
-                // that hold, or else we may guide inference causing other goals to go
-                // from ambig -> pass/fail.
-                candidates.retain(|candidate| candidate.result().is_ok());
-            }
+            // If we have an ambiguous obligation, we must consider *all* candidates
+            // that hold, or else we may guide inference causing other goals to go
+            // from ambig -> pass/fail.
+            true => goal.applicable_candidates(),
             false => {
+                let mut candidates = goal.candidates();
                 // We always handle rigid alias candidates separately as we may not add them for
                 // aliases whose trait bound doesn't hold.
                 candidates.retain(|c| !matches!(c.kind(), inspect::ProbeKind::RigidAlias { .. }));
@@ -242,10 +589,9 @@ fn non_trivial_candidates<'a>(
                         })
                     });
                 }
+                candidates
             }
         }
-
-        candidates
     }
 
     /// HACK: We walk the nested obligations for a well-formed arg manually,
@@ -374,6 +720,40 @@ fn detect_non_well_formed_assoc_item(
         })
     }
 
+    /// An alias-relate goal may fail because the lhs or rhs can't be normalized,
+    /// and is therefore treated as rigid.
+    ///
+    /// If both sides are not well-formed, this is most likely due to the same root
+    /// cause, e.g. `<T as Trait>::Assoc == <T as Trait>::Other` if `T: Trait` does
+    /// not hold. To avoid reporting both of them, we only check the well-formedness
+    /// of each term once per root obligation and prefer the side which is mentioned
+    /// in the root obligation, as that's the one written by the user.
+    fn visit_alias_relate_well_formed(
+        &mut self,
+        goal: &inspect::InspectGoal<'_, 'tcx>,
+        lhs: ty::Term<'tcx>,
+        rhs: ty::Term<'tcx>,
+    ) -> ControlFlow<PredicateObligation<'tcx>> {
+        let tcx = goal.infcx().tcx;
+        let root_predicate = self.root_predicate;
+        let is_mentioned =
+            |term: ty::Term<'tcx>| root_predicate.visit_with(&mut MentionsTerm(term)).is_break();
+        let sides = if !is_mentioned(lhs) && is_mentioned(rhs) { [rhs, lhs] } else { [lhs, rhs] };
+        for term in sides {
+            if !self.visited_well_formed_terms.insert(term) {
+                continue;
+            }
+
+            goal.infcx().visit_proof_tree_at_depth(
+                goal.goal().with(tcx, ty::ClauseKind::WellFormed(term)),
+                goal.depth() + 1,
+                self,
+            )?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
     /// If we have no candidates, then it's likely that there is a
     /// non-well-formed alias in the goal.
     fn detect_error_from_empty_candidates(
@@ -418,22 +798,40 @@ fn visit_goal(&mut self, goal: &inspect::InspectGoal<'_, 'tcx>) -> Self::Result
             _ => return ControlFlow::Continue(()),
         }
 
+        let infcx = goal.infcx();
+        let body_goals = infcx.error_refinement_goals.get() + 1;
+        infcx.error_refinement_goals.set(body_goals);
+        if body_goals > tcx.sess.opts.unstable_opts.error_refinement_budget {
+            trace!("exhausted the error refinement budget of this body -> exit");
+            self.exceeded_limits = true;
+            return ControlFlow::Break(self.obligation.clone());
+        }
+
         let pred = goal.goal().predicate;
 
         let candidates = self.non_trivial_candidates(goal);
         let candidate = match candidates.as_slice() {
             [candidate] => candidate,
             [] => return self.detect_error_from_empty_candidates(goal),
-            _ => return ControlFlow::Break(self.obligation.clone()),
+            _ => {
+                if self.consider_ambiguities {
+                    self.ambiguous_impls = candidates
+                        .iter()
+                        .filter_map(|c| match c.kind() {
+                            inspect::ProbeKind::TraitCandidate {
+                                source: CandidateSource::Impl(impl_def_id),
+                                result: _,
+                            } => Some(impl_def_id),
+                            _ => None,
+                        })
+                        .collect();
+                }
+                return ControlFlow::Break(self.obligation.clone());
+            }
         };
 
         // Don't walk into impls that have `do_not_recommend`.
-        if let inspect::ProbeKind::TraitCandidate {
-            source: CandidateSource::Impl(impl_def_id),
-            result: _,
-        } = candidate.kind()
-            && tcx.do_not_recommend_impl(impl_def_id)
-        {
+        if candidate.is_do_not_recommend_impl() {
             trace!("#[do_not_recommend] -> exit");
             return ControlFlow::Break(self.obligation.clone());
         }
@@ -465,6 +863,14 @@ fn visit_goal(&mut self, goal: &inspect::InspectGoal<'_, 'tcx>) -> Self::Result
         };
 
         let nested_goals = candidate.instantiate_nested_goals(self.span());
+        // We've built the proof tree of every nested goal, even if we only end up
+        // visiting the first one which failed.
+        self.instantiated_goals += nested_goals.len();
+        if self.instantiated_goals > MAX_INSTANTIATED_GOALS_FOR_LEAF_OBLIGATION {
+            trace!("exceeded the limit of instantiated goals -> exit");
+            self.exceeded_limits = true;
+            return ControlFlow::Break(self.obligation.clone());
+        }
 
         // If the candidate requires some `T: FnPtr` bound which does not hold should not be treated as
         // an actual candidate, instead we should treat them as if the impl was never considered to
@@ -535,19 +941,8 @@ fn visit_goal(&mut self, goal: &inspect::InspectGoal<'_, 'tcx>) -> Self::Result
             self.with_derived_obligation(obligation, |this| nested_goal.visit_with(this))?;
         }
 
-        // alias-relate may fail because the lhs or rhs can't be normalized,
-        // and therefore is treated as rigid.
         if let Some(ty::PredicateKind::AliasRelate(lhs, rhs, _)) = pred.kind().no_bound_vars() {
-            goal.infcx().visit_proof_tree_at_depth(
-                goal.goal().with(tcx, ty::ClauseKind::WellFormed(lhs.into())),
-                goal.depth() + 1,
-                self,
-            )?;
-            goal.infcx().visit_proof_tree_at_depth(
-                goal.goal().with(tcx, ty::ClauseKind::WellFormed(rhs.into())),
-                goal.depth() + 1,
-                self,
-            )?;
+            self.visit_alias_relate_well_formed(goal, lhs, rhs)?;
         }
 
         self.detect_trait_error_in_higher_ranked_projection(goal)?;
@@ -656,3 +1051,18 @@ fn derive_host_cause<'tcx>(
     };
     cause
 }
+
+/// Checks whether a type or const is mentioned somewhere in a value.
+struct MentionsTerm<'tcx>(ty::Term<'tcx>);
+
+impl<'tcx> TypeVisitor<TyCtxt<'tcx>> for MentionsTerm<'tcx> {
+    type Result = ControlFlow<()>;
+
+    fn visit_ty(&mut self, ty: Ty<'tcx>) -> Self::Result {
+        if self.0 == ty.into() { ControlFlow::Break(()) } else { ty.super_visit_with(self) }
+    }
+
+    fn visit_const(&mut self, ct: ty::Const<'tcx>) -> Self::Result {
+        if self.0 == ct.into() { ControlFlow::Break(()) } else { ct.super_visit_with(self) }
+    }
+}
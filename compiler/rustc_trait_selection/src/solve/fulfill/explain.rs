@@ -0,0 +1,57 @@
+//! `-Zexplain-goal`: prints how a goal at a given position has been proven.
+
+use rustc_infer::infer::InferCtxt;
+use rustc_infer::traits::PredicateObligation;
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_session::config::ExplainGoal;
+use rustc_span::Span;
+
+use crate::solve::inspect::InferCtxtProofTreeExt;
+
+/// Prints the proof tree of `obligation` if it is the goal requested by `-Zexplain-goal`.
+///
+/// This is called once the result of the obligation is final, i.e. when it has either
+/// been proven, or when it is reported as an error or as ambiguous.
+pub(super) fn explain_if_requested<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    obligation: &PredicateObligation<'tcx>,
+) {
+    let Some(explain_goal) = &infcx.tcx.sess.opts.unstable_opts.explain_goal else {
+        return;
+    };
+    let span = obligation.cause.span;
+    if !matches_position(infcx, explain_goal, span) {
+        return;
+    }
+    let obligation = infcx.resolve_vars_if_possible(obligation.clone());
+    let predicate = with_forced_trimmed_paths!(obligation.predicate.to_string());
+    if !eq_ignoring_whitespace(&predicate, &explain_goal.predicate) {
+        return;
+    }
+
+    let location = infcx.tcx.sess.source_map().span_to_diagnostic_string(span);
+    println!("explaining `{predicate}` at {location}:");
+    let proof_tree = infcx.owned_proof_tree(obligation.as_goal(), span);
+    for line in proof_tree.to_string().lines() {
+        println!("    {line}");
+    }
+}
+
+fn matches_position(infcx: &InferCtxt<'_>, explain_goal: &ExplainGoal, span: Span) -> bool {
+    let (Some(file), lo_line, lo_col, hi_line, hi_col) =
+        infcx.tcx.sess.source_map().span_to_location_info(span)
+    else {
+        return false;
+    };
+    if let Some(suffix) = &explain_goal.file
+        && !file.name.prefer_local_unconditionally().to_string().ends_with(suffix.as_str())
+    {
+        return false;
+    }
+    let position = (explain_goal.line, explain_goal.col);
+    (lo_line, lo_col) <= position && position <= (hi_line, hi_col)
+}
+
+fn eq_ignoring_whitespace(a: &str, b: &str) -> bool {
+    a.chars().filter(|c| !c.is_whitespace()).eq(b.chars().filter(|c| !c.is_whitespace()))
+}
@@ -1,4 +1,6 @@
 pub use rustc_next_trait_solver::solve::inspect::*;
 
 mod analyse;
+mod owned;
 pub use analyse::*;
+pub use owned::*;
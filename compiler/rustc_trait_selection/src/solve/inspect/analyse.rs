@@ -14,16 +14,19 @@
 use rustc_infer::infer::InferCtxt;
 use rustc_infer::traits::Obligation;
 use rustc_macros::extension;
-use rustc_middle::traits::ObligationCause;
-use rustc_middle::traits::solve::{Certainty, Goal, GoalSource, NoSolution, QueryResult};
-use rustc_middle::ty::{TyCtxt, VisitorResult, try_visit};
+use rustc_middle::traits::solve::{
+    CandidateSource, Certainty, Goal, GoalSource, NoSolution, ParamEnvSource, QueryResult,
+};
+use rustc_middle::traits::{BuiltinImplSource, ObligationCause};
+use rustc_middle::ty::{TyCtxt, TypeFoldable, TypingMode, VisitorResult, try_visit};
 use rustc_middle::{bug, ty};
 use rustc_next_trait_solver::canonical::instantiate_canonical_state;
 use rustc_next_trait_solver::resolve::eager_resolve_vars;
 use rustc_next_trait_solver::solve::{MaybeCause, SolverDelegateEvalExt as _, inspect};
-use rustc_span::Span;
+use rustc_span::{Span, Symbol};
 use tracing::instrument;
 
+use super::OwnedProofTree;
 use crate::solve::delegate::SolverDelegate;
 use crate::traits::ObligationCtxt;
 
@@ -121,6 +124,44 @@ pub fn shallow_certainty(&self) -> Certainty {
         self.shallow_certainty
     }
 
+    /// Whether this candidate uses an impl marked with `#[diagnostic::do_not_recommend]`,
+    /// in which case diagnostics should not point into its where-clauses.
+    pub fn is_do_not_recommend_impl(&self) -> bool {
+        match self.kind {
+            inspect::ProbeKind::TraitCandidate {
+                source: CandidateSource::Impl(impl_def_id),
+                result: _,
+            } => self.goal.infcx.tcx.do_not_recommend_impl(impl_def_id),
+            _ => false,
+        }
+    }
+
+    /// If this candidate uses an impl with an `#[unstable_feature_bound]` whose feature
+    /// may not be used by the goal, returns that feature. Such impls are only ever
+    /// ambiguous, so they never get selected outside of crates enabling the feature.
+    pub fn unstable_feature_bound(&self) -> Option<Symbol> {
+        let inspect::ProbeKind::TraitCandidate {
+            source: CandidateSource::Impl(impl_def_id),
+            result: _,
+        } = self.kind
+        else {
+            return None;
+        };
+        let infcx = self.goal.infcx;
+        let param_env = self.goal.goal.param_env;
+        infcx.tcx.predicates_of(impl_def_id).predicates.iter().find_map(|&(clause, _)| match clause
+            .kind()
+            .skip_binder()
+        {
+            ty::ClauseKind::UnstableFeature(feature)
+                if !ty::may_use_unstable_feature(&**infcx, param_env, feature) =>
+            {
+                Some(feature)
+            }
+            _ => None,
+        })
+    }
+
     /// Visit all nested goals of this candidate without rolling
     /// back their inference constraints. This function modifies
     /// the state of the `infcx`.
@@ -396,14 +437,61 @@ pub fn candidates(&'a self) -> Vec<InspectCandidate<'a, 'tcx>> {
         candidates
     }
 
+    /// Returns all candidates which apply for the current goal, dropping
+    /// candidates which are shadowed by other applicable candidates.
+    ///
+    /// This mirrors the candidate preference of the trait solver itself: trivial
+    /// builtin impls shadow everything else, non-global where-bounds shadow
+    /// everything but other where-bounds, and alias-bounds shadow impls. Global
+    /// where-bounds are only considered if there are no other candidates.
+    ///
+    /// FIXME(-Znext-solver): This does not handle the preference for alias-bounds
+    /// over where-bounds when proving marker traits or the shadowing of
+    /// specialized impls.
+    pub fn applicable_candidates(&'a self) -> Vec<InspectCandidate<'a, 'tcx>> {
+        let mut candidates = self.candidates();
+        candidates.retain(|c| c.result().is_ok());
+        if let TypingMode::Coherence = self.infcx.typing_mode() {
+            return candidates;
+        }
+
+        let source = |c: &InspectCandidate<'a, 'tcx>| match c.kind() {
+            inspect::ProbeKind::TraitCandidate { source, result: _ } => Some(source),
+            _ => None,
+        };
+        if let Some(trivial) = candidates.iter().position(|c| {
+            matches!(source(c), Some(CandidateSource::BuiltinImpl(BuiltinImplSource::Trivial)))
+        }) {
+            return vec![candidates.swap_remove(trivial)];
+        }
+
+        if candidates.iter().any(|c| {
+            matches!(source(c), Some(CandidateSource::ParamEnv(ParamEnvSource::NonGlobal)))
+        }) {
+            candidates.retain(|c| matches!(source(c), Some(CandidateSource::ParamEnv(_))));
+        } else if candidates
+            .iter()
+            .any(|c| matches!(source(c), Some(CandidateSource::AliasBound(_))))
+        {
+            candidates.retain(|c| matches!(source(c), Some(CandidateSource::AliasBound(_))));
+        } else if !candidates
+            .iter()
+            .all(|c| matches!(source(c), Some(CandidateSource::ParamEnv(ParamEnvSource::Global))))
+        {
+            candidates.retain(|c| {
+                !matches!(source(c), Some(CandidateSource::ParamEnv(ParamEnvSource::Global)))
+            });
+        }
+        candidates
+    }
+
     /// Returns the single candidate applicable for the current goal, if it exists.
+    /// Candidates shadowed by other applicable candidates are ignored, see
+    /// [`Self::applicable_candidates`].
     ///
     /// Returns `None` if there are either no or multiple applicable candidates.
     pub fn unique_applicable_candidate(&'a self) -> Option<InspectCandidate<'a, 'tcx>> {
-        // FIXME(-Znext-solver): This does not handle impl candidates
-        // hidden by env candidates.
-        let mut candidates = self.candidates();
-        candidates.retain(|c| c.result().is_ok());
+        let mut candidates = self.applicable_candidates();
         candidates.pop().filter(|_| candidates.is_empty())
     }
 
@@ -483,4 +571,42 @@ fn visit_proof_tree_at_depth<V: ProofTreeVisitor<'tcx>>(
             .evaluate_root_goal_for_proof_tree(goal, visitor.span());
         visitor.visit_goal(&InspectGoal::new(self, depth, proof_tree, None, GoalSource::Misc))
     }
+
+    /// Builds the proof tree of `goal` and converts it into an [`OwnedProofTree`],
+    /// rolling back all inference constraints afterwards.
+    fn owned_proof_tree(
+        &self,
+        goal: Goal<'tcx, ty::Predicate<'tcx>>,
+        span: Span,
+    ) -> OwnedProofTree {
+        self.probe(|_| {
+            let (_, proof_tree) =
+                <&SolverDelegate<'tcx>>::from(self).evaluate_root_goal_for_proof_tree(goal, span);
+            OwnedProofTree::new(
+                &InspectGoal::new(self, 0, proof_tree, None, GoalSource::Misc),
+                span,
+            )
+        })
+    }
+
+    /// Builds the proof tree of `goal` inside of a snapshot and passes it to `f`,
+    /// rolling back all inference constraints afterwards.
+    ///
+    /// Inference variables created inside of the snapshot are replaced by fresh
+    /// ones in the value returned by `f`, see [`InferCtxt::fudge_inference_if_ok`].
+    /// This means that the `ObligationCause` of a returned obligation is not fudged,
+    /// as it is ignored when folding.
+    fn probe_return_proof_tree<T: TypeFoldable<TyCtxt<'tcx>>>(
+        &self,
+        goal: Goal<'tcx, ty::Predicate<'tcx>>,
+        span: Span,
+        f: impl FnOnce(&InspectGoal<'_, 'tcx>) -> Option<T>,
+    ) -> Option<T> {
+        self.fudge_inference_if_ok(|| {
+            let (_, proof_tree) =
+                <&SolverDelegate<'tcx>>::from(self).evaluate_root_goal_for_proof_tree(goal, span);
+            f(&InspectGoal::new(self, 0, proof_tree, None, GoalSource::Misc)).ok_or(())
+        })
+        .ok()
+    }
 }
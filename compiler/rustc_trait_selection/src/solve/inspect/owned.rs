@@ -0,0 +1,161 @@
+//! A proof tree representation which is independent of the inference context
+//! it has been built in.
+//!
+//! Unlike [`InspectGoal`], an [`OwnedProofTree`] does not borrow anything, so it
+//! can be stored, sent to other threads, or serialized, e.g. to emit it later on
+//! or to pass it to external tools. Types and predicates are stored in their
+//! printed form with all inference variables resolved as far as possible.
+
+use std::fmt;
+
+use rustc_macros::{Decodable_NoContext, Encodable_NoContext};
+use rustc_middle::traits::solve::{CandidateSource, Certainty, NoSolution};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_span::Span;
+
+use super::{InspectGoal, ProbeKind, ProofTreeVisitor};
+
+/// The result of a goal or candidate in an [`OwnedProofTree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encodable_NoContext, Decodable_NoContext)]
+pub enum OwnedResult {
+    Yes,
+    Maybe,
+    No,
+}
+
+impl From<Result<Certainty, NoSolution>> for OwnedResult {
+    fn from(result: Result<Certainty, NoSolution>) -> OwnedResult {
+        match result {
+            Ok(Certainty::Yes) => OwnedResult::Yes,
+            Ok(Certainty::Maybe { .. }) => OwnedResult::Maybe,
+            Err(NoSolution) => OwnedResult::No,
+        }
+    }
+}
+
+impl fmt::Display for OwnedResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OwnedResult::Yes => "yes",
+            OwnedResult::Maybe => "maybe",
+            OwnedResult::No => "no",
+        })
+    }
+}
+
+/// An owned snapshot of the proof tree of a goal, see the module docs.
+#[derive(Debug, Clone, PartialEq, Eq, Encodable_NoContext, Decodable_NoContext)]
+pub struct OwnedProofTree {
+    /// The predicate of the goal.
+    pub goal: String,
+    pub result: OwnedResult,
+    pub candidates: Vec<OwnedCandidate>,
+}
+
+/// A candidate considered when proving the goal of an [`OwnedProofTree`].
+#[derive(Debug, Clone, PartialEq, Eq, Encodable_NoContext, Decodable_NoContext)]
+pub struct OwnedCandidate {
+    /// A description of the kind of candidate, e.g. the impl it comes from.
+    pub kind: String,
+    pub result: OwnedResult,
+    /// Whether this candidate is used to prove the goal, or may be used
+    /// if the goal is ambiguous.
+    pub applicable: bool,
+    pub nested_goals: Vec<OwnedProofTree>,
+}
+
+impl OwnedProofTree {
+    /// Converts the proof tree of `goal` into its owned form, up to the
+    /// same depth as [`ProofTreeVisitor`]s visit by default.
+    pub fn new(goal: &InspectGoal<'_, '_>, span: Span) -> OwnedProofTree {
+        let mut builder = OwnedProofTreeBuilder { span, stack: vec![Vec::new()] };
+        // Owned proof trees are printed outside of diagnostics, so we must
+        // not use trimmed paths, which may only be used if a diagnostic is
+        // actually emitted.
+        with_no_trimmed_paths!(builder.visit_goal(goal));
+        let mut root = builder.stack.pop().unwrap();
+        assert!(builder.stack.is_empty() && root.len() == 1);
+        root.pop().unwrap()
+    }
+
+    fn fmt_with_indent(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "    ".repeat(2 * depth);
+        writeln!(f, "{indent}goal `{}` => {}", self.goal, self.result)?;
+        for candidate in &self.candidates {
+            let selection = match (candidate.applicable, self.result) {
+                (true, OwnedResult::Yes) => " (selected)",
+                (true, OwnedResult::Maybe) => " (ambiguous)",
+                (false, _) | (true, OwnedResult::No) => "",
+            };
+            writeln!(
+                f,
+                "{indent}    candidate {} => {}{selection}",
+                candidate.kind, candidate.result
+            )?;
+            for nested in &candidate.nested_goals {
+                nested.fmt_with_indent(f, depth + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for OwnedProofTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_indent(f, 0)
+    }
+}
+
+struct OwnedProofTreeBuilder {
+    span: Span,
+    /// The goals of the candidates we're currently visiting, with the
+    /// last entry being the one nested goals are added to.
+    stack: Vec<Vec<OwnedProofTree>>,
+}
+
+impl<'tcx> ProofTreeVisitor<'tcx> for OwnedProofTreeBuilder {
+    fn span(&self) -> Span {
+        self.span
+    }
+
+    fn visit_goal(&mut self, goal: &InspectGoal<'_, 'tcx>) {
+        let infcx = goal.infcx();
+        let predicate = infcx.resolve_vars_if_possible(goal.goal().predicate);
+        let applicable: Vec<_> =
+            goal.applicable_candidates().iter().map(|candidate| candidate.kind()).collect();
+        let mut candidates = Vec::new();
+        for candidate in goal.candidates() {
+            let kind = match candidate.kind() {
+                ProbeKind::TraitCandidate { source, result: _ } => match source {
+                    CandidateSource::Impl(def_id) => {
+                        format!("impl `{}`", infcx.tcx.def_path_str(def_id))
+                    }
+                    CandidateSource::BuiltinImpl(source) => format!("builtin impl ({source:?})"),
+                    CandidateSource::ParamEnv(source) => format!("where-clause ({source:?})"),
+                    CandidateSource::AliasBound(kind) => format!("alias-bound ({kind:?})"),
+                    CandidateSource::CoherenceUnknowable => "unknowable impl".to_owned(),
+                },
+                ProbeKind::Root { .. } => "root".to_owned(),
+                ProbeKind::OpaqueTypeStorageLookup { .. } => {
+                    "opaque type storage lookup".to_owned()
+                }
+                ProbeKind::RigidAlias { .. } => "rigid alias".to_owned(),
+                kind => format!("{kind:?}"),
+            };
+            self.stack.push(Vec::new());
+            candidate.visit_nested_in_probe(self);
+            let nested_goals = self.stack.pop().unwrap();
+            candidates.push(OwnedCandidate {
+                kind,
+                result: candidate.result().into(),
+                applicable: applicable.contains(&candidate.kind()),
+                nested_goals,
+            });
+        }
+        self.stack.last_mut().unwrap().push(OwnedProofTree {
+            goal: predicate.to_string(),
+            result: goal.result().into(),
+            candidates,
+        });
+    }
+}
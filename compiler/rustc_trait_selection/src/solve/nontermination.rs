@@ -0,0 +1,116 @@
+//! Detects overflow caused by an impl which recursively requires itself to hold for
+//! ever larger types, e.g. `impl<T> Trait for T where Box<T>: Trait`. Increasing the
+//! recursion limit never helps in this case.
+
+use std::ops::ControlFlow;
+
+use rustc_hir::def_id::DefId;
+use rustc_infer::infer::InferCtxt;
+use rustc_infer::traits::PredicateObligation;
+use rustc_infer::traits::query::NoSolution;
+use rustc_infer::traits::solve::{CandidateSource, Certainty, MaybeCause};
+use rustc_middle::traits::solve::inspect::ProbeKind;
+use rustc_middle::ty::{self, TypeVisitableExt};
+use rustc_span::Span;
+
+use crate::solve::inspect::{InferCtxtProofTreeExt, InspectConfig, InspectGoal, ProofTreeVisitor};
+
+/// How deep we walk into the proof tree when looking for self-similar goals.
+const MAX_DEPTH: usize = 16;
+
+/// The maximum number of goals we visit, as the proof tree of an overflowing
+/// goal may be very large.
+const MAX_VISITED_GOALS: usize = 256;
+
+/// Returns the impl which causes `obligation` to overflow by requiring itself to
+/// hold for a larger instance of the same trait predicate each time it is used.
+///
+/// This must only be used with the new solver.
+pub(crate) fn nonterminating_impl<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    obligation: &PredicateObligation<'tcx>,
+) -> Option<DefId> {
+    let mut visitor = GrowingGoals { span: obligation.cause.span, path: vec![], visited_goals: 0 };
+    infcx.probe(|_| infcx.visit_proof_tree(obligation.as_goal(), &mut visitor)).break_value()
+}
+
+struct GrowingGoals<'tcx> {
+    span: Span,
+    /// The trait predicates on the path to the overflow, together with the
+    /// impl used to prove them.
+    path: Vec<(ty::PolyTraitPredicate<'tcx>, DefId)>,
+    visited_goals: usize,
+}
+
+impl<'tcx> ProofTreeVisitor<'tcx> for GrowingGoals<'tcx> {
+    type Result = ControlFlow<DefId>;
+
+    fn span(&self) -> Span {
+        self.span
+    }
+
+    fn config(&self) -> InspectConfig {
+        InspectConfig { max_depth: MAX_DEPTH }
+    }
+
+    fn visit_goal(&mut self, goal: &InspectGoal<'_, 'tcx>) -> Self::Result {
+        if !is_overflow(goal.result()) || self.visited_goals >= MAX_VISITED_GOALS {
+            return ControlFlow::Continue(());
+        }
+        self.visited_goals += 1;
+
+        let infcx = goal.infcx();
+        let Some(trait_pred) =
+            infcx.resolve_vars_if_possible(goal.goal().predicate).as_trait_clause()
+        else {
+            return ControlFlow::Continue(());
+        };
+        for candidate in goal.candidates() {
+            let ProbeKind::TraitCandidate { source: CandidateSource::Impl(impl_def_id), .. } =
+                candidate.kind()
+            else {
+                continue;
+            };
+            if !is_overflow(candidate.result()) {
+                continue;
+            }
+
+            if self
+                .path
+                .iter()
+                .any(|&(prev, prev_impl)| prev_impl == impl_def_id && grows(prev, trait_pred))
+            {
+                return ControlFlow::Break(impl_def_id);
+            }
+
+            self.path.push((trait_pred, impl_def_id));
+            let result = candidate.visit_nested_in_probe(self);
+            self.path.pop();
+            result?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+fn is_overflow(result: Result<Certainty, NoSolution>) -> bool {
+    matches!(result, Ok(Certainty::Maybe { cause: MaybeCause::Overflow { .. }, .. }))
+}
+
+/// Whether `new` is a strictly larger instance of `prev`, i.e. whether each of
+/// its generic arguments is either equal to or contains the corresponding
+/// argument of `prev`.
+fn grows<'tcx>(prev: ty::PolyTraitPredicate<'tcx>, new: ty::PolyTraitPredicate<'tcx>) -> bool {
+    let (Some(prev), Some(new)) = (prev.no_bound_vars(), new.no_bound_vars()) else {
+        return false;
+    };
+    if prev == new
+        || prev.def_id() != new.def_id()
+        || prev.polarity != new.polarity
+        || prev.has_non_region_infer()
+    {
+        return false;
+    }
+    std::iter::zip(prev.trait_ref.args, new.trait_ref.args)
+        .all(|(prev, new)| new.walk().any(|arg| arg == prev))
+}
@@ -16,8 +16,13 @@
 
 #[extension(pub trait InferCtxtSelectExt<'tcx>)]
 impl<'tcx> InferCtxt<'tcx> {
-    /// Do not use this directly. This is called from [`crate::traits::SelectionContext::select`].
-    fn select_in_new_trait_solver(
+    /// Selects the candidate used to prove `obligation` with the new trait solver,
+    /// returning its `ImplSource` together with the nested obligations of that
+    /// candidate.
+    ///
+    /// This is used by [`crate::traits::SelectionContext::select`] and by codegen
+    /// selection when the new solver is enabled.
+    fn select_in_new_solver(
         &self,
         obligation: &TraitObligation<'tcx>,
     ) -> SelectionResult<'tcx, Selection<'tcx>> {
@@ -44,8 +49,7 @@ fn span(&self) -> Span {
     }
 
     fn visit_goal(&mut self, goal: &inspect::InspectGoal<'_, 'tcx>) -> Self::Result {
-        let mut candidates = goal.candidates();
-        candidates.retain(|cand| cand.result().is_ok());
+        let mut candidates = goal.applicable_candidates();
 
         // No candidates -- not implemented.
         if candidates.is_empty() {
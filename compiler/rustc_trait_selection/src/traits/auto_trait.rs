@@ -1,14 +1,18 @@
 //! Support code for rustdoc and external tools.
 //! You really don't want to be using this unless you need to.
 
+use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::iter;
 
-use rustc_data_structures::fx::{FxIndexMap, FxIndexSet, IndexEntry};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet, IndexEntry};
 use rustc_data_structures::unord::UnordSet;
 use rustc_hir::def_id::CRATE_DEF_ID;
-use rustc_infer::infer::DefineOpaqueTypes;
-use rustc_middle::ty::{Region, RegionVid};
+use rustc_infer::infer::{DefineOpaqueTypes, RegionVariableOrigin};
+use rustc_infer::traits::solve::{CandidateSource, Certainty, Goal};
+use rustc_middle::traits::solve::inspect::ProbeKind;
+use rustc_middle::ty::{Region, RegionVid, fold_regions};
+use rustc_span::DUMMY_SP;
 use tracing::debug;
 
 use super::*;
@@ -16,6 +20,7 @@
 use crate::infer::TypeFreshener;
 use crate::infer::region_constraints::{ConstraintKind, RegionConstraintData};
 use crate::regions::OutlivesEnvironmentBuildExt;
+use crate::solve::inspect::{InferCtxtProofTreeExt, InspectConfig, InspectGoal, ProofTreeVisitor};
 use crate::traits::project::ProjectAndUnifyResult;
 
 // FIXME(twk): this is obviously not nice to duplicate like that
@@ -45,11 +50,22 @@ pub struct AutoTraitInfo<'cx> {
 
 pub struct AutoTraitFinder<'tcx> {
     tcx: TyCtxt<'tcx>,
+    /// The bounds under which an ADT implements an auto trait, in terms of the
+    /// generic parameters of the ADT, or `None` if it never implements it.
+    ///
+    /// This is only used with the new solver, see [`Self::adt_auto_trait_bounds`].
+    adt_bounds: RefCell<FxHashMap<(DefId, DefId), Option<Vec<ty::Clause<'tcx>>>>>,
+    /// The ADTs whose bounds we're currently computing, used to avoid cycles.
+    adts_in_progress: RefCell<FxHashSet<(DefId, DefId)>>,
 }
 
 impl<'tcx> AutoTraitFinder<'tcx> {
     pub fn new(tcx: TyCtxt<'tcx>) -> Self {
-        AutoTraitFinder { tcx }
+        AutoTraitFinder {
+            tcx,
+            adt_bounds: Default::default(),
+            adts_in_progress: Default::default(),
+        }
     }
 
     /// Makes a best effort to determine whether and under which conditions an auto trait is
@@ -78,6 +94,14 @@ pub fn find_auto_trait_generics<A>(
         mut auto_trait_callback: impl FnMut(AutoTraitInfo<'tcx>) -> A,
     ) -> AutoTraitResult<A> {
         let tcx = self.tcx;
+        if tcx.next_trait_solver_globally() {
+            return self.find_auto_trait_generics_next_solver(
+                ty,
+                typing_env,
+                trait_did,
+                auto_trait_callback,
+            );
+        }
 
         let trait_ref = ty::TraitRef::new(tcx, trait_did, [ty]);
 
@@ -173,6 +197,182 @@ pub fn find_auto_trait_generics<A>(
         AutoTraitResult::PositiveImpl(auto_trait_callback(info))
     }
 
+    /// The implementation of [`Self::find_auto_trait_generics`] used with the new solver.
+    ///
+    /// Instead of driving selection by hand, we inspect the proof tree of the auto trait
+    /// goal and add the failing nested goals on generic parameters to our `ParamEnv` until
+    /// the goal holds, see [`Self::auto_trait_bounds`].
+    fn find_auto_trait_generics_next_solver<A>(
+        &self,
+        ty: Ty<'tcx>,
+        typing_env: ty::TypingEnv<'tcx>,
+        trait_did: DefId,
+        mut auto_trait_callback: impl FnMut(AutoTraitInfo<'tcx>) -> A,
+    ) -> AutoTraitResult<A> {
+        let tcx = self.tcx;
+        if tcx.non_blanket_impls_for_ty(trait_did, ty).next().is_some() {
+            debug!(
+                "find_auto_trait_generics({ty:?}, {trait_did:?}): manual impl found, bailing out"
+            );
+            return AutoTraitResult::ExplicitImpl;
+        }
+
+        let (infcx, orig_env) = tcx.infer_ctxt().build_with_typing_env(typing_env);
+        let Some(user_bounds) = self.auto_trait_bounds(&infcx, ty, orig_env, trait_did) else {
+            return AutoTraitResult::NegativeImpl;
+        };
+        let full_env = self.extend_param_env(orig_env, user_bounds.iter().copied());
+        let full_user_env = ty::ParamEnv::new(tcx.mk_clauses(&user_bounds));
+
+        // Like with the old solver, prove the goal in the final `ParamEnv` to
+        // collect the region constraints we need.
+        let ocx = ObligationCtxt::new(&infcx);
+        ocx.register_bound(ObligationCause::dummy(), full_env, ty, trait_did);
+        let errors = ocx.evaluate_obligations_error_on_ambiguity();
+        if !errors.is_empty() {
+            // The bounds we computed are only a best guess, e.g. we ignore goals
+            // which are ambiguous. Rather than documenting an impl which may not
+            // hold, we don't document any impl in this case, as if the type had
+            // an explicit impl.
+            debug!(
+                "find_auto_trait_generics({ty:?}, {trait_did:?}): \
+                 unable to fulfill with {full_env:?}: {errors:?}"
+            );
+            return AutoTraitResult::ExplicitImpl;
+        }
+
+        let outlives_env = OutlivesEnvironment::new(&infcx, CRATE_DEF_ID, full_env, []);
+        let _ = infcx.process_registered_region_obligations(&outlives_env, |ty, _| Ok(ty));
+
+        let region_data = infcx.inner.borrow_mut().unwrap_region_constraints().data().clone();
+        let vid_to_region = self.map_vid_to_region(&region_data);
+        let info = AutoTraitInfo { full_user_env, region_data, vid_to_region };
+        AutoTraitResult::PositiveImpl(auto_trait_callback(info))
+    }
+
+    /// Computes the bounds which have to be added to `param_env` for `ty` to implement
+    /// the auto trait `trait_did`, or returns `None` if it never does.
+    ///
+    /// We use a worklist of trait goals, starting with `ty: Trait`. Goals whose self type
+    /// is a generic parameter get added to the `ParamEnv` if they don't already hold. For
+    /// other goals we walk their proof tree and add their failing nested goals to the
+    /// worklist. Goals for ADTs without manual impls use the cached bounds of that ADT
+    /// instead, so that we only compute them once per ADT.
+    fn auto_trait_bounds(
+        &self,
+        infcx: &InferCtxt<'tcx>,
+        ty: Ty<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+        trait_did: DefId,
+    ) -> Option<Vec<ty::Clause<'tcx>>> {
+        let tcx = self.tcx;
+        let mut bounds = FxIndexSet::default();
+        let mut visited = FxHashSet::default();
+        let mut pending = VecDeque::from([ty::TraitRef::new(tcx, trait_did, [ty])]);
+        while let Some(trait_ref) = pending.pop_front() {
+            if !visited.insert(trait_ref) {
+                continue;
+            }
+
+            let env = self.extend_param_env(param_env, bounds.iter().copied());
+            if self.is_param_no_infer(trait_ref.args) {
+                let obligation = Obligation::new(tcx, ObligationCause::dummy(), env, trait_ref);
+                if !infcx.predicate_must_hold_modulo_regions(&obligation) {
+                    bounds.insert(trait_ref.upcast(tcx));
+                }
+                continue;
+            }
+
+            if let Some(adt_bounds) = self.adt_auto_trait_bounds(trait_ref) {
+                pending.extend(adt_bounds?.into_iter().filter_map(|clause| {
+                    clause.as_trait_clause()?.no_bound_vars().map(|pred| pred.trait_ref)
+                }));
+                continue;
+            }
+
+            let mut visitor = AutoTraitBoundsVisitor { finder: self, pending: vec![] };
+            if infcx.visit_proof_tree(Goal::new(tcx, env, trait_ref), &mut visitor).is_break() {
+                debug!("auto_trait_bounds({ty:?}, {trait_did:?}): `{trait_ref}` never holds");
+                return None;
+            }
+
+            // Region variables of the proof tree don't exist outside of it.
+            pending.extend(visitor.pending.into_iter().map(|trait_ref| {
+                fold_regions(tcx, trait_ref, |r, _| {
+                    if r.is_erased() {
+                        infcx.next_region_var(RegionVariableOrigin::Misc(DUMMY_SP))
+                    } else {
+                        r
+                    }
+                })
+            }));
+        }
+
+        Some(bounds.into_iter().collect())
+    }
+
+    /// Returns the instantiated bounds of `trait_ref` if its self type is an ADT which
+    /// implements the auto trait via its builtin impl, computing and caching them for
+    /// the ADT if necessary.
+    fn adt_auto_trait_bounds(
+        &self,
+        trait_ref: ty::TraitRef<'tcx>,
+    ) -> Option<Option<Vec<ty::Clause<'tcx>>>> {
+        let tcx = self.tcx;
+        let ty::Adt(adt, args) = *trait_ref.self_ty().kind() else {
+            return None;
+        };
+        let trait_did = trait_ref.def_id;
+        if !tcx.trait_is_auto(trait_did)
+            || trait_ref.args.len() != 1
+            || tcx.non_blanket_impls_for_ty(trait_did, trait_ref.self_ty()).next().is_some()
+        {
+            return None;
+        }
+
+        let key = (adt.did(), trait_did);
+        let cached = self.adt_bounds.borrow().get(&key).cloned();
+        let bounds = match cached {
+            Some(bounds) => bounds,
+            None => {
+                if !self.adts_in_progress.borrow_mut().insert(key) {
+                    return None;
+                }
+
+                let infcx = tcx.infer_ctxt().build(TypingMode::non_body_analysis());
+                let bounds = self.auto_trait_bounds(
+                    &infcx,
+                    tcx.type_of(adt.did()).instantiate_identity(),
+                    tcx.param_env(adt.did()),
+                    trait_did,
+                );
+                self.adts_in_progress.borrow_mut().remove(&key);
+                // Region variables are local to this `InferCtxt`.
+                if bounds.has_infer() {
+                    return None;
+                }
+
+                self.adt_bounds.borrow_mut().insert(key, bounds.clone());
+                bounds
+            }
+        };
+
+        Some(
+            bounds
+                .map(|bounds| ty::EarlyBinder::bind(bounds).iter_instantiated(tcx, args).collect()),
+        )
+    }
+
+    /// Adds `bounds` and everything they imply to `param_env`.
+    fn extend_param_env(
+        &self,
+        param_env: ty::ParamEnv<'tcx>,
+        bounds: impl IntoIterator<Item = ty::Clause<'tcx>>,
+    ) -> ty::ParamEnv<'tcx> {
+        let clauses = param_env.caller_bounds().iter().chain(bounds);
+        ty::ParamEnv::new(self.tcx.mk_clauses_from_iter(elaborate(self.tcx, clauses)))
+    }
+
     /// The core logic responsible for computing the bounds for our synthesized impl.
     ///
     /// To calculate the bounds, we call `SelectionContext.select` in a loop. Like
@@ -821,3 +1021,61 @@ pub fn clean_pred(
         p.fold_with(&mut TypeFreshener::new(infcx))
     }
 }
+
+/// Collects the failing nested goals of an auto trait goal which have to be
+/// handled by [`AutoTraitFinder::auto_trait_bounds`].
+struct AutoTraitBoundsVisitor<'a, 'tcx> {
+    finder: &'a AutoTraitFinder<'tcx>,
+    pending: Vec<ty::TraitRef<'tcx>>,
+}
+
+impl<'tcx> ProofTreeVisitor<'tcx> for AutoTraitBoundsVisitor<'_, 'tcx> {
+    type Result = ControlFlow<()>;
+
+    fn span(&self) -> Span {
+        DUMMY_SP
+    }
+
+    fn config(&self) -> InspectConfig {
+        InspectConfig { max_depth: 64 }
+    }
+
+    fn visit_goal(&mut self, goal: &InspectGoal<'_, 'tcx>) -> Self::Result {
+        if let Ok(Certainty::Yes) = goal.result() {
+            return ControlFlow::Continue(());
+        }
+
+        let tcx = goal.infcx().tcx;
+        let predicate = goal.infcx().resolve_vars_if_possible(goal.goal().predicate);
+        if goal.depth() > 0
+            && let Some(trait_pred) = predicate.as_trait_clause().and_then(|p| p.no_bound_vars())
+            && trait_pred.polarity == ty::PredicatePolarity::Positive
+            && !trait_pred.trait_ref.args.has_non_region_infer()
+            && (self.finder.is_of_param(trait_pred.self_ty())
+                || matches!(trait_pred.self_ty().kind(), ty::Adt(..)))
+        {
+            // Region variables of the proof tree don't exist outside of it,
+            // so we replace them with new ones later on.
+            self.pending.push(fold_regions(tcx, trait_pred.trait_ref, |r, _| {
+                if r.is_var() { tcx.lifetimes.re_erased } else { r }
+            }));
+            return ControlFlow::Continue(());
+        }
+
+        // Where-bounds don't help us here, as they are missing the bounds we're
+        // looking for in the first place.
+        let candidate = goal.candidates().into_iter().find(|candidate| {
+            !matches!(
+                candidate.kind(),
+                ProbeKind::TraitCandidate {
+                    source: CandidateSource::ParamEnv(_) | CandidateSource::AliasBound(_),
+                    ..
+                }
+            )
+        });
+        match candidate {
+            Some(candidate) => candidate.visit_nested_in_probe(self),
+            None => ControlFlow::Break(()),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Support code for rustdoc and external tools.
+//! You really don't want to be using this unless you need to.
+
+use rustc_hir::def_id::DefId;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_infer::traits::solve::{CandidateSource, Goal};
+use rustc_middle::traits::solve::inspect::ProbeKind;
+use rustc_middle::ty::{self, TyCtxt, TypingMode};
+use rustc_span::{DUMMY_SP, Span};
+use tracing::{debug, instrument};
+
+use crate::solve::inspect::{InferCtxtProofTreeExt, InspectGoal, ProofTreeVisitor};
+
+/// Returns the blanket impls of `trait_def_id` which may apply to the type of
+/// `item_def_id` for some instantiation of its generic parameters.
+///
+/// This uses the candidates assembled by the new trait solver when proving that
+/// the type implements the trait, so impls whose where-clauses only hold for some
+/// instantiations, or which result in overflow, are considered to apply.
+#[instrument(level = "debug", skip(tcx), ret)]
+pub fn applicable_blanket_impls<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    item_def_id: DefId,
+    trait_def_id: DefId,
+) -> Vec<DefId> {
+    let blanket_impls = tcx.trait_impls_of(trait_def_id).blanket_impls();
+    if blanket_impls.is_empty() {
+        return vec![];
+    }
+
+    let infcx =
+        tcx.infer_ctxt().with_next_trait_solver(true).build(TypingMode::non_body_analysis());
+    let item_args = infcx.fresh_args_for_item(DUMMY_SP, item_def_id);
+    let ty = tcx.type_of(item_def_id).instantiate(tcx, item_args);
+    let trait_args = infcx.fresh_args_for_item(DUMMY_SP, trait_def_id);
+    let trait_ref = ty::TraitRef::new(
+        tcx,
+        trait_def_id,
+        [ty.into()].into_iter().chain(trait_args.iter().skip(1)),
+    );
+
+    let mut visitor = BlanketImplsVisitor { blanket_impls, applicable: vec![] };
+    let goal = Goal::new(tcx, ty::ParamEnv::empty(), trait_ref);
+    infcx.visit_proof_tree(goal, &mut visitor);
+    visitor.applicable
+}
+
+struct BlanketImplsVisitor<'a> {
+    blanket_impls: &'a [DefId],
+    applicable: Vec<DefId>,
+}
+
+impl<'tcx> ProofTreeVisitor<'tcx> for BlanketImplsVisitor<'_> {
+    type Result = ();
+
+    fn span(&self) -> Span {
+        DUMMY_SP
+    }
+
+    fn visit_goal(&mut self, goal: &InspectGoal<'_, 'tcx>) {
+        let tcx = goal.infcx().tcx;
+        for candidate in goal.candidates() {
+            let ProbeKind::TraitCandidate { source: CandidateSource::Impl(impl_def_id), .. } =
+                candidate.kind()
+            else {
+                continue;
+            };
+            if candidate.result().is_err() || !self.blanket_impls.contains(&impl_def_id) {
+                continue;
+            }
+            // Only consider impls for a type parameter, ignoring e.g. impls for
+            // projections which end up in the same list.
+            let impl_self_ty = tcx.impl_trait_ref(impl_def_id).skip_binder().self_ty();
+            if !matches!(impl_self_ty.kind(), ty::Param(_)) {
+                continue;
+            }
+
+            debug!(?impl_def_id, "found applicable blanket impl");
+            self.applicable.push(impl_def_id);
+        }
+    }
+}
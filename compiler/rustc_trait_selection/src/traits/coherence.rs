@@ -18,6 +18,7 @@
 use rustc_middle::traits::solve::{CandidateSource, Certainty, Goal};
 use rustc_middle::traits::specialization_graph::OverlapMode;
 use rustc_middle::ty::fast_reject::DeepRejectCtxt;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{
     self, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypeVisitor, TypingMode,
 };
@@ -28,6 +29,7 @@
 
 use super::ObligationCtxt;
 use crate::error_reporting::traits::suggest_new_overflow_limit;
+use crate::errors::FundamentalTypeDownstreamImpls;
 use crate::infer::InferOk;
 use crate::solve::inspect::{InferCtxtProofTreeExt, InspectGoal, ProofTreeVisitor};
 use crate::solve::{SolverDelegate, deeply_normalize_for_diagnostics, inspect};
@@ -69,6 +71,43 @@ pub fn add_placeholder_note<G: EmissionGuarantee>(err: &mut Diag<'_, G>) {
     );
 }
 
+/// Explains why downstream crates may implement traits for `#[fundamental]` types,
+/// as the notes of `causes` may otherwise be confusing for foreign types like `Box<_>`.
+pub fn add_fundamental_type_notes<G: EmissionGuarantee>(
+    tcx: TyCtxt<'_>,
+    err: &mut Diag<'_, G>,
+    causes: &FxIndexSet<IntercrateAmbiguityCause<'_>>,
+) {
+    let wrappers: FxIndexSet<_> = causes
+        .iter()
+        .filter_map(|cause| {
+            let IntercrateAmbiguityCause::DownstreamCrate { trait_ref, self_ty: Some(self_ty) } =
+                cause
+            else {
+                return None;
+            };
+            // Downstream crates may also implement the trait if one of its other
+            // arguments is their own type, so the self type need not matter.
+            if trait_ref.args.types().skip(1).any(|ty| ty.has_non_region_infer()) {
+                return None;
+            }
+            // Only mention the wrapper if it's what allows downstream crates to
+            // implement the trait, i.e. if it directly wraps their own type.
+            let (wrapper, wrapped) = match *self_ty.kind() {
+                ty::Ref(_, wrapped, _) => ("reference".to_owned(), wrapped),
+                ty::Adt(adt_def, args) if adt_def.is_fundamental() => {
+                    (with_no_trimmed_paths!(tcx.def_path_str(adt_def.did())), args.types().next()?)
+                }
+                _ => return None,
+            };
+            wrapped.is_ty_var().then_some(wrapper)
+        })
+        .collect();
+    for wrapper in wrappers {
+        err.subdiagnostic(FundamentalTypeDownstreamImpls { wrapper });
+    }
+}
+
 pub(crate) fn suggest_increasing_recursion_limit<'tcx, G: EmissionGuarantee>(
     tcx: TyCtxt<'tcx>,
     err: &mut Diag<'_, G>,
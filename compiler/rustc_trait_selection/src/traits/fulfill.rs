@@ -348,6 +348,39 @@ fn needs_process_obligation(&self, pending_obligation: &Self::Obligation) -> boo
     fn process_obligation(
         &mut self,
         pending_obligation: &mut PendingPredicateObligation<'tcx>,
+    ) -> ProcessResult<PendingPredicateObligation<'tcx>, FulfillmentErrorCode<'tcx>> {
+        let result = self.process_pending_obligation(pending_obligation);
+        // Obligations which are still stalled get processed again later on, so
+        // we only count them once we've made progress.
+        if !matches!(result, ProcessResult::Unchanged) {
+            super::note_solved_goal(self.selcx.tcx());
+        }
+        result
+    }
+
+    #[inline(never)]
+    fn process_backedge<'c, I>(
+        &mut self,
+        cycle: I,
+        _marker: PhantomData<&'c PendingPredicateObligation<'tcx>>,
+    ) -> Result<(), FulfillmentErrorCode<'tcx>>
+    where
+        I: Clone + Iterator<Item = &'c PendingPredicateObligation<'tcx>>,
+    {
+        if self.selcx.coinductive_match(cycle.clone().map(|s| s.obligation.predicate)) {
+            debug!("process_child_obligations: coinductive match");
+            Ok(())
+        } else {
+            let cycle = cycle.map(|c| c.obligation.clone()).collect();
+            Err(FulfillmentErrorCode::Cycle(cycle))
+        }
+    }
+}
+
+impl<'a, 'tcx> FulfillProcessor<'a, 'tcx> {
+    fn process_pending_obligation(
+        &mut self,
+        pending_obligation: &mut PendingPredicateObligation<'tcx>,
     ) -> ProcessResult<PendingPredicateObligation<'tcx>, FulfillmentErrorCode<'tcx>> {
         pending_obligation.stalled_on.truncate(0);
 
@@ -809,26 +842,6 @@ fn process_obligation(
         }
     }
 
-    #[inline(never)]
-    fn process_backedge<'c, I>(
-        &mut self,
-        cycle: I,
-        _marker: PhantomData<&'c PendingPredicateObligation<'tcx>>,
-    ) -> Result<(), FulfillmentErrorCode<'tcx>>
-    where
-        I: Clone + Iterator<Item = &'c PendingPredicateObligation<'tcx>>,
-    {
-        if self.selcx.coinductive_match(cycle.clone().map(|s| s.obligation.predicate)) {
-            debug!("process_child_obligations: coinductive match");
-            Ok(())
-        } else {
-            let cycle = cycle.map(|c| c.obligation.clone()).collect();
-            Err(FulfillmentErrorCode::Cycle(cycle))
-        }
-    }
-}
-
-impl<'a, 'tcx> FulfillProcessor<'a, 'tcx> {
     #[instrument(level = "debug", skip(self, obligation, stalled_on))]
     fn process_trait_obligation(
         &mut self,
@@ -3,6 +3,7 @@
 //! [rustc dev guide]: https://rustc-dev-guide.rust-lang.org/traits/resolution.html
 
 pub mod auto_trait;
+pub mod blanket_impls;
 pub(crate) mod coherence;
 pub mod const_evaluatable;
 mod dyn_compatibility;
@@ -12,6 +13,7 @@
 pub mod misc;
 pub mod normalize;
 pub mod outlives_bounds;
+pub mod param_env_diff;
 pub mod project;
 pub mod query;
 #[allow(hidden_glob_reexports)]
@@ -25,7 +27,9 @@
 
 use std::fmt::Debug;
 use std::ops::ControlFlow;
+use std::sync::atomic::Ordering;
 
+use rustc_data_structures::fx::FxIndexSet;
 use rustc_errors::ErrorGuaranteed;
 use rustc_hir::def::DefKind;
 pub use rustc_infer::traits::*;
@@ -37,14 +41,14 @@
     self, GenericArgs, GenericArgsRef, Ty, TyCtxt, TypeFoldable, TypeFolder, TypeSuperFoldable,
     TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypingMode, Upcast,
 };
-use rustc_span::Span;
 use rustc_span::def_id::DefId;
+use rustc_span::{Span, Symbol};
 use tracing::{debug, instrument};
 
 pub use self::coherence::{
     InCrate, IsFirstInputType, OrphanCheckErr, OrphanCheckMode, OverlapResult, UncoveredTyParams,
-    add_placeholder_note, orphan_check_trait_ref, overlapping_inherent_impls,
-    overlapping_trait_impls,
+    add_fundamental_type_notes, add_placeholder_note, orphan_check_trait_ref,
+    overlapping_inherent_impls, overlapping_trait_impls,
 };
 pub use self::dyn_compatibility::{
     DynCompatibilityViolation, dyn_compatibility_violations_for_assoc_item,
@@ -66,11 +70,12 @@
 };
 pub use self::structural_normalize::StructurallyNormalizeExt;
 pub use self::util::{
-    BoundVarReplacer, PlaceholderReplacer, elaborate, expand_trait_aliases, impl_item_is_final,
-    sizedness_fast_path, supertrait_def_ids, supertraits, transitive_bounds_that_define_assoc_item,
-    upcast_choices, with_replaced_escaping_bound_vars,
+    BoundVarReplacer, ImplUnusable, PlaceholderReplacer, elaborate, expand_trait_aliases,
+    impl_item_is_final, impl_usable_from, sizedness_fast_path, supertrait_def_ids, supertraits,
+    transitive_bounds_that_define_assoc_item, upcast_choices, with_replaced_escaping_bound_vars,
 };
 use crate::error_reporting::InferCtxtErrorExt;
+use crate::errors::TraitSolvingCancelled;
 use crate::infer::outlives::env::OutlivesEnvironment;
 use crate::infer::{InferCtxt, TyCtxtInferExt};
 use crate::regions::InferCtxtRegionExt;
@@ -84,6 +89,28 @@ pub struct FulfillmentError<'tcx> {
     /// the failure to process `obligation`. This is the obligation
     /// that was initially passed to `register_predicate_obligation`
     pub root_obligation: PredicateObligation<'tcx>,
+    /// Diagnostics only: whether we gave up on searching for a more precise
+    /// `obligation` in the proof tree of `root_obligation`, as doing so
+    /// exceeded the limits for error refinement. Only used by the new solver.
+    pub refinement_exceeded_limits: bool,
+    /// Diagnostics only: if `obligation` is ambiguous as multiple impls apply
+    /// to it, these impls. Only used by the new solver.
+    pub ambiguous_impls: Vec<DefId>,
+    /// Diagnostics only: the reservation impl which would have applied to
+    /// `obligation`, together with its `#[rustc_reservation_impl]` message.
+    /// Only used by the new solver.
+    pub reservation_impl: Option<(DefId, Symbol)>,
+    /// Diagnostics only: the impls which would apply to `obligation` but are
+    /// ignored as they require an unstable feature which isn't enabled, together
+    /// with that feature. Only used by the new solver.
+    pub nightly_only_impls: Vec<(DefId, Symbol)>,
+    /// Diagnostics only: the span of `obligation` inside of the expansion of a
+    /// macro from another crate, if any, in which case `obligation` points at the
+    /// closest span in the current crate instead. Only used by the new solver.
+    pub macro_span: Option<Span>,
+    /// Diagnostics only: whether evaluating `obligation` exceeded the memory limit
+    /// of the solver. Only used by the new solver.
+    pub exceeded_memory_limit: bool,
 }
 
 impl<'tcx> FulfillmentError<'tcx> {
@@ -92,7 +119,17 @@ pub fn new(
         code: FulfillmentErrorCode<'tcx>,
         root_obligation: PredicateObligation<'tcx>,
     ) -> FulfillmentError<'tcx> {
-        FulfillmentError { obligation, code, root_obligation }
+        FulfillmentError {
+            obligation,
+            code,
+            root_obligation,
+            refinement_exceeded_limits: false,
+            ambiguous_impls: vec![],
+            reservation_impl: None,
+            nightly_only_impls: vec![],
+            macro_span: None,
+            exceeded_memory_limit: false,
+        }
     }
 
     pub fn is_true_error(&self) -> bool {
@@ -339,6 +376,13 @@ pub fn normalize_param_env_or_error<'tcx>(
     // parameter environments once for every fn as it goes,
     // and errors will get reported then; so outside of type inference we
     // can be sure that no errors should occur.
+    //
+    // FIXME(-Znext-solver): We eagerly elaborate all bounds here, which is costly for
+    // items with large bound sets and deep supertrait hierarchies. The new solver could
+    // instead elaborate the supertraits of where-clauses when assembling param-env
+    // candidates, caching the elaborated clauses per where-clause. This requires all
+    // other users of `caller_bounds`, e.g. implied bounds and outlives environments,
+    // to elaborate on their own, so it is not yet possible.
     let mut predicates: Vec<_> = util::elaborate(
         tcx,
         unnormalized_env.caller_bounds().into_iter().map(|predicate| {
@@ -471,10 +515,15 @@ fn fold_const(&mut self, c: ty::Const<'tcx>) -> ty::Const<'tcx> {
     };
     debug!("normalize_param_env_or_error: outlives predicates={:?}", outlives_predicates);
 
-    let mut predicates = non_outlives_predicates;
-    predicates.extend(outlives_predicates);
+    // Elaboration already deduplicates the clauses of the env, but normalizing them may
+    // result in new duplicates, e.g. if both `T: Trait<<u8 as Identity>::Assoc>` and
+    // `T: Trait<u8>` are in scope. We remove them so that items whose envs only differ
+    // by such duplicates share the same interned clause list, and so that caches keyed
+    // by the env hit more often.
+    let predicates: FxIndexSet<_> =
+        non_outlives_predicates.into_iter().chain(outlives_predicates).collect();
     debug!("normalize_param_env_or_error: final predicates={:?}", predicates);
-    ty::ParamEnv::new(tcx.mk_clauses(&predicates))
+    ty::ParamEnv::new(tcx.mk_clauses_from_iter(predicates.into_iter()))
 }
 
 /// Deeply normalize the param env using the next solver ignoring
@@ -916,6 +965,23 @@ fn visit_const(&mut self, ct: ty::Const<'tcx>) -> Self::Result {
     !ocx.try_evaluate_obligations().is_empty()
 }
 
+/// Notes that the trait solver has solved a goal. Every
+/// `-Ztrait-solver-progress-interval` goals, this calls the `trait_solver_progress`
+/// hook and aborts compilation if it requests trait solving to be cancelled.
+///
+/// This must only be called once evaluating a goal made progress, not when
+/// processing goals which are still stalled, as these don't do any work.
+pub(crate) fn note_solved_goal(tcx: TyCtxt<'_>) {
+    let interval = tcx.sess.opts.unstable_opts.trait_solver_progress_interval;
+    if interval == 0 {
+        return;
+    }
+    let solved_goals = tcx.sess.solved_trait_goals.fetch_add(1, Ordering::Relaxed) + 1;
+    if solved_goals % interval == 0 && tcx.trait_solver_progress(solved_goals).is_break() {
+        tcx.dcx().emit_fatal(TraitSolvingCancelled { solved_goals });
+    }
+}
+
 pub fn provide(providers: &mut Providers) {
     dyn_compatibility::provide(providers);
     vtable::provide(providers);
@@ -925,6 +991,9 @@ pub fn provide(providers: &mut Providers) {
         specialization_enabled_in: specialize::specialization_enabled_in,
         instantiate_and_check_impossible_predicates,
         is_impossible_associated_item,
+        check_conflicting_projection_bounds:
+            crate::error_reporting::traits::ambiguity::check_conflicting_projection_bounds,
+        traits_by_def_path: crate::error_reporting::traits::on_unimplemented::traits_by_def_path,
         ..*providers
     };
 }
@@ -1,15 +1,18 @@
+use rustc_data_structures::fx::FxIndexSet;
 use rustc_infer::infer::InferOk;
 use rustc_infer::infer::resolve::OpportunisticRegionResolver;
 use rustc_infer::traits::query::type_op::ImpliedOutlivesBounds;
 use rustc_macros::extension;
 use rustc_middle::infer::canonical::{OriginalQueryValues, QueryRegionConstraints};
 pub use rustc_middle::traits::query::OutlivesBound;
-use rustc_middle::ty::{self, ParamEnv, Ty, TypeFolder, TypeVisitableExt};
+use rustc_middle::ty::{self, ParamEnv, Ty, TyCtxt, TypeFolder, TypeVisitableExt};
+use rustc_span::Span;
 use rustc_span::def_id::LocalDefId;
 use tracing::instrument;
 
-use crate::infer::InferCtxt;
-use crate::traits::ObligationCause;
+use crate::infer::{InferCtxt, TyCtxtInferExt};
+use crate::traits::query::type_op::implied_outlives_bounds::compute_implied_outlives_bounds_inner;
+use crate::traits::{ObligationCause, ObligationCtxt};
 
 /// Implied bounds are region relationships that we deduce
 /// automatically. The idea is that (e.g.) a caller must check that a
@@ -107,3 +110,42 @@ fn implied_bounds_tys<Tys: IntoIterator<Item = Ty<'tcx>>>(
         })
     }
 }
+
+/// Computes the outlives requirements for `ty` to be well-formed using the new trait
+/// solver, in terms of the generic parameters in scope. For example, `&'a Vec<T>` is
+/// only well-formed if `T: 'a` holds.
+///
+/// Requirements on regions which can't be named by the item `ty` is used in, i.e.
+/// `'static` and regions bound inside of `ty`, are dropped.
+///
+/// This is used by the outlives inference of ADTs and must not be called for types whose
+/// well-formedness depends on outlives predicates which are still being inferred, as
+/// looking those up would cycle.
+pub fn wf_outlives_requirements<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    ty: Ty<'tcx>,
+    span: Span,
+) -> Vec<ty::ArgOutlivesPredicate<'tcx>> {
+    let infcx =
+        tcx.infer_ctxt().with_next_trait_solver(true).build(ty::TypingMode::non_body_analysis());
+    let ocx = ObligationCtxt::new(&infcx);
+    let Ok(bounds) = compute_implied_outlives_bounds_inner(&ocx, param_env, ty, span, true) else {
+        return vec![];
+    };
+
+    let is_early_param = |r: ty::Region<'tcx>| matches!(r.kind(), ty::ReEarlyParam(_));
+    let mut requirements = FxIndexSet::default();
+    for bound in bounds {
+        let (arg, region): (ty::GenericArg<'tcx>, _) = match bound {
+            OutlivesBound::RegionSubRegion(sub, sup) if is_early_param(sup) => (sup.into(), sub),
+            OutlivesBound::RegionSubRegion(..) => continue,
+            OutlivesBound::RegionSubParam(sub, param) => (param.to_ty(tcx).into(), sub),
+            OutlivesBound::RegionSubAlias(sub, alias) => (alias.to_ty(tcx).into(), sub),
+        };
+        if is_early_param(region) {
+            requirements.insert(ty::OutlivesPredicate(arg, region));
+        }
+    }
+    requirements.into_iter().collect()
+}
@@ -0,0 +1,82 @@
+//! Compares the environments of different items, e.g. to explain why a goal
+//! holds in one item but not in another.
+
+use rustc_hir::def_id::DefId;
+use rustc_infer::infer::InferCtxt;
+use rustc_middle::ty::{self, GenericArgs, TyCtxt};
+
+use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
+use crate::traits::{Obligation, ObligationCause};
+
+/// Returns the item whose environment `def_id` is expected to be compatible
+/// with, together with that environment in terms of the generic parameters
+/// of `def_id`.
+///
+/// For now, this only handles items of trait impls, where this is the trait
+/// item they implement, instantiated with the generic args of the impl.
+pub fn sibling_param_env<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+) -> Option<(DefId, ty::ParamEnv<'tcx>)> {
+    let impl_item = tcx.opt_associated_item(def_id)?;
+    let trait_item_def_id = impl_item.trait_item_def_id()?;
+    let impl_def_id = impl_item.container_id(tcx);
+    let impl_trait_ref = tcx.impl_trait_ref(impl_def_id).instantiate_identity();
+    let trait_to_impl_args = GenericArgs::identity_for_item(tcx, def_id).rebase_onto(
+        tcx,
+        impl_def_id,
+        impl_trait_ref.args,
+    );
+    let clauses = tcx
+        .predicates_of(impl_def_id)
+        .instantiate_identity(tcx)
+        .into_iter()
+        .chain(tcx.predicates_of(trait_item_def_id).instantiate_own(tcx, trait_to_impl_args))
+        .map(|(clause, _)| clause);
+    Some((trait_item_def_id, ty::ParamEnv::new(tcx.mk_clauses_from_iter(clauses))))
+}
+
+/// Returns the where-clauses of `other_env` which are missing from `param_env` and
+/// which are necessary for `predicate` to hold, or `None` if `predicate` does not
+/// hold in `other_env` either.
+///
+/// If `predicate` only holds by using a combination of multiple where-clauses, each
+/// of which isn't necessary on its own, all missing where-clauses are returned.
+pub fn missing_where_clauses<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    predicate: ty::Predicate<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    other_env: ty::ParamEnv<'tcx>,
+) -> Option<Vec<ty::Clause<'tcx>>> {
+    let tcx = infcx.tcx;
+    let holds = |clauses: &[ty::Clause<'tcx>]| {
+        let env = ty::ParamEnv::new(tcx.mk_clauses(clauses));
+        infcx.predicate_must_hold_modulo_regions(&Obligation::new(
+            tcx,
+            ObligationCause::dummy(),
+            env,
+            predicate,
+        ))
+    };
+
+    let mut clauses = param_env.caller_bounds().to_vec();
+    let missing: Vec<_> = other_env
+        .caller_bounds()
+        .iter()
+        .filter(|clause| !param_env.caller_bounds().contains(clause))
+        .collect();
+    clauses.extend(&missing);
+    if missing.is_empty() || !holds(&clauses) {
+        return None;
+    }
+
+    let necessary: Vec<_> = missing
+        .iter()
+        .copied()
+        .filter(|&clause| {
+            let without_clause: Vec<_> = clauses.iter().copied().filter(|&c| c != clause).collect();
+            !holds(&without_clause)
+        })
+        .collect();
+    Some(if necessary.is_empty() { missing } else { necessary })
+}
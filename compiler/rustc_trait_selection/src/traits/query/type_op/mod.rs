@@ -119,6 +119,8 @@ fn fully_perform_into(
         let canonical_self = infcx.canonicalize_query(query_key, &mut canonical_var_values);
         let canonical_result = Self::perform_query(infcx.tcx, canonical_self)?;
 
+        // Instantiating the response of a successful query should never fail. If it
+        // does, this is a bug in the query, so report everything needed to debug it.
         let InferOk { value, obligations } = infcx
             .instantiate_nll_query_response_and_region_obligations(
                 &ObligationCause::dummy_with_span(span),
@@ -126,7 +128,18 @@ fn fully_perform_into(
                 &canonical_var_values,
                 canonical_result,
                 output_query_region_constraints,
-            )?;
+            )
+            .map_err(|err| {
+                infcx.dcx().span_delayed_bug(
+                    span,
+                    format!(
+                        "failed to instantiate query response: {err:?}\n\
+                         goal: {canonical_self:#?}\n\
+                         response: {canonical_result:#?}"
+                    ),
+                );
+                NoSolution
+            })?;
 
         Ok((value, Some(canonical_self), obligations, canonical_result.value.certainty))
     }
@@ -16,6 +16,7 @@
 use rustc_middle::ty::fast_reject::DeepRejectCtxt;
 use rustc_middle::ty::{self, SizedTraitKind, Ty, TypeVisitableExt, TypingMode, elaborate};
 use rustc_middle::{bug, span_bug};
+use rustc_span::sym;
 use tracing::{debug, instrument, trace};
 
 use super::SelectionCandidate::*;
@@ -47,10 +48,11 @@ pub(super) fn assemble_candidates<'o>(
             // Take the fast path out - this also improves
             // performance by preventing assemble_candidates_from_impls from
             // matching every impl for this trait.
-            return Ok(SelectionCandidateSet { vec: vec![], ambiguous: true });
+            return Ok(SelectionCandidateSet { vec: vec![], ambiguous: true, feature_gated: None });
         }
 
-        let mut candidates = SelectionCandidateSet { vec: Vec::new(), ambiguous: false };
+        let mut candidates =
+            SelectionCandidateSet { vec: Vec::new(), ambiguous: false, feature_gated: None };
 
         // Negative trait predicates have different rules than positive trait predicates.
         if obligation.polarity() == ty::PredicatePolarity::Negative {
@@ -1176,6 +1178,8 @@ fn assemble_builtin_copy_clone_candidate(
                             } else {
                                 candidates.vec.push(BuiltinCandidate);
                             }
+                        } else {
+                            candidates.feature_gated = Some(sym::coroutine_clone);
                         }
                     }
                 }
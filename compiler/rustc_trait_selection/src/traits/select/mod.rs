@@ -183,6 +183,10 @@ struct SelectionCandidateSet<'tcx> {
     /// of the input types are type variables, in which case there are
     /// various "builtin" rules that might or might not trigger.
     ambiguous: bool,
+
+    /// The unstable feature required by a builtin candidate which would have
+    /// applied if that feature were enabled. Only used for diagnostics.
+    feature_gated: Option<Symbol>,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -287,7 +291,7 @@ pub fn select(
         obligation: &TraitObligation<'tcx>,
     ) -> SelectionResult<'tcx, Selection<'tcx>> {
         if self.infcx.next_trait_solver() {
-            return self.infcx.select_in_new_trait_solver(obligation);
+            return self.infcx.select_in_new_solver(obligation);
         }
 
         self.poly_select(&Obligation {
@@ -403,6 +407,7 @@ fn candidate_from_obligation_no_cache<'o>(
         }
 
         let candidates = candidate_set.vec;
+        let feature_gated = candidate_set.feature_gated;
 
         debug!(?stack, ?candidates, "assembled {} candidates", candidates.len());
 
@@ -472,6 +477,8 @@ fn candidate_from_obligation_no_cache<'o>(
             if stack.obligation.predicate.references_error() {
                 debug!(?stack.obligation.predicate, "found error type in predicate, treating as ambiguous");
                 Ok(None)
+            } else if let Some(feature) = feature_gated {
+                Err(SelectionError::FeatureGated(feature))
             } else {
                 Err(SelectionError::Unimplemented)
             }
@@ -509,6 +516,7 @@ pub fn evaluate_root_obligation(
         obligation: &PredicateObligation<'tcx>,
     ) -> Result<EvaluationResult, OverflowError> {
         debug_assert!(!self.infcx.next_trait_solver());
+        super::note_solved_goal(self.tcx());
         self.evaluation_probe(|this| {
             let goal =
                 this.infcx.resolve_vars_if_possible((obligation.predicate, obligation.param_env));
@@ -486,6 +486,7 @@ fn report_negative_positive_conflict<'tcx>(
     for cause in &overlap.intercrate_ambiguity_causes {
         cause.add_intercrate_ambiguity_hint(&mut diag);
     }
+    coherence::add_fundamental_type_notes(tcx, &mut diag, &overlap.intercrate_ambiguity_causes);
 
     diag.emit()
 }
@@ -533,6 +534,7 @@ fn decorate<'tcx, G: EmissionGuarantee>(
         for cause in &overlap.intercrate_ambiguity_causes {
             cause.add_intercrate_ambiguity_hint(err);
         }
+        coherence::add_fundamental_type_notes(tcx, err, &overlap.intercrate_ambiguity_causes);
 
         if overlap.involves_placeholder {
             coherence::add_placeholder_note(err);
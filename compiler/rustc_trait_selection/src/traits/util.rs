@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::iter;
 
 use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
 use rustc_hir::LangItem;
@@ -13,7 +14,7 @@
     TyCtxt, TypeFoldable, TypeFolder, TypeSuperFoldable, TypeVisitableExt,
 };
 pub use rustc_next_trait_solver::placeholder::BoundVarReplacer;
-use rustc_span::Span;
+use rustc_span::{Span, Symbol};
 use smallvec::{SmallVec, smallvec};
 use tracing::debug;
 
@@ -443,3 +444,44 @@ pub(crate) fn lazily_elaborate_sizedness_candidate<'tcx>(
         polarity: c.polarity,
     })
 }
+
+/// Why an impl can't be used from some module, see [`impl_usable_from`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImplUnusable {
+    /// The trait or the self type of the impl can't be named from the module.
+    /// Stores the inaccessible item.
+    Private(DefId),
+    /// The impl is unstable and its feature isn't enabled.
+    Unstable(Symbol),
+}
+
+/// Returns whether the impl `impl_def_id` could be relied on by code in `module`:
+/// both its trait and, if it's an ADT, its self type have to be accessible from
+/// there, and the impl must either be stable or have its feature enabled.
+///
+/// Trait solving itself never checks this, as impls apply everywhere. This is
+/// only used by diagnostics to avoid pointing to impls the user can't use.
+pub fn impl_usable_from(
+    tcx: TyCtxt<'_>,
+    impl_def_id: DefId,
+    module: DefId,
+) -> Result<(), ImplUnusable> {
+    if let Some(stability) = tcx.lookup_stability(impl_def_id)
+        && stability.is_unstable()
+        && !tcx.features().enabled(stability.feature)
+    {
+        return Err(ImplUnusable::Unstable(stability.feature));
+    }
+
+    let trait_ref = tcx.impl_trait_ref(impl_def_id).skip_binder();
+    let self_ty_def_id = match trait_ref.self_ty().peel_refs().kind() {
+        ty::Adt(def, _) => Some(def.did()),
+        _ => None,
+    };
+    for def_id in iter::once(trait_ref.def_id).chain(self_ty_def_id) {
+        if !tcx.visibility(def_id).is_accessible_from(module, tcx) {
+            return Err(ImplUnusable::Private(def_id));
+        }
+    }
+    Ok(())
+}
@@ -6,8 +6,11 @@
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::bug;
 use rustc_middle::traits::CodegenObligationError;
-use rustc_middle::ty::{self, PseudoCanonicalInput, TyCtxt, TypeVisitableExt};
+use rustc_middle::ty::{self, GenericArgsRef, PseudoCanonicalInput, TyCtxt, TypeVisitableExt};
+use rustc_span::def_id::DefId;
+use rustc_span::{ErrorGuaranteed, Span};
 use rustc_trait_selection::error_reporting::InferCtxtErrorExt;
+use rustc_trait_selection::solve::InferCtxtSelectExt;
 use rustc_trait_selection::traits::{
     ImplSource, Obligation, ObligationCause, ObligationCtxt, ScrubbedTraitError, SelectionContext,
     SelectionError,
@@ -32,12 +35,18 @@ pub(crate) fn codegen_select_candidate<'tcx>(
     // Do the initial selection for the obligation. This yields the
     // shallow result we are looking for -- that is, what specific impl.
     let (infcx, param_env) = tcx.infer_ctxt().ignoring_regions().build_with_typing_env(typing_env);
-    let mut selcx = SelectionContext::new(&infcx);
 
     let obligation_cause = ObligationCause::dummy();
     let obligation = Obligation::new(tcx, obligation_cause, param_env, trait_ref);
 
-    let selection = match selcx.select(&obligation) {
+    // With the new solver, go through the same selection used everywhere else
+    // instead of the old solver's `SelectionContext`.
+    let selection = if infcx.next_trait_solver() {
+        infcx.select_in_new_solver(&obligation)
+    } else {
+        SelectionContext::new(&infcx).select(&obligation)
+    };
+    let selection = match selection {
         Ok(Some(selection)) => selection,
         Ok(None) => return Err(CodegenObligationError::Ambiguity),
         Err(SelectionError::Unimplemented) => return Err(CodegenObligationError::Unimplemented),
@@ -91,3 +100,29 @@ pub(crate) fn codegen_select_candidate<'tcx>(
 
     Ok(&*tcx.arena.alloc(impl_source))
 }
+
+/// Reports the error which caused the selection of the trait of the associated item
+/// `def_id` to fail, as `codegen_select_candidate` does not report any errors itself.
+///
+/// This is used by `Instance::expect_resolve` for trait bounds which only fail after
+/// monomorphization. We go through the usual fulfillment error reporting, so with the
+/// new solver we report the leaf obligation which actually failed.
+pub(crate) fn report_unresolved_assoc_item<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    typing_env: ty::TypingEnv<'tcx>,
+    def_id: DefId,
+    args: GenericArgsRef<'tcx>,
+    span: Span,
+) -> Option<ErrorGuaranteed> {
+    let trait_ref = ty::TraitRef::from_assoc(tcx, tcx.parent(def_id), args);
+    let (infcx, param_env) = tcx.infer_ctxt().ignoring_regions().build_with_typing_env(typing_env);
+    let ocx = ObligationCtxt::new_with_diagnostics(&infcx);
+    ocx.register_obligation(Obligation::new(
+        tcx,
+        ObligationCause::dummy_with_span(span),
+        param_env,
+        trait_ref,
+    ));
+    let errors = ocx.evaluate_obligations_error_on_ambiguity();
+    if errors.is_empty() { None } else { Some(infcx.err_ctxt().report_fulfillment_errors(errors)) }
+}
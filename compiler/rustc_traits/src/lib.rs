@@ -13,7 +13,9 @@
 mod normalize_projection_ty;
 mod type_op;
 
-use rustc_middle::query::Providers;
+use std::ops::ControlFlow;
+
+use rustc_middle::util::Providers;
 pub use rustc_trait_selection::traits::query::type_op::ascribe_user_type::type_op_ascribe_user_type_with_span;
 pub use type_op::type_op_prove_predicate_with_cause;
 
@@ -25,5 +27,7 @@ pub fn provide(p: &mut Providers) {
     normalize_erasing_regions::provide(p);
     type_op::provide(p);
     p.codegen_select_candidate = codegen::codegen_select_candidate;
+    p.hooks.report_unresolved_assoc_item = codegen::report_unresolved_assoc_item;
+    p.hooks.trait_solver_progress = |_, _| ControlFlow::Continue(());
     p.coroutine_hidden_types = coroutine_witnesses::coroutine_hidden_types;
 }
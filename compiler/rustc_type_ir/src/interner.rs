@@ -245,6 +245,14 @@ fn mk_type_list_from_iter<I, T>(self, args: I) -> T::Output
 
     fn recursion_limit(self) -> usize;
 
+    /// The approximate amount of memory in bytes the trait solver may use while
+    /// evaluating a single root goal.
+    fn solver_memory_limit(self) -> usize;
+
+    /// Whether the trait solver should check its internal invariants while solving,
+    /// reporting violations as delayed bugs. See `-Zsolver-validation`.
+    fn solver_validation(self) -> bool;
+
     type Features: Features<Self>;
     fn features(self) -> Self::Features;
 
@@ -33,6 +33,13 @@ pub(super) struct CacheData<'a, X: Cx> {
     pub(super) encountered_overflow: bool,
     pub(super) nested_goals: &'a NestedGoals<X>,
 }
+/// A result stored in the global cache, see [`GlobalCache::entries`].
+pub struct GlobalCacheEntry<'a, X: Cx> {
+    pub result: &'a X::Tracked<X::Result>,
+    pub required_depth: usize,
+    pub encountered_overflow: bool,
+}
+
 #[derive_where(Default; X: Cx)]
 pub struct GlobalCache<X: Cx> {
     map: HashMap<X::Input, CacheEntry<X>>,
@@ -107,4 +114,21 @@ pub(super) fn get<'a>(
 
         None
     }
+
+    /// Returns all results stored in the cache, in no particular order.
+    ///
+    /// This is only intended to be used for debugging, e.g. by `-Zdump-solver-cache`.
+    pub fn entries(&self) -> impl Iterator<Item = (X::Input, GlobalCacheEntry<'_, X>)> {
+        self.map.iter().flat_map(|(&input, entry)| {
+            let success = entry.success.iter().map(move |success| {
+                let Success { required_depth, nested_goals: _, ref result } = *success;
+                (input, GlobalCacheEntry { result, required_depth, encountered_overflow: false })
+            });
+            let with_overflow = entry.with_overflow.iter().map(move |(&required_depth, entry)| {
+                let result = &entry.result;
+                (input, GlobalCacheEntry { result, required_depth, encountered_overflow: true })
+            });
+            success.chain(with_overflow)
+        })
+    }
 }
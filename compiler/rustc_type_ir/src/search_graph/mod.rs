@@ -29,7 +29,7 @@
 use stack::{Stack, StackDepth, StackEntry};
 mod global_cache;
 use global_cache::CacheData;
-pub use global_cache::GlobalCache;
+pub use global_cache::{GlobalCache, GlobalCacheEntry};
 
 /// The search graph does not simply use `Interner` directly
 /// to enable its fuzzing without having to stub the rest of
@@ -74,6 +74,14 @@ fn enter_validation_scope(
         cx: Self::Cx,
         input: <Self::Cx as Cx>::Input,
     ) -> Option<Self::ValidationScope>;
+    /// Called if reevaluating `input` while validating the global cache
+    /// results in a different result than the one stored in the cache.
+    fn on_cache_mismatch(
+        cx: Self::Cx,
+        input: <Self::Cx as Cx>::Input,
+        cached: <Self::Cx as Cx>::Result,
+        computed: <Self::Cx as Cx>::Result,
+    );
 
     const FIXPOINT_STEP_LIMIT: usize;
 
@@ -581,6 +589,16 @@ pub struct SearchGraph<D: Delegate<Cx = X>, X: Cx = <D as Delegate>::Cx> {
     /// is only valid until the result of one of its cycle heads changes.
     provisional_cache: HashMap<X::Input, Vec<ProvisionalCacheEntry<X>>>,
 
+    /// The approximate amount of memory in bytes we may use while evaluating the
+    /// root goal, see [`SearchGraph::exceeded_memory_limit`].
+    memory_limit: usize,
+    /// The number of entries in the `provisional_cache`.
+    num_provisional_cache_entries: usize,
+    /// The number of global cache entries added while evaluating the root goal.
+    /// Unlike stack and provisional cache entries, these are never freed.
+    num_global_cache_entries: usize,
+    exceeded_memory_limit: bool,
+
     _marker: PhantomData<D>,
 }
 
@@ -600,11 +618,15 @@ enum UpdateParentGoalCtxt<'a, X: Cx> {
 }
 
 impl<D: Delegate<Cx = X>, X: Cx> SearchGraph<D> {
-    pub fn new(root_depth: usize) -> SearchGraph<D> {
+    pub fn new(root_depth: usize, memory_limit: usize) -> SearchGraph<D> {
         Self {
             root_depth: AvailableDepth(root_depth),
             stack: Default::default(),
             provisional_cache: Default::default(),
+            memory_limit,
+            num_provisional_cache_entries: 0,
+            num_global_cache_entries: 0,
+            exceeded_memory_limit: false,
             _marker: PhantomData,
         }
     }
@@ -679,6 +701,35 @@ pub fn is_empty(&self) -> bool {
         }
     }
 
+    /// Whether evaluating the root goal exceeded the memory limit. Once we've
+    /// exceeded it, all goals which are not already cached result in overflow.
+    ///
+    /// We approximate the memory used by the solver via the size of the entries
+    /// currently on the stack and in the provisional cache, and the global cache
+    /// entries added while evaluating the root goal. This is only meant to fail
+    /// gracefully instead of running out of memory.
+    pub fn exceeded_memory_limit(&self) -> bool {
+        self.exceeded_memory_limit
+    }
+
+    fn approx_memory_usage(&self) -> usize {
+        self.stack.len() * size_of::<StackEntry<X>>()
+            + self.num_provisional_cache_entries * size_of::<ProvisionalCacheEntry<X>>()
+            + self.num_global_cache_entries * size_of::<(X::Input, X::Result)>()
+    }
+
+    /// Returns `false` if pushing another goal on the stack exceeds the memory limit.
+    fn check_memory_limit(&mut self) -> bool {
+        if !self.exceeded_memory_limit {
+            let approx_memory_usage = self.approx_memory_usage() + size_of::<StackEntry<X>>();
+            if approx_memory_usage > self.memory_limit {
+                debug!(approx_memory_usage, "exceeded memory limit");
+                self.exceeded_memory_limit = true;
+            }
+        }
+        !self.exceeded_memory_limit
+    }
+
     /// The number of goals currently in the search graph. This should only be
     /// used for debugging purposes.
     pub fn debug_current_depth(&self) -> usize {
@@ -724,10 +775,11 @@ pub fn ignore_candidate_head_usages(&mut self, usages: CandidateHeadUsages) {
     pub fn evaluate_root_goal_for_proof_tree(
         cx: X,
         root_depth: usize,
+        memory_limit: usize,
         input: X::Input,
         inspect: &mut D::ProofTreeBuilder,
     ) -> X::Result {
-        let mut this = SearchGraph::<D>::new(root_depth);
+        let mut this = SearchGraph::<D>::new(root_depth, memory_limit);
         let available_depth = AvailableDepth(root_depth);
         let step_kind_from_parent = PathKind::Inductive; // is never used
         this.stack.push(StackEntry {
@@ -806,6 +858,9 @@ pub fn evaluate_goal(
         }
 
         // Unfortunate, it looks like we actually have to compute this goal.
+        if !self.check_memory_limit() {
+            return self.handle_overflow(cx, input);
+        }
         self.stack.push(StackEntry {
             input,
             step_kind_from_parent,
@@ -846,12 +901,15 @@ pub fn evaluate_goal(
             if let Some((_scope, expected)) = validate_cache {
                 // Do not try to move a goal into the cache again if we're testing
                 // the global cache.
-                assert_eq!(expected, evaluation_result.result, "input={input:?}");
+                if expected != evaluation_result.result {
+                    D::on_cache_mismatch(cx, input, expected, evaluation_result.result);
+                }
             } else if D::inspect_is_noop(inspect) {
                 self.insert_global_cache(cx, input, evaluation_result, dep_node)
             }
         } else if D::ENABLE_PROVISIONAL_CACHE {
             debug_assert!(validate_cache.is_none(), "unexpected non-root: {input:?}");
+            self.num_provisional_cache_entries += 1;
             let entry = self.provisional_cache.entry(input).or_default();
             let EvaluationResult {
                 encountered_overflow,
@@ -876,6 +934,11 @@ pub fn evaluate_goal(
         result
     }
 
+    #[allow(rustc::potential_query_instability)]
+    fn update_num_provisional_cache_entries(&mut self) {
+        self.num_provisional_cache_entries = self.provisional_cache.values().map(Vec::len).sum();
+    }
+
     fn handle_overflow(&mut self, cx: X, input: X::Input) -> X::Result {
         if let Some(last) = self.stack.last_mut() {
             last.encountered_overflow = true;
@@ -909,6 +972,7 @@ fn clear_dependent_provisional_results_for_rerun(&mut self) {
             });
             !entries.is_empty()
         });
+        self.update_num_provisional_cache_entries();
     }
 }
 
@@ -1066,6 +1130,7 @@ fn rebase_provisional_cache_entries(
             });
             !entries.is_empty()
         });
+        self.update_num_provisional_cache_entries();
     }
 
     fn lookup_provisional_cache(
@@ -1432,6 +1497,7 @@ fn insert_global_cache(
         dep_node: X::DepNodeIndex,
     ) {
         debug!(?evaluation_result, "insert global cache");
+        self.num_global_cache_entries += 1;
         cx.with_global_cache(|cache| cache.insert(cx, input, evaluation_result, dep_node))
     }
 }
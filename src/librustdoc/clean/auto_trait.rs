@@ -24,7 +24,7 @@ pub(crate) fn synthesize_auto_trait_impls<'tcx>(
     let typing_env = ty::TypingEnv::non_body_analysis(tcx, item_def_id);
     let ty = tcx.type_of(item_def_id).instantiate_identity();
 
-    let finder = auto_trait::AutoTraitFinder::new(tcx);
+    let finder = cx.auto_trait_finder.clone();
     let mut auto_trait_impls: Vec<_> = cx
         .auto_traits
         .clone()
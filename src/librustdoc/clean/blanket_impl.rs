@@ -2,9 +2,10 @@
 use rustc_hir as hir;
 use rustc_infer::infer::{DefineOpaqueTypes, InferOk, TyCtxtInferExt};
 use rustc_infer::traits;
-use rustc_middle::ty::{self, TypingMode, Upcast};
+use rustc_middle::ty::{self, TyCtxt, TypingMode, Upcast};
 use rustc_span::DUMMY_SP;
 use rustc_span::def_id::DefId;
+use rustc_trait_selection::traits::blanket_impls::applicable_blanket_impls;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt;
 use tracing::{debug, instrument, trace};
 
@@ -29,55 +30,20 @@ pub(crate) fn synthesize_blanket_impls(
         {
             continue;
         }
-        // NOTE: doesn't use `for_each_relevant_impl` to avoid looking at anything besides blanket impls
-        let trait_impls = tcx.trait_impls_of(trait_def_id);
-        'blanket_impls: for &impl_def_id in trait_impls.blanket_impls() {
-            trace!("considering impl `{impl_def_id:?}` for trait `{trait_def_id:?}`");
-
+        let applicable_impls = if tcx.next_trait_solver_globally() {
+            applicable_blanket_impls(tcx, item_def_id, trait_def_id)
+        } else {
+            // NOTE: doesn't use `for_each_relevant_impl` to avoid looking at anything besides blanket impls
+            let trait_impls = tcx.trait_impls_of(trait_def_id);
+            trait_impls
+                .blanket_impls()
+                .iter()
+                .copied()
+                .filter(|&impl_def_id| blanket_impl_may_apply(tcx, item_def_id, impl_def_id))
+                .collect()
+        };
+        for impl_def_id in applicable_impls {
             let trait_ref = tcx.impl_trait_ref(impl_def_id);
-            if !matches!(trait_ref.skip_binder().self_ty().kind(), ty::Param(_)) {
-                continue;
-            }
-            let infcx = tcx.infer_ctxt().build(TypingMode::non_body_analysis());
-            let args = infcx.fresh_args_for_item(DUMMY_SP, item_def_id);
-            let impl_ty = ty.instantiate(tcx, args);
-            let param_env = ty::ParamEnv::empty();
-
-            let impl_args = infcx.fresh_args_for_item(DUMMY_SP, impl_def_id);
-            let impl_trait_ref = trait_ref.instantiate(tcx, impl_args);
-
-            // Require the type the impl is implemented on to match
-            // our type, and ignore the impl if there was a mismatch.
-            let Ok(eq_result) = infcx.at(&traits::ObligationCause::dummy(), param_env).eq(
-                DefineOpaqueTypes::Yes,
-                impl_trait_ref.self_ty(),
-                impl_ty,
-            ) else {
-                continue;
-            };
-            let InferOk { value: (), obligations } = eq_result;
-            // FIXME(eddyb) ignoring `obligations` might cause false positives.
-            drop(obligations);
-
-            let predicates = tcx
-                .predicates_of(impl_def_id)
-                .instantiate(tcx, impl_args)
-                .predicates
-                .into_iter()
-                .chain(Some(impl_trait_ref.upcast(tcx)));
-            for predicate in predicates {
-                let obligation = traits::Obligation::new(
-                    tcx,
-                    traits::ObligationCause::dummy(),
-                    param_env,
-                    predicate,
-                );
-                match infcx.evaluate_obligation(&obligation) {
-                    Ok(eval_result) if eval_result.may_apply() => {}
-                    Err(traits::OverflowError::Canonical) => {}
-                    _ => continue 'blanket_impls,
-                }
-            }
             debug!("found applicable impl for trait ref {trait_ref:?}");
 
             cx.generated_synthetics.insert((ty.skip_binder(), trait_def_id));
@@ -127,3 +93,49 @@ pub(crate) fn synthesize_blanket_impls(
 
     blanket_impls
 }
+
+fn blanket_impl_may_apply(tcx: TyCtxt<'_>, item_def_id: DefId, impl_def_id: DefId) -> bool {
+    trace!("considering impl `{impl_def_id:?}`");
+
+    let trait_ref = tcx.impl_trait_ref(impl_def_id);
+    if !matches!(trait_ref.skip_binder().self_ty().kind(), ty::Param(_)) {
+        return false;
+    }
+    let infcx = tcx.infer_ctxt().build(TypingMode::non_body_analysis());
+    let args = infcx.fresh_args_for_item(DUMMY_SP, item_def_id);
+    let impl_ty = tcx.type_of(item_def_id).instantiate(tcx, args);
+    let param_env = ty::ParamEnv::empty();
+
+    let impl_args = infcx.fresh_args_for_item(DUMMY_SP, impl_def_id);
+    let impl_trait_ref = trait_ref.instantiate(tcx, impl_args);
+
+    // Require the type the impl is implemented on to match
+    // our type, and ignore the impl if there was a mismatch.
+    let Ok(eq_result) = infcx.at(&traits::ObligationCause::dummy(), param_env).eq(
+        DefineOpaqueTypes::Yes,
+        impl_trait_ref.self_ty(),
+        impl_ty,
+    ) else {
+        return false;
+    };
+    let InferOk { value: (), obligations } = eq_result;
+    // FIXME(eddyb) ignoring `obligations` might cause false positives.
+    drop(obligations);
+
+    let predicates = tcx
+        .predicates_of(impl_def_id)
+        .instantiate(tcx, impl_args)
+        .predicates
+        .into_iter()
+        .chain(Some(impl_trait_ref.upcast(tcx)));
+    for predicate in predicates {
+        let obligation =
+            traits::Obligation::new(tcx, traits::ObligationCause::dummy(), param_env, predicate);
+        match infcx.evaluate_obligation(&obligation) {
+            Ok(eval_result) if eval_result.may_apply() => {}
+            Err(traits::OverflowError::Canonical) => {}
+            _ => return false,
+        }
+    }
+    true
+}
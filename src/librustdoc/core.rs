@@ -1,3 +1,4 @@
+use std::rc::Rc;
 use std::sync::{Arc, LazyLock};
 use std::{io, mem};
 
@@ -24,6 +25,7 @@
 use rustc_session::{Session, lint};
 use rustc_span::source_map;
 use rustc_span::symbol::sym;
+use rustc_trait_selection::traits::auto_trait::AutoTraitFinder;
 use tracing::{debug, info};
 
 use crate::clean::inline::build_trait;
@@ -60,6 +62,9 @@ pub(crate) struct DocContext<'tcx> {
     // FIXME(eddyb) make this a `ty::TraitRef<'tcx>` set.
     pub(crate) generated_synthetics: FxHashSet<(Ty<'tcx>, DefId)>,
     pub(crate) auto_traits: Vec<DefId>,
+    /// Shared between all items so that the bounds of auto trait impls it caches
+    /// are only computed once.
+    pub(crate) auto_trait_finder: Rc<AutoTraitFinder<'tcx>>,
     /// This same cache is used throughout rustdoc, including in [`crate::html::render`].
     pub(crate) cache: Cache,
     /// Used by [`clean::inline`] to tell if an item has already been inlined.
@@ -373,6 +378,7 @@ pub(crate) fn run_global_ctxt(
         impl_trait_bounds: Default::default(),
         generated_synthetics: Default::default(),
         auto_traits,
+        auto_trait_finder: Rc::new(AutoTraitFinder::new(tcx)),
         cache: Cache::new(render_options.document_private, render_options.document_hidden),
         inlined: FxHashSet::default(),
         output_format,
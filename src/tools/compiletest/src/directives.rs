@@ -184,6 +184,9 @@ pub(crate) struct TestProps {
     // If true, `rustfix` will only apply `MachineApplicable` suggestions.
     pub rustfix_only_machine_applicable: bool,
     pub assembly_output: Option<String>,
+    /// Items whose proof trees are printed to stdout via `-Zdump-proof-tree`,
+    /// so that they get compared against the `.stdout` snapshot of UI tests.
+    pub dump_proof_tree: Vec<String>,
     // If true, the test is expected to ICE
     pub should_ice: bool,
     // If true, the stderr is expected to be different across bit-widths.
@@ -248,6 +251,7 @@ mod directives {
     pub const RUN_RUSTFIX: &'static str = "run-rustfix";
     pub const RUSTFIX_ONLY_MACHINE_APPLICABLE: &'static str = "rustfix-only-machine-applicable";
     pub const ASSEMBLY_OUTPUT: &'static str = "assembly-output";
+    pub const DUMP_PROOF_TREE: &'static str = "dump-proof-tree";
     pub const STDERR_PER_BITWIDTH: &'static str = "stderr-per-bitwidth";
     pub const INCREMENTAL: &'static str = "incremental";
     pub const KNOWN_BUG: &'static str = "known-bug";
@@ -306,6 +310,7 @@ pub fn new() -> Self {
             run_rustfix: false,
             rustfix_only_machine_applicable: false,
             assembly_output: None,
+            dump_proof_tree: vec![],
             should_ice: false,
             stderr_per_bitwidth: false,
             mir_unit_test: None,
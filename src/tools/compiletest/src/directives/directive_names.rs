@@ -33,6 +33,7 @@
     "dont-check-compiler-stdout",
     "dont-check-failure-status",
     "dont-require-annotations",
+    "dump-proof-tree",
     "edition",
     "error-pattern",
     "exact-llvm-major-version",
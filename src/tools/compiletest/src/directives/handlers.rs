@@ -270,6 +270,11 @@ fn make_directive_handlers_map() -> HashMap<&'static str, Handler> {
                 r.trim().to_string()
             });
         }),
+        handler(DUMP_PROOF_TREE, |config, ln, props| {
+            if let Some(item) = config.parse_name_value_directive(ln, DUMP_PROOF_TREE) {
+                props.dump_proof_tree.push(item.trim().to_string());
+            }
+        }),
         handler(STDERR_PER_BITWIDTH, |config, ln, props| {
             config.set_name_directive(ln, STDERR_PER_BITWIDTH, &mut props.stderr_per_bitwidth);
         }),
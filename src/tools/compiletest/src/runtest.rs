@@ -1693,6 +1693,9 @@ fn make_compile_args(
                 rustc.arg("-Zui-testing");
                 rustc.arg("-Zdeduplicate-diagnostics=no");
                 rustc.arg("-Zwrite-long-types-to-disk=no");
+                for item in &self.props.dump_proof_tree {
+                    rustc.arg(format!("-Zdump-proof-tree={item}"));
+                }
                 // FIXME: use this for other modes too, for perf?
                 rustc.arg("-Cstrip=debuginfo");
             }
@@ -0,0 +1,29 @@
+// Check that blanket impls are computed using the new solver with `-Znext-solver`.
+//@ compile-flags: -Znext-solver
+#![crate_name = "foo"]
+
+pub trait Something {}
+
+pub trait Applies {}
+impl<T: Something> Applies for T {}
+
+pub trait NeverApplies {}
+impl<T: Unimplemented> NeverApplies for T {}
+
+trait Unimplemented {}
+
+//@ has 'foo/struct.MyStruct.html'
+//@ has - '//*[@id="blanket-implementations-list"]//*[@class="impl"]' 'impl<T> Applies for T'
+//@ !has - '//*[@id="blanket-implementations-list"]//*[@class="impl"]' 'NeverApplies'
+pub struct MyStruct;
+
+impl Something for MyStruct {}
+
+// The blanket impl may apply for some instantiation of `T`.
+
+//@ has 'foo/struct.Generic.html'
+//@ has - '//*[@id="blanket-implementations-list"]//*[@class="impl"]' 'impl<T> Applies for T'
+//@ !has - '//*[@id="blanket-implementations-list"]//*[@class="impl"]' 'NeverApplies'
+pub struct Generic<T>(T);
+
+impl Something for Generic<u32> {}
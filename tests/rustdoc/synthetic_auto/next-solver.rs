@@ -0,0 +1,46 @@
+// Check that auto trait impls are synthesized using the new solver with `-Znext-solver`.
+//@ compile-flags: -Znext-solver
+#![crate_name = "foo"]
+
+pub struct Inner<T> {
+    field: T,
+}
+
+unsafe impl<T> Send for Inner<T> where T: Copy {}
+
+//@ has foo/struct.Outer.html
+//@ has - '//*[@id="synthetic-implementations-list"]//*[@class="impl"]//h3[@class="code-header"]' \
+// 'impl<T> Send for Outer<T>where T: Copy'
+//
+//@ has - '//*[@id="synthetic-implementations-list"]//*[@class="impl"]//h3[@class="code-header"]' \
+// 'impl<T> Sync for Outer<T>where T: Sync'
+pub struct Outer<T> {
+    inner_field: Inner<T>,
+}
+
+// `Wrapper` uses the bounds computed for `Outer`.
+
+//@ has foo/struct.Wrapper.html
+//@ has - '//*[@id="synthetic-implementations-list"]//*[@class="impl"]//h3[@class="code-header"]' \
+// 'impl<U> Sync for Wrapper<U>where U: Sync'
+pub struct Wrapper<U> {
+    first: Outer<U>,
+    second: Outer<U>,
+}
+
+//@ has foo/struct.Raw.html
+//@ has - '//*[@id="synthetic-implementations-list"]//*[@class="impl"]//h3[@class="code-header"]' \
+// 'impl<T> !Send for Raw<T>'
+pub struct Raw<T> {
+    field: *mut T,
+}
+
+//@ has foo/struct.Manual.html
+//@ has - '//*[@id="trait-implementations-list"]//*[@class="impl"]//h3[@class="code-header"]' \
+// 'impl<T> Sync for Manual<T>'
+//@ count - '//*[@id="synthetic-implementations-list"]//h3[@class="code-header"][contains(., "Sync")]' 0
+pub struct Manual<T> {
+    field: *mut T,
+}
+
+unsafe impl<T> Sync for Manual<T> {}
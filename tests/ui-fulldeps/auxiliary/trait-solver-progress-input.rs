@@ -0,0 +1,11 @@
+trait Trait {}
+impl Trait for u32 {}
+impl<T: Trait> Trait for Vec<T> {}
+impl<T: Trait, U: Trait> Trait for (T, U) {}
+
+fn requires_trait<T: Trait>(_: T) {}
+
+fn main() {
+    requires_trait(vec![vec![1u32]]);
+    requires_trait((vec![1u32], (1u32, vec![vec![1u32]])));
+}
@@ -0,0 +1,61 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --emit=metadata {{src-base}}/auxiliary/trait-solver-progress-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+#![feature(rustc_private)]
+
+//! Checks that overriding the `trait_solver_progress` hook allows tools to
+//! cancel trait solving, which aborts compilation, with both trait solvers.
+
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rustc_interface::Config;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+/// The number of solved goals passed to the last call of the hook.
+static SOLVED_GOALS: AtomicUsize = AtomicUsize::new(0);
+
+fn main() {
+    let solvers: [&[&str]; 2] = [&[], &["-Znext-solver"]];
+    for solver_args in solvers {
+        SOLVED_GOALS.store(0, Ordering::Relaxed);
+        let mut rustc_args: Vec<_> = std::env::args().collect();
+        rustc_args.push("-Ztrait-solver-progress-interval=2".to_owned());
+        rustc_args.extend(solver_args.iter().map(|arg| arg.to_string()));
+        let exit_code = rustc_driver::catch_with_exit_code(|| {
+            rustc_driver::run_compiler(&rustc_args, &mut CompilerCalls)
+        });
+        assert_eq!(exit_code, rustc_driver::EXIT_FAILURE, "{solver_args:?}");
+        // We cancel trait solving the first time the hook gets called.
+        assert_eq!(SOLVED_GOALS.load(Ordering::Relaxed), 2, "{solver_args:?}");
+    }
+}
+
+struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, providers: &mut Providers) {
+    providers.hooks.trait_solver_progress = trait_solver_progress;
+}
+
+fn trait_solver_progress(_tcx: TyCtxt<'_>, solved_goals: usize) -> ControlFlow<()> {
+    let previous = SOLVED_GOALS.swap(solved_goals, Ordering::Relaxed);
+    assert_eq!(previous, 0, "trait solving continued after it has been cancelled");
+    ControlFlow::Break(())
+}
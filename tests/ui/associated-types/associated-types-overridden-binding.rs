@@ -1,10 +1,10 @@
 #![feature(trait_alias)]
 
 trait Foo: Iterator<Item = i32> {}
-trait Bar: Foo<Item = u32> {} //~ ERROR type annotations needed
+trait Bar: Foo<Item = u32> {} //~ ERROR conflicting requirements on
 
 trait I32Iterator = Iterator<Item = i32>;
-trait U32Iterator = I32Iterator<Item = u32>; //~ ERROR type annotations needed
+trait U32Iterator = I32Iterator<Item = u32>; //~ ERROR conflicting requirements on
 
 fn main() {
     let _: &dyn I32Iterator<Item = u32>;
@@ -0,0 +1,35 @@
+//@ add-minicore
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+// Point at both where-clauses when they require different types for the same
+// associated type, instead of asking for type annotations.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Iter {
+    type Item;
+}
+
+trait Numbers: Iter<Item = u32> {}
+
+fn consume<I: Iter<Item = X>, X>(_: I) {}
+
+fn from_supertrait<T: Numbers + Iter<Item = i64>>(t: T) {
+    //~^ ERROR conflicting requirements on `<T as Iter>::Item`
+    consume(t);
+}
+
+fn from_where_clauses<T>(t: T)
+where
+    T: Iter<Item = u8>,
+    //~^ ERROR conflicting requirements on `<T as Iter>::Item`
+    T: Iter<Item = u16>,
+{
+    consume(t);
+}
+
+fn main() {}
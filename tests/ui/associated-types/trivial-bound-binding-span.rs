@@ -0,0 +1,26 @@
+//@ add-minicore
+// Point at the `Assoc = Ty` binding of a trivially false where-clause
+// instead of the whole predicate.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Trait {
+    type Assoc;
+}
+
+struct S;
+impl Trait for S {
+    type Assoc = u8;
+}
+
+fn f() where S: Trait<Assoc = u32> {}
+//~^ ERROR type mismatch resolving `<S as Trait>::Assoc == u32`
+
+struct W;
+impl Copy for W where S: Trait<Assoc = u32> {}
+//~^ ERROR type mismatch resolving `<S as Trait>::Assoc == u32`
+
+fn main() {}
@@ -1,4 +1,6 @@
-//@ known-bug: #119095
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#119095
+//@ build-fail
 //@ edition: 2021
 
 fn any<T>() -> T {
@@ -27,7 +29,7 @@ fn get_connection<T>() -> impl Unit
 fn main() {
     let future = async { async { get_connection::<&'static ()>() }.await };
 
-    future.resolve_me();
+    future.resolve_me(); //~ ERROR the trait bound `(): CheckSend<
 }
 
 trait ResolveMe {
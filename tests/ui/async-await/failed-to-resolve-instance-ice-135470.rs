@@ -1,4 +1,6 @@
-//@ known-bug: #135470
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#135470
+//@ build-fail
 //@ compile-flags: -Copt-level=0
 //@ edition: 2021
 
@@ -36,6 +38,6 @@ trait Service {
 }
 impl<T: Send> Service for T {
     fn call(&self) {
-        Wrap::<T>::call2();
+        Wrap::<T>::call2(); //~ ERROR the trait bound `Wrap<
     }
 }
@@ -0,0 +1,102 @@
+//@ add-minicore
+//@ dont-require-annotations: HELP
+// Point at both operands when an operator trait bound fails through a derived requirement, and
+// suggest borrowing an operand or index when the operator trait is implemented for its reference.
+#![feature(no_core, lang_items)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+#[lang = "eq"]
+trait PartialEq<Rhs: ?Sized = Self> {
+    fn eq(&self, other: &Rhs) -> bool;
+}
+
+impl<'a, 'b, A: ?Sized + PartialEq<B>, B: ?Sized> PartialEq<&'b B> for &'a A {
+    fn eq(&self, other: &&'b B) -> bool {
+        true
+    }
+}
+
+#[lang = "index"]
+trait Index<Idx: ?Sized> {
+    type Output: ?Sized;
+
+    fn index(&self, index: Idx) -> &Self::Output;
+}
+
+struct Meters;
+struct Feet;
+
+impl PartialEq for Meters {
+    fn eq(&self, other: &Meters) -> bool {
+        true
+    }
+}
+
+impl<'a> PartialEq<u8> for &'a Meters {
+    fn eq(&self, other: &u8) -> bool {
+        true
+    }
+}
+
+struct Big;
+
+impl<'a> Add<&'a Big> for Big {
+    type Output = Big;
+
+    fn add(self, other: &'a Big) -> Big {
+        self
+    }
+}
+
+impl Add<u8> for Big {
+    type Output = Big;
+
+    fn add(self, other: u8) -> Big {
+        self
+    }
+}
+
+struct Key;
+struct Table;
+
+impl<'a> Index<&'a Key> for Table {
+    type Output = u8;
+
+    fn index(&self, index: &'a Key) -> &u8 {
+        &0
+    }
+}
+
+impl Index<u8> for Table {
+    type Output = u8;
+
+    fn index(&self, index: u8) -> &u8 {
+        &0
+    }
+}
+
+fn compare(m: &Meters, f: &Feet) -> bool {
+    m == f
+    //~^ ERROR the trait bound `Meters: PartialEq<Feet>` is not satisfied
+}
+
+fn sum(a: Big, b: Big) -> Big {
+    a + b
+    //~^ ERROR the trait bound `Big: minicore::Add` is not satisfied
+    //~| HELP consider borrowing here
+}
+
+fn lookup(table: &Table, key: Key, byte: &u8) -> u8 {
+    let _ = table[*byte];
+    let _ = table[byte];
+    //~^ ERROR the trait bound `Table: Index<&u8>` is not satisfied
+    //~| HELP dereference this index
+    table[key]
+    //~^ ERROR the trait bound `Table: Index<Key>` is not satisfied
+    //~| HELP borrow this index
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// Check that we only explain that references are `#[fundamental]` if this
+// is what allows downstream crates to implement the trait.
+
+trait Bar {}
+trait Foo {}
+impl<T: Bar> Foo for T {}
+impl<T> Foo for &T {}
+//~^ ERROR conflicting implementations of trait `Foo` for type `&_`
+
+// Downstream crates may implement `GenericBar<TheirType>` for any type.
+trait GenericBar<U> {}
+trait GenericFoo<U> {}
+impl<T: GenericBar<U>, U> GenericFoo<U> for T {}
+impl<T, U> GenericFoo<U> for &T {}
+//~^ ERROR conflicting implementations of trait `GenericFoo<_>` for type `&_`
+
+fn main() {}
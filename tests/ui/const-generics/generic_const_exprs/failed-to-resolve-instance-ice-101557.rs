@@ -1,6 +1,8 @@
-//@ known-bug: #101557
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#101557
+//@ build-fail
 //@ compile-flags: -Copt-level=0
-#![feature(generic_const_exprs)]
+#![feature(generic_const_exprs)] //~ WARN the feature `generic_const_exprs` is incomplete
 use std::marker::PhantomData;
 
 trait Trait {
@@ -33,7 +35,7 @@ fn f<T: Trait>() -> A<T>
 {
     // Usage of `0` is arbitrary
     let a = A::<[i8; T::CONST]>::from(0);
-    A::<T>::from(a)
+    A::<T>::from(a) //~ ERROR type annotations needed
 }
 
 fn main() {
@@ -1,4 +1,6 @@
-//@ known-bug: #119692
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#119692
+//@ build-fail
 //@ compile-flags: -Copt-level=0
 #![allow(incomplete_features)]
 #![feature(adt_const_params)]
@@ -40,7 +42,7 @@ fn add(self, rhs: Quantity<f32, { Dimension }>) -> Self::Output {
 }
 
 pub fn add<const U: Dimension>(x: Quantity<f32, U>, y: Quantity<f32, U>) -> Quantity<f32, U> {
-    x + y
+    x + y //~ ERROR type annotations needed
 }
 
 fn main() {
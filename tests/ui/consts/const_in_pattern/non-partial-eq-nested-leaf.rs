@@ -0,0 +1,84 @@
+//@ add-minicore
+// Only point at the nested types whose `PartialEq` bounds fail to hold when a constant of
+// a type which does not implement `PartialEq` is used in a pattern.
+#![feature(no_core, lang_items)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+#[lang = "eq"]
+trait PartialEq<Rhs: ?Sized = Self> {
+    fn eq(&self, other: &Rhs) -> bool;
+}
+
+#[lang = "structural_peq"]
+trait StructuralPartialEq {}
+
+impl<T: ?Sized> PartialEq for PhantomData<T> {
+    fn eq(&self, other: &PhantomData<T>) -> bool {
+        true
+    }
+}
+impl<T: ?Sized> StructuralPartialEq for PhantomData<T> {}
+
+impl<A: PartialEq, B: PartialEq> PartialEq for (A, B) {
+    fn eq(&self, other: &(A, B)) -> bool {
+        true
+    }
+}
+
+enum Maybe<T> {
+    Nothing,
+    Just(T),
+}
+
+impl<T: PartialEq> PartialEq for Maybe<T> {
+    fn eq(&self, other: &Maybe<T>) -> bool {
+        true
+    }
+}
+impl<T> StructuralPartialEq for Maybe<T> {}
+
+trait Marker {}
+
+struct Checked<T>(T);
+
+impl<T: Marker> PartialEq for Checked<T> {
+    fn eq(&self, other: &Checked<T>) -> bool {
+        true
+    }
+}
+impl<T> StructuralPartialEq for Checked<T> {}
+
+struct NoEq;
+//~^ NOTE must be annotated with `#[derive(PartialEq)]` to be usable in patterns
+struct Phantom;
+
+const PAIR: (Maybe<NoEq>, PhantomData<Phantom>) = (Maybe::Nothing, PhantomData);
+//~^ NOTE constant defined here
+const CHECKED: Checked<u8> = Checked(0);
+//~^ NOTE constant defined here
+
+fn pair(x: (Maybe<NoEq>, PhantomData<Phantom>)) {
+    match x {
+        PAIR => {}
+        //~^ ERROR constant of non-structural type `(Maybe<NoEq>, minicore::PhantomData<Phantom>)` in a pattern
+        //~| NOTE constant of non-structural type
+        //~| NOTE see https://doc.rust-lang.org/stable/std/marker/trait.StructuralPartialEq.html for details
+        _ => {}
+    }
+}
+
+fn checked(x: Checked<u8>) {
+    match x {
+        CHECKED => {}
+        //~^ ERROR constant of non-structural type `Checked<u8>` in a pattern
+        //~| NOTE `Checked<u8>` does not implement `PartialEq` because the trait bound `u8: Marker` is not satisfied
+        //~| NOTE constant of non-structural type
+        //~| NOTE see https://doc.rust-lang.org/stable/std/marker/trait.StructuralPartialEq.html for details
+        _ => {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//@compile-flags: --diagnostic-width=300
+//@ revisions: current next
+//@[next] compile-flags: -Znext-solver
+//@ ignore-compare-mode-next-solver (explicit revisions)
+// Check that we mention the unstable feature when a coroutine is not
+// `Copy`/`Clone` because `coroutine_clone` is not enabled.
+
+#![feature(coroutines, stmt_expr_attributes)]
+
+fn main() {
+    let generator = #[coroutine]
+    move || {
+        yield;
+    };
+    check_copy(&generator);
+    //~^ ERROR Copy` is not satisfied
+    check_clone(&generator);
+    //~^ ERROR Clone` is not satisfied
+}
+
+fn check_copy<T: Copy>(_x: &T) {}
+fn check_clone<T: Clone>(_x: &T) {}
@@ -0,0 +1,57 @@
+//@ add-minicore
+//@ dont-require-annotations: NOTE
+// Parameters marked `#[diagnostic::inferred_from_self]` are only reported as needing
+// annotations when nothing else is left to annotate.
+#![feature(no_core, diagnostic_inferred_from_self)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+struct Foo;
+struct A;
+struct B;
+
+trait Convert<#[diagnostic::inferred_from_self] Out> {
+    fn convert(&self) -> Out;
+}
+
+impl Convert<A> for Foo {
+    fn convert(&self) -> A {
+        A
+    }
+}
+
+impl Convert<B> for Foo {
+    fn convert(&self) -> B {
+        B
+    }
+}
+
+trait Produce<#[diagnostic::inferred_from_self] Out, Arg> {
+    fn produce(&self, arg: Arg) -> Out;
+}
+
+impl Produce<A, A> for Foo {
+    fn produce(&self, arg: A) -> A {
+        arg
+    }
+}
+
+impl Produce<B, B> for Foo {
+    fn produce(&self, arg: B) -> B {
+        arg
+    }
+}
+
+fn make<T>() -> T {
+    loop {}
+}
+
+fn main() {
+    let _x = Foo.convert();
+    //~^ ERROR type annotations needed
+    //~| NOTE the type parameter `Out` of `Convert` is usually inferred from the `Self` type, which is `Foo` here
+    let _y = Foo.produce(make());
+    //~^ ERROR type annotations needed
+}
@@ -0,0 +1,37 @@
+#![feature(diagnostic_inferred_from_self)]
+
+pub struct Foo;
+pub struct A;
+pub struct B;
+
+pub trait Convert<#[diagnostic::inferred_from_self] Out> {
+    fn convert(&self) -> Out;
+}
+
+impl Convert<A> for Foo {
+    fn convert(&self) -> A {
+        A
+    }
+}
+
+impl Convert<B> for Foo {
+    fn convert(&self) -> B {
+        B
+    }
+}
+
+pub trait Plain<Out> {
+    fn plain(&self) -> Out;
+}
+
+impl Plain<A> for Foo {
+    fn plain(&self) -> A {
+        A
+    }
+}
+
+impl Plain<B> for Foo {
+    fn plain(&self) -> B {
+        B
+    }
+}
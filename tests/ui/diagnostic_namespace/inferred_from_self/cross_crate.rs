@@ -0,0 +1,15 @@
+//@ aux-build:inferred_from_self_other.rs
+//@ dont-require-annotations: NOTE
+// `#[diagnostic::inferred_from_self]` is also respected for traits from other crates,
+// and type parameters without it are not affected.
+
+extern crate inferred_from_self_other;
+use inferred_from_self_other::{Convert, Foo, Plain};
+
+fn main() {
+    let _x = Foo.convert();
+    //~^ ERROR type annotations needed
+    //~| NOTE the type parameter `Out` of `Convert` is usually inferred from the `Self` type, which is `Foo` here
+    let _y = Foo.plain();
+    //~^ ERROR type annotations needed
+}
@@ -0,0 +1,22 @@
+#![feature(diagnostic_inferred_from_self)]
+#![deny(misplaced_diagnostic_attributes, malformed_diagnostic_attributes)]
+
+#[diagnostic::inferred_from_self]
+//~^ ERROR: `#[diagnostic::inferred_from_self]` can only be applied to type parameters of trait definitions
+pub trait Foo<T> {}
+
+pub struct Bar<#[diagnostic::inferred_from_self] T>(T);
+//~^ ERROR: `#[diagnostic::inferred_from_self]` can only be applied to type parameters of trait definitions
+
+pub trait Baz<#[diagnostic::inferred_from_self] 'a> {
+    //~^ ERROR: `#[diagnostic::inferred_from_self]` can only be applied to type parameters of trait definitions
+    fn baz<#[diagnostic::inferred_from_self] T>(&self);
+    //~^ ERROR: `#[diagnostic::inferred_from_self]` can only be applied to type parameters of trait definitions
+}
+
+pub trait Qux<#[diagnostic::inferred_from_self(T)] T> {}
+//~^ ERROR: `#[diagnostic::inferred_from_self]` does not expect any arguments
+
+pub trait Ok<#[diagnostic::inferred_from_self] T> {}
+
+fn main() {}
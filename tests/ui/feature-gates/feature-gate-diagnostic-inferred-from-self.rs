@@ -0,0 +1,38 @@
+//@ add-minicore
+//! This is an unusual feature gate test, as it doesn't test the feature
+//! gate, but the fact that not adding the feature gate will cause the
+//! attribute to be ignored when reporting ambiguity errors.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+struct Foo;
+struct A;
+struct B;
+
+trait Produce<#[diagnostic::inferred_from_self] Out, Arg> {
+    fn produce(&self, arg: Arg) -> Out;
+}
+
+impl Produce<A, A> for Foo {
+    fn produce(&self, arg: A) -> A {
+        arg
+    }
+}
+
+impl Produce<B, B> for Foo {
+    fn produce(&self, arg: B) -> B {
+        arg
+    }
+}
+
+fn make<T>() -> T {
+    loop {}
+}
+
+fn main() {
+    let _y = Foo.produce(make());
+    //~^ ERROR type annotations needed
+}
@@ -0,0 +1,67 @@
+//@ add-minicore
+//@ dont-require-annotations: NOTE
+//@ dont-require-annotations: HELP
+// Suggest `{:?}` at the formatting parameter when an argument doesn't implement `Display` but
+// does implement `Debug`, including when the failing bound was derived from the argument's type.
+#![feature(no_core, lang_items, rustc_attrs, decl_macro)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+#[rustc_builtin_macro]
+macro format_args($fmt:expr) {}
+
+#[rustc_diagnostic_item = "Display"]
+trait Display {}
+
+#[rustc_diagnostic_item = "Debug"]
+trait Debug {}
+
+#[lang = "format_argument"]
+struct Argument<'a>(&'a u8);
+
+impl<'a> Argument<'a> {
+    fn new_display<T: Display>(x: &'a T) -> Argument<'a> {
+        loop {}
+    }
+}
+
+#[lang = "format_arguments"]
+struct Arguments<'a>(&'a u8);
+
+impl<'a> Arguments<'a> {
+    unsafe fn new<const N: usize, const M: usize>(
+        template: &'a [u8; N],
+        args: &'a [Argument<'a>; M],
+    ) -> Arguments<'a> {
+        loop {}
+    }
+}
+
+struct OnlyDebug;
+impl Debug for OnlyDebug {}
+
+struct Neither;
+
+struct Wrap<T>(T);
+impl<T: Display> Display for Wrap<T> {}
+impl<T: Debug> Debug for Wrap<T> {}
+
+fn main() {
+    let x = OnlyDebug;
+    format_args!("{}", x);
+    //~^ ERROR the trait bound `OnlyDebug: Display` is not satisfied
+    //~| HELP use the `Debug` implementation of `OnlyDebug` instead
+    format_args!("{x}");
+    //~^ ERROR the trait bound `OnlyDebug: Display` is not satisfied
+    //~| HELP use the `Debug` implementation of `OnlyDebug` instead
+    format_args!("{:>5}", x);
+    //~^ ERROR the trait bound `OnlyDebug: Display` is not satisfied
+    //~| HELP use the `Debug` implementation of `OnlyDebug` instead
+    format_args!("{}", Neither);
+    //~^ ERROR the trait bound `Neither: Display` is not satisfied
+    format_args!("{}", Wrap(OnlyDebug));
+    //~^ ERROR the trait bound `OnlyDebug: Display` is not satisfied
+    //~| HELP use the `Debug` implementation of `Wrap<OnlyDebug>` instead
+}
@@ -1,6 +1,6 @@
-//@ known-bug: #139120
-
-
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#139120
+//@ build-fail
 
 pub trait Foo {
     type Bar<'a>;
@@ -13,7 +13,7 @@ impl Foo for FooImpl {
 }
 
 pub trait FooFn {
-    fn bar(&self);
+    fn bar(&self); //~ ERROR the trait bound `fn(FooImpl, ()): FooFn` is not satisfied
 }
 
 impl<T: Foo> FooFn for fn(T, T::Bar<'_>) {
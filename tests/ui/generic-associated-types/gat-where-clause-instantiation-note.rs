@@ -0,0 +1,29 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+// Checks that we name the instantiation of a generic associated type whose
+// where-clause does not hold.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+struct NotCopy;
+trait WithLifetime<'a> {}
+
+trait Lending {
+    type Item<'a, T>
+    where
+        T: Copy;
+
+    type Pair<'a, T>
+    where
+        T: WithLifetime<'a>;
+}
+
+fn use_site<L: Lending>(_: L::Item<'static, NotCopy>) {}
+//~^ ERROR the trait bound `NotCopy: minicore::Copy` is not satisfied
+fn use_site_with_lifetime<L: Lending>(_: L::Pair<'static, u8>) {}
+//~^ ERROR the trait bound `u8: WithLifetime<'static>` is not satisfied
+
+fn main() {}
@@ -0,0 +1,37 @@
+//@ add-minicore
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver=globally
+// Checks that the required bounds of GATs are the same with both solvers.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Iterable {
+    type Item<'x>;
+    //~^ ERROR missing required bound on `Item`
+    fn iter<'a>(&'a self) -> Self::Item<'a>;
+}
+
+trait Deserializer<T> {
+    type Out<'x>;
+    //~^ ERROR missing required bound on `Out`
+    fn deserialize<'a>(&self, input: &'a T) -> Self::Out<'a>;
+}
+
+trait Outlives {
+    type Ref<'a, 'b>;
+    //~^ ERROR missing required bound on `Ref`
+    fn get<'a, 'b>(x: &'a &'b ()) -> Self::Ref<'a, 'b>;
+}
+
+trait AlreadyBounded {
+    type Item<'x>
+    where
+        Self: 'x;
+    fn iter<'a>(&'a self) -> Self::Item<'a>;
+}
+
+fn main() {}
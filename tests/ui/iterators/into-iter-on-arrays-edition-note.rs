@@ -0,0 +1,42 @@
+//@ add-minicore
+//@ edition:2018
+// Before Rust 2021, `array.into_iter()` skips the by-value impl for arrays. Point
+// this out if the resulting by-reference iteration causes an unsatisfied bound.
+#![feature(no_core, rustc_attrs)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+#[rustc_skip_during_method_dispatch(array)]
+pub trait IntoIter {
+    type Item;
+    fn into_iter(self) -> Self::Item;
+}
+
+impl<T, const N: usize> IntoIter for [T; N] {
+    type Item = T;
+    fn into_iter(self) -> T {
+        loop {}
+    }
+}
+
+impl<'a, T, const N: usize> IntoIter for &'a [T; N] {
+    type Item = &'a T;
+    fn into_iter(self) -> &'a T {
+        loop {}
+    }
+}
+
+trait Takes {}
+impl Takes for u8 {}
+
+fn takes<T: Takes>(_: T) {}
+
+fn main() {
+    let a = [1u8];
+    takes(a.into_iter());
+    //~^ ERROR the trait bound `&u8: Takes` is not satisfied
+    //~| WARN trait method `into_iter` will become ambiguous in Rust 2021
+    //~| WARN this changes meaning in Rust 2021
+}
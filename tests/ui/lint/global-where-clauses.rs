@@ -0,0 +1,30 @@
+//@ add-minicore
+// Trait bounds which don't depend on any generic parameters and always hold are redundant.
+#![feature(no_core)]
+#![no_core]
+#![deny(global_where_clauses)]
+
+extern crate minicore;
+use minicore::*;
+
+pub struct A where i32: Copy;
+//~^ ERROR the trait bound `i32: minicore::Copy` always holds
+
+pub fn first<T>() where u8: Copy, T: Copy {}
+//~^ ERROR the trait bound `u8: minicore::Copy` always holds
+
+pub fn last<T>() where T: Copy, u8: Sized + Copy {}
+//~^ ERROR the trait bound `u8: minicore::Sized` always holds
+//~| ERROR the trait bound `u8: minicore::Copy` always holds
+
+pub fn not_global<T>() where T: Copy, Option<T>: Copy {}
+
+macro_rules! copy_fn {
+    ($t:ty) => {
+        pub fn from_macro() where $t: Copy {}
+        //~^ ERROR the trait bound `u32: minicore::Copy` always holds
+    };
+}
+copy_fn!(u32);
+
+fn main() {}
@@ -0,0 +1,46 @@
+//@ add-minicore
+// Point out how the traits of ambiguous method candidates are in scope if a
+// trait from the prelude collides with an imported trait, or if the traits
+// come from multiple glob imports.
+#![feature(no_core, prelude_import)]
+#![no_core]
+
+extern crate minicore;
+#[prelude_import]
+use minicore::*;
+
+mod ext {
+    pub trait AddExt {
+        fn add(self, rhs: isize) -> isize;
+    }
+    impl AddExt for isize {
+        fn add(self, rhs: isize) -> isize {
+            rhs
+        }
+    }
+}
+
+mod a {
+    pub trait A {
+        fn foo(&self) {}
+    }
+    impl A for u8 {}
+}
+
+mod b {
+    pub trait B {
+        fn foo(&self) {}
+    }
+    impl B for u8 {}
+}
+
+use ext::AddExt;
+use a::*;
+use b::*;
+
+fn main() {
+    1isize.add(2);
+    //~^ ERROR multiple applicable items in scope
+    1u8.foo();
+    //~^ ERROR multiple applicable items in scope
+}
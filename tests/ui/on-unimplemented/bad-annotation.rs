@@ -100,7 +100,7 @@ trait KeyMustBeIdentifier2 {}
 
 #[rustc_on_unimplemented(on(aaaaaaaaaaaaaa(a, b), message = "y"))]
 //~^ ERROR this predicate is invalid
-//~^^ NOTE expected one of `any`, `all` or `not` here, not `aaaaaaaaaaaaaa`
+//~^^ NOTE expected one of `any`, `all`, `not` or `implements` here, not `aaaaaaaaaaaaaa`
 trait InvalidPredicate {}
 
 #[rustc_on_unimplemented(on(something, message = "y"))]
@@ -0,0 +1,19 @@
+//@ add-minicore
+#![feature(no_core, rustc_attrs)]
+#![no_core]
+
+extern crate minicore;
+
+#[rustc_on_unimplemented(on(implements(T), message = "x"))]
+//~^ ERROR expected a single `param = "path::to::Trait"` in `implements(..)`
+trait NoTrait<T> {}
+
+#[rustc_on_unimplemented(on(implements(T = "minicore::Copy", T = "minicore::Sized"), message = "x"))]
+//~^ ERROR expected a single `param = "path::to::Trait"` in `implements(..)`
+trait TwoTraits<T> {}
+
+#[rustc_on_unimplemented(on(implements(U = "minicore::Copy"), message = "x"))]
+//~^ ERROR invalid name in `on`-clause
+trait UnknownParam<T> {}
+
+fn main() {}
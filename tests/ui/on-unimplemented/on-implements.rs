@@ -0,0 +1,25 @@
+//@ add-minicore
+// Test that `on`-filters can check whether a generic argument implements a trait.
+#![feature(no_core, rustc_attrs)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+#[rustc_on_unimplemented(
+    on(implements(T = "minicore::Copy"), note = "`{T}` is `Copy`, so use `copied()`"),
+    on(not(implements(T = "minicore::Copy")), note = "use `iter()` because `{T}` is not `Copy`"),
+    message = "cannot collect into `{Self}` from `{T}`",
+)]
+trait Collect<T> {}
+
+struct NotCopy;
+
+fn collect<C: Collect<T>, T>() {}
+
+fn main() {
+    collect::<(), u8>();
+    //~^ ERROR cannot collect into `()` from `u8`
+    collect::<(), NotCopy>();
+    //~^ ERROR cannot collect into `()` from `NotCopy`
+}
@@ -0,0 +1,28 @@
+// Check that fulfillment errors are reported in a deterministic order
+// when using the parallel frontend.
+//
+//@ add-minicore
+//@ compile-flags: -Z threads=16
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+struct NotCopy;
+
+trait First {}
+trait Second {}
+
+fn requires_copy<T: Copy>(_: T) {}
+fn requires_both<T: Second + First>() {}
+
+fn main() {
+    requires_copy(NotCopy);
+    //~^ ERROR the trait bound `NotCopy: minicore::Copy` is not satisfied
+    requires_both::<NotCopy>();
+    //~^ ERROR the trait bound `NotCopy: Second` is not satisfied
+    //~| ERROR the trait bound `NotCopy: First` is not satisfied
+    requires_copy(&mut NotCopy);
+    //~^ ERROR the trait bound `&mut NotCopy: minicore::Copy` is not satisfied
+}
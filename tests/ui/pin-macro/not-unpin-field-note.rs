@@ -0,0 +1,48 @@
+//@ add-minicore
+//@ dont-require-annotations: NOTE
+// Point at the field which makes a type `!Unpin`, and suggest pinning local arguments
+// with `pin!` when that satisfies the bound of the callee.
+#![feature(no_core, lang_items, negative_impls)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+#[lang = "pin"]
+struct Pin<Ptr> {
+    pointer: Ptr,
+}
+
+impl<Ptr: Unpin> Unpin for Pin<Ptr> {}
+
+struct PhantomPinned;
+//~^ HELP within `Sleep`, the trait `minicore::Unpin` is not implemented for `PhantomPinned`
+//~| HELP within `Sleep`, the trait `minicore::Unpin` is not implemented for `PhantomPinned`
+impl !Unpin for PhantomPinned {}
+
+struct Sleep {
+    timer: u32,
+    _pinned: PhantomPinned,
+    //~^ NOTE `Sleep` is not `Unpin` because this field of type `PhantomPinned` is not
+}
+
+trait Poll {}
+impl<F: Unpin> Poll for &mut F {}
+//~^ HELP the following other types implement trait `Poll`
+impl<F> Poll for Pin<&mut F> {}
+
+fn assert_unpin<T: Unpin>(_: T) {}
+fn poll<P: Poll>(_: P) {}
+
+fn by_value(sleep: Sleep) {
+    assert_unpin(sleep);
+    //~^ ERROR `PhantomPinned` cannot be unpinned
+}
+
+fn by_mut_ref(mut sleep: Sleep) {
+    poll(&mut sleep);
+    //~^ ERROR `PhantomPinned` cannot be unpinned
+    //~| HELP consider pinning `sleep` in place with the `pin!` macro
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//@ add-minicore
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub trait Format: private::Sealed {}
+
+pub struct Json;
+impl private::Sealed for Json {}
+impl Format for Json {}
+
+pub fn write<F: Format>() {}
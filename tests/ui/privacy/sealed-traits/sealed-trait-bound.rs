@@ -0,0 +1,16 @@
+//@ add-minicore
+//@ aux-build: sealed-trait.rs
+// Point out that a trait from another crate is sealed when a local type
+// doesn't implement it, as adding an impl can't fix the error.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+extern crate sealed_trait;
+
+struct Yaml;
+
+fn main() {
+    sealed_trait::write::<Yaml>();
+    //~^ ERROR the trait bound `Yaml: Format` is not satisfied
+}
@@ -0,0 +1,23 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+#[proc_macro_derive(Encode)]
+pub fn derive(_input: TokenStream) -> TokenStream {
+    "impl Config {
+        fn encode(&self) {
+            let _: crate::AssertEncode<u32>;
+            let _: crate::AssertEncode<Mode>;
+            let _: crate::AssertEncode<Mode>;
+        }
+
+        fn encoded_len(&self) -> usize {
+            let _: crate::AssertEncode<u32>;
+            let _: crate::AssertEncode<Mode>;
+            0
+        }
+    }
+    "
+    .parse()
+    .unwrap()
+}
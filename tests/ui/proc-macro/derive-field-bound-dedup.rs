@@ -0,0 +1,24 @@
+//@ proc-macro: derive-field-bound.rs
+//@ ignore-backends: gcc
+// Point at the field when code generated by a derive macro requires a bound its type doesn't
+// satisfy, and only report it once even though the generated code requires it several times.
+
+#[macro_use]
+extern crate derive_field_bound;
+
+trait Encode {}
+
+impl Encode for u32 {}
+
+struct AssertEncode<T: Encode>(T);
+
+struct Mode;
+
+#[derive(Encode)]
+struct Config {
+    name: u32,
+    mode: Mode,
+    //~^ ERROR the trait bound `Mode: Encode` is not satisfied
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//@ revisions: current next
+//@[next] compile-flags: -Znext-solver-infer-outlives
+// Check that computing the outlives requirements of fields which only consist of
+// builtin types with the trait solver matches the default computation.
+#![feature(rustc_attrs)]
+
+#[rustc_outlives]
+struct Nested<'a, 'b, T> { //~ ERROR rustc_outlives
+    field: &'a &'b T,
+}
+
+#[rustc_outlives]
+struct Composite<'a, 'b, T, U, const N: usize> { //~ ERROR rustc_outlives
+    array: [&'a (T, *const &'b U); N],
+    fn_ptr: for<'c> fn(&'c T, &'a U),
+}
+
+#[rustc_outlives]
+enum Enum<'a, T> { //~ ERROR rustc_outlives
+    Ref(&'a mut [T]),
+    Static(&'static str),
+}
+
+fn main() {}
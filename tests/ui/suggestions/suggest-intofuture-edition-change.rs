@@ -0,0 +1,14 @@
+// Make sure that trying to access `IntoFuture` in pre-2024 mentions the
+// Edition 2024 change.
+//@ edition:2021
+
+async fn test() {
+    let _ = IntoFuture::into_future(async {}).await;
+    //~^ ERROR failed to resolve: use of undeclared type
+    //~| NOTE use of undeclared type
+    //~| NOTE 'std::future::IntoFuture' is included in the prelude starting in Edition 2024
+}
+
+fn main() {
+    let _ = test();
+}
@@ -1,3 +1,7 @@
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+
 // Test case where an associated type is referenced from within the
 // supertrait definition, and the impl makes the wrong
 // associations. Issue #20220.
@@ -10,7 +14,8 @@ pub trait Foo: Iterator<Item = <Self as Foo>::Key> {
 
 impl Foo for IntoIter<i32> {
     type Key = u32;
-    //~^ ERROR expected `IntoIter<i32>` to be an iterator that yields `u32`, but it yields `i32`
+    //[current]~^ ERROR expected `IntoIter<i32>` to be an iterator that yields `u32`, but it yields `i32`
+    //[next]~^^ ERROR type mismatch resolving `i32 == u32`
 }
 
 fn main() {}
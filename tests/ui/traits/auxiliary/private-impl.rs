@@ -0,0 +1,10 @@
+//@ add-minicore
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+
+pub trait Marker {}
+
+pub(crate) struct Hidden;
+impl Marker for Hidden {}
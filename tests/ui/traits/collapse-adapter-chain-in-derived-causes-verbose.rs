@@ -0,0 +1,25 @@
+//@ add-minicore
+//@ compile-flags: -Zverbose-internals
+// With `-Zverbose-internals`, requirements through a chain of adapters are not collapsed.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Iter {}
+
+struct Map<I, F>(I, F);
+struct Filter<I, P>(I, P);
+struct Empty;
+
+impl<I: Iter, F> Iter for Map<I, F> {}
+impl<I: Iter, P> Iter for Filter<I, P> {}
+
+fn consume<I: Iter>(_: I) {}
+
+fn main() {
+    let iter = Map(Filter(Map(Map(Filter(Empty, ()), ()), ()), ()), ());
+    consume(iter);
+    //~^ ERROR the trait bound `Empty: Iter` is not satisfied
+}
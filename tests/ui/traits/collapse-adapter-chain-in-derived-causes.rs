@@ -0,0 +1,24 @@
+//@ add-minicore
+// Requirements through a chain of adapters are collapsed into a single note.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Iter {}
+
+struct Map<I, F>(I, F);
+struct Filter<I, P>(I, P);
+struct Empty;
+
+impl<I: Iter, F> Iter for Map<I, F> {}
+impl<I: Iter, P> Iter for Filter<I, P> {}
+
+fn consume<I: Iter>(_: I) {}
+
+fn main() {
+    let iter = Map(Filter(Map(Map(Filter(Empty, ()), ()), ()), ()), ());
+    consume(iter);
+    //~^ ERROR the trait bound `Empty: Iter` is not satisfied
+}
@@ -1,4 +1,6 @@
-//@ known-bug: #135718
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#135718
+//@ build-fail
 
 struct Equal;
 
@@ -46,5 +48,5 @@ impl Problem<()> for Sum where Bar: TwiceNested {}
 impl Problem<Equal> for Sum where Bar: TwiceNested {}
 
 fn main() {
-    Sum::not();
+    Sum::not(); //~ ERROR the trait bound `Sum: Not` is not satisfied
 }
@@ -0,0 +1,23 @@
+//@ add-minicore
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+
+pub trait Portable {}
+
+pub fn require_portable<T: Portable>(_: &T) {}
+
+#[macro_export]
+macro_rules! assert_portable {
+    ($e:expr) => {
+        $crate::require_portable(&$e)
+    };
+}
+
+#[macro_export]
+macro_rules! assert_all_portable {
+    ($($e:expr),*) => {
+        $($crate::assert_portable!($e);)*
+    };
+}
@@ -0,0 +1,25 @@
+//@ compile-flags: -Znext-solver=globally
+//@ check-pass
+
+// Evaluating the associated constants below resolves them through codegen
+// selection, which uses the new solver when it is enabled globally.
+
+trait Trait {
+    const VALUE: usize;
+}
+
+impl Trait for u32 {
+    const VALUE: usize = 1;
+}
+
+impl<T: Trait> Trait for Vec<T> {
+    const VALUE: usize = T::VALUE + 1;
+}
+
+impl<T: Trait, U: Trait> Trait for (T, U) {
+    const VALUE: usize = T::VALUE + U::VALUE;
+}
+
+const _: () = assert!(<(Vec<u32>, Vec<Vec<u32>>) as Trait>::VALUE == 5);
+
+fn main() {}
@@ -0,0 +1,36 @@
+//@ compile-flags: -Znext-solver
+// With ten or more applicable impls we don't point at them after looking at them
+// ourselves. The new solver does however tell us which impls actually apply to
+// the ambiguous goal, so we point to all of them.
+
+trait Trait<T> {}
+
+struct Foo;
+struct A0;
+struct A1;
+struct A2;
+struct A3;
+struct A4;
+struct A5;
+struct A6;
+struct A7;
+struct A8;
+struct A9;
+
+impl Trait<A0> for Foo {}
+impl Trait<A1> for Foo {}
+impl Trait<A2> for Foo {}
+impl Trait<A3> for Foo {}
+impl Trait<A4> for Foo {}
+impl Trait<A5> for Foo {}
+impl Trait<A6> for Foo {}
+impl Trait<A7> for Foo {}
+impl Trait<A8> for Foo {}
+impl Trait<A9> for Foo {}
+
+fn impls_trait<T: Trait<U>, U>(_: T) {}
+
+fn main() {
+    impls_trait(Foo);
+    //~^ ERROR type annotations needed
+}
@@ -0,0 +1,37 @@
+//@ compile-flags: -Znext-solver
+// When the self type of an ambiguous goal is unconstrained, only list the
+// types whose impls may apply, i.e. not the ones implementing `Trait<i32>`.
+
+trait Trait<T> {}
+
+struct A0;
+impl Trait<u32> for A0 {}
+struct A1;
+impl Trait<u32> for A1 {}
+struct A2;
+impl Trait<u32> for A2 {}
+struct A3;
+impl Trait<u32> for A3 {}
+struct A4;
+impl Trait<u32> for A4 {}
+struct A5;
+impl Trait<u32> for A5 {}
+struct A6;
+impl Trait<u32> for A6 {}
+struct A7;
+impl Trait<u32> for A7 {}
+struct A8;
+impl Trait<u32> for A8 {}
+struct A9;
+impl Trait<u32> for A9 {}
+struct B0;
+impl Trait<i32> for B0 {}
+struct B1;
+impl Trait<i32> for B1 {}
+
+fn needs<T: Trait<U>, U>(_: U) {}
+
+fn main() {
+    needs(1u32);
+    //~^ ERROR type annotations needed
+}
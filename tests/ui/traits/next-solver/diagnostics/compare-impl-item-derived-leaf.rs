@@ -0,0 +1,42 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+// Stricter where-clauses of impl items which only fail due to a nested goal are
+// still reported at the where-clause of the impl item.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Foo {}
+trait Bar {}
+impl<T: Bar> Foo for T {}
+
+trait Id {
+    type This;
+}
+impl<T> Id for T {
+    type This = T;
+}
+
+trait Trait {
+    fn method<T>();
+    fn normalizes<T>();
+}
+
+impl Trait for () {
+    fn method<T>()
+    where
+        T: Foo,
+        //~^ ERROR impl has stricter requirements than trait
+    {
+    }
+    fn normalizes<T>()
+    where
+        <T as Id>::This: Foo,
+        //~^ ERROR impl has stricter requirements than trait
+    {
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+//@ compile-flags: -Znext-solver -Zdump-goal-env
+
+// Check that `-Zdump-goal-env` notes the where-clauses which may
+// be used to prove the failing goal.
+
+trait Trait<A> {}
+
+trait Other {
+    type Assoc;
+}
+
+fn needs_trait<T: Trait<u16>>() {}
+
+fn no_where_clauses<T: Trait<u8>>() {
+    needs_trait::<T>();
+    //~^ ERROR the trait bound `T: Trait<u16>` is not satisfied
+}
+
+fn where_clauses<T, U: Other>()
+where
+    T: Trait<u8>,
+    T: Trait<<U as Other>::Assoc>,
+{
+    needs_trait::<T>();
+    //~^ ERROR the trait bound `T: Trait<u16>` is not satisfied
+}
+
+fn main() {}
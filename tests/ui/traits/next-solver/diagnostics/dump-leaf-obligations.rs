@@ -0,0 +1,44 @@
+//@ add-minicore
+// Checks the leaf obligations derived from the proof trees of failing goals.
+#![feature(no_core, rustc_attrs, trivial_bounds)]
+#![no_core]
+#![allow(trivial_bounds)]
+
+extern crate minicore;
+use minicore::*;
+
+trait Foo {}
+trait Bar {}
+trait Constrain {
+    type Output;
+}
+
+impl<T, U> Foo for T
+where
+    T: Constrain<Output = U>,
+    U: Bar,
+{
+}
+
+impl Constrain for () {
+    type Output = ();
+}
+
+impl Bar for u32 {}
+impl Constrain for u32 {
+    type Output = u32;
+}
+
+trait Env<T: Bar> {
+    #[rustc_dump_leaf_obligations]
+    fn goals()
+    where
+        u32: Foo,
+        //~^ ERROR rustc_dump_leaf_obligations: `u32: Foo`
+        (): Foo,
+        //~^ ERROR rustc_dump_leaf_obligations: `(): Foo`
+        T: Foo;
+        //~^ ERROR rustc_dump_leaf_obligations: `T: Foo`
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//@ compile-flags: -Znext-solver -Zerror-refinement-budget=2
+// Once the error refinement budget of a body is exhausted, errors are reported
+// for their root obligation. Refining the first error already visits two goals,
+// so the second one is only reported for `W<u64>: Trait`.
+
+trait Trait {}
+trait Bound {}
+
+struct W<T>(T);
+impl<T: Bound> Trait for W<T> {}
+
+fn impls_trait<T: Trait>() {}
+
+fn main() {
+    impls_trait::<W<u32>>();
+    //~^ ERROR the trait bound `u32: Bound` is not satisfied
+    impls_trait::<W<u64>>();
+    //~^ ERROR the trait bound `W<u64>: Trait` is not satisfied
+}
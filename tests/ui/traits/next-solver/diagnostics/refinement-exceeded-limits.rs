@@ -0,0 +1,23 @@
+//@ compile-flags: -Znext-solver
+
+// Check that we note when we stop looking for the leaf obligation responsible
+// for an error as doing so would build the proof trees of too many goals.
+
+macro_rules! wide_struct {
+    // Doubles the number of fields for each `x`.
+    ([$($field:ty,)*] x $($rest:tt)*) => {
+        wide_struct!([$($field,)* $($field,)*] $($rest)*);
+    };
+    ([$($field:ty,)*]) => {
+        struct Wide($($field,)* *const ());
+    };
+}
+
+wide_struct!([u8,] x x x x x x x x x);
+
+fn is_send<T: Send>() {}
+
+fn main() {
+    is_send::<Wide>();
+    //~^ ERROR `Wide` cannot be sent between threads safely
+}
@@ -0,0 +1,33 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+// Type mismatches from `Subtype` goals which only fail once both sides are
+// inferred should be reported like any other type mismatch.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+fn make<T>() -> T {
+    loop {}
+}
+
+fn fn_ptr_arity(c: bool) {
+    let a = make();
+    let b = make();
+    let _ = if c { a } else { b };
+    //~^ ERROR `if` and `else` have incompatible types
+    let pair = (a, b);
+    let _: (fn(u8), fn(u8, u8)) = pair;
+}
+
+fn mutability(c: bool) {
+    let a = make();
+    let b = make();
+    let _ = if c { a } else { b };
+    //~^ ERROR `if` and `else` have incompatible types
+    let pair = (a, b);
+    let _: (&'static mut u8, &'static u8) = pair;
+}
+
+fn main() {}
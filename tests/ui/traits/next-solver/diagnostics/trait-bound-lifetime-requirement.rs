@@ -0,0 +1,21 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+// Point to the trait bound which introduces a lifetime requirement
+// in borrowck errors.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Trait<'a> {}
+impl<'a> Trait<'a> for &'a u8 {}
+
+fn require<T: Trait<'static>>(_: T) {}
+
+fn test<'a>(x: &'a u8) {
+    require(x);
+    //~^ ERROR borrowed data escapes outside of function
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+// Unresolved const inference variables in the leaf obligation point at
+// the const parameter they were created for.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+struct Foo<const N: usize>;
+trait Bar {}
+
+fn needs_bar<T: Bar>(_: T) {}
+fn make<const N: usize>() -> Foo<N> {
+    Foo
+}
+
+fn main() {
+    needs_bar(make());
+    //~^ ERROR the trait bound `Foo<_>: Bar` is not satisfied
+}
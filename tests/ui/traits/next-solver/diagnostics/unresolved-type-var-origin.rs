@@ -0,0 +1,23 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+// Unresolved type inference variables in the leaf obligation point at the
+// expression whose type could not be inferred.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+struct Wrapper<T, U>(T, U);
+trait Bar {}
+
+fn needs_bar<T: Bar>(_: T) {}
+fn make<T, U>() -> Wrapper<T, U> {
+    loop {}
+}
+
+fn main() {
+    let x = make();
+    needs_bar(x);
+    //~^ ERROR the trait bound `Wrapper<_, _>: Bar` is not satisfied
+}
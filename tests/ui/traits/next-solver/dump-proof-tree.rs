@@ -0,0 +1,43 @@
+//@ add-minicore
+//@ check-pass
+//@ dump-proof-tree: Env::goals
+// Snapshot the candidates considered by the new solver when proving
+// where-clauses, both for goals which hold and for goals which do not.
+#![feature(no_core, trivial_bounds)]
+#![no_core]
+#![allow(trivial_bounds)]
+
+extern crate minicore;
+use minicore::*;
+
+trait Foo {}
+trait Bar {}
+trait Constrain {
+    type Output;
+}
+
+impl<T, U> Foo for T
+where
+    T: Constrain<Output = U>,
+    U: Bar,
+{
+}
+
+impl Constrain for () {
+    type Output = ();
+}
+
+impl Bar for u32 {}
+impl Constrain for u32 {
+    type Output = u32;
+}
+
+trait Env<T: Bar> {
+    fn goals()
+    where
+        u32: Foo,
+        (): Foo,
+        T: Foo;
+}
+
+fn main() {}
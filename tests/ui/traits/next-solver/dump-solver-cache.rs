@@ -0,0 +1,19 @@
+//@ add-minicore
+//@ check-pass
+//@ compile-flags: -Znext-solver -Zdump-solver-cache
+//@ normalize-stdout: "dep-node \d+" -> "dep-node N"
+// Dump the results cached in the global evaluation cache of the new solver.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Foo {}
+impl Foo for u32 {}
+
+fn requires_foo<T: Foo>() {}
+
+fn main() {
+    requires_foo::<u32>();
+}
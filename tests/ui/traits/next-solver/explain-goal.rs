@@ -0,0 +1,34 @@
+//@ add-minicore
+//@ check-pass
+//@ compile-flags: -Znext-solver -Zexplain-goal=u32:Foo@explain-goal.rs:33:5
+// Explain how the new solver proves a goal at a given position, marking
+// the candidates which were selected.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Foo {}
+trait Bar {}
+trait Constrain {
+    type Output;
+}
+
+impl<T, U> Foo for T
+where
+    T: Constrain<Output = U>,
+    U: Bar,
+{
+}
+
+impl Bar for u32 {}
+impl Constrain for u32 {
+    type Output = u32;
+}
+
+fn requires_foo<T: Foo>() {}
+
+fn main() {
+    requires_foo::<u32>();
+}
@@ -0,0 +1,29 @@
+//@ add-minicore
+//@ aux-build: external-macro-bound.rs
+//@ compile-flags: -Znext-solver
+//@ dont-require-annotations: NOTE
+// Point at the closest macro call in the current crate when the unsatisfied bound is
+// required by code inside of a macro from another crate, and note the code in the macro.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+#[macro_use]
+extern crate external_macro_bound;
+
+struct Local;
+
+macro_rules! check_local {
+    () => {
+        assert_portable!(Local);
+        //~^ ERROR the trait bound `Local: Portable` is not satisfied
+        //~| NOTE the unsatisfied bound above is required by this code in an external macro
+    };
+}
+
+fn main() {
+    assert_all_portable!(Local);
+    //~^ ERROR the trait bound `Local: Portable` is not satisfied
+    //~| NOTE the unsatisfied bound above is required by this code in an external macro
+    check_local!();
+}
@@ -23,8 +23,8 @@ impl<T> Bar for T where T: Foo<Assoc = i32> {}
 fn needs_bar<T: Bar>() {}
 
 fn foo<T: Foo<Assoc = i32> + Foo<Assoc = u32>>() {
+    //~^ ERROR conflicting requirements on `<T as Foo>::Assoc`
     needs_bar::<T>();
-    //~^ ERROR type annotations needed: cannot normalize
 }
 
 fn main() {}
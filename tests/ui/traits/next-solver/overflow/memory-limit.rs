@@ -0,0 +1,37 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver -Znext-solver-memory-limit=1
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+// Proving `(): Count<N>` requires proving `2^N` distinct goals, each of
+// which is cheap on its own. Check that we fail gracefully once the solver
+// exceeds its memory limit and keep reporting errors for other goals.
+
+struct Zero;
+struct Succ<N>(N);
+struct L<T>(T);
+struct R<T>(T);
+
+trait Count<N> {}
+impl<T> Count<Zero> for T {}
+impl<T, N> Count<Succ<N>> for T
+where
+    L<T>: Count<N>,
+    R<T>: Count<N>,
+{
+}
+
+type Sixteen = Succ<Succ<Succ<Succ<Succ<Succ<Succ<Succ<
+    Succ<Succ<Succ<Succ<Succ<Succ<Succ<Succ<Zero>>>>>>>>>>>>>>>>;
+
+fn count<T: Count<Sixteen>>() {}
+
+fn main() {
+    count::<()>();
+    //~^ ERROR is too complex for the trait solver
+    count::<u8>();
+    //~^ ERROR is too complex for the trait solver
+}
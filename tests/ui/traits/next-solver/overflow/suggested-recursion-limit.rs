@@ -0,0 +1,31 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+// Suggest a recursion limit which is actually sufficient, and point to
+// the impl causing the overflow if increasing the limit never helps.
+#![recursion_limit = "9"]
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+struct Inc<T>(T);
+
+trait Deep {}
+impl<T: Deep> Deep for Inc<T> {}
+impl Deep for () {}
+
+trait Infinite {}
+impl<T> Infinite for T where Inc<T>: Infinite {}
+
+fn impls_deep<T: Deep>() {}
+fn impls_infinite<T: Infinite>() {}
+
+type Four<T> = Inc<Inc<Inc<Inc<T>>>>;
+
+fn main() {
+    impls_deep::<Four<Four<Four<Four<Four<()>>>>>>();
+    //~^ ERROR overflow evaluating the requirement
+    impls_infinite::<()>();
+    //~^ ERROR overflow evaluating the requirement
+}
@@ -0,0 +1,41 @@
+//@ compile-flags: -Znext-solver
+
+// Check which candidate is used to point to the source of an ambiguous method.
+// Non-global where-bounds and alias-bounds shadow impls, while global
+// where-bounds are shadowed by them.
+
+trait A {
+    fn foo(&self) {}
+}
+trait B {
+    fn foo(&self) {}
+}
+impl<T> A for T {}
+impl<T> B for T {}
+
+trait Tr {
+    type Assoc: A;
+}
+
+fn global_where_bound()
+where
+    u8: A,
+{
+    0u8.foo();
+    //~^ ERROR multiple applicable items in scope
+}
+
+fn where_bound<T>(x: Vec<T>)
+where
+    Vec<T>: A,
+{
+    x.foo();
+    //~^ ERROR multiple applicable items in scope
+}
+
+fn alias_bound<T: Tr>(x: T::Assoc) {
+    x.foo();
+    //~^ ERROR multiple applicable items in scope
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+//@ compile-flags: -Znext-solver
+//@ check-pass
+
+// Global where-bounds are shadowed by impls. When deducing the closure
+// signature, we must only consider the impl candidate of `(): Trait<?F>`
+// and recurse into it to find the nested `?F: Fn(u32) -> u32` goal.
+
+trait Trait<F> {}
+impl<F: Fn(u32) -> u32> Trait<F> for () {}
+
+fn needs_trait<F>(f: F) -> F
+where
+    (): Trait<F>,
+{
+    f
+}
+
+fn global_where_bound()
+where
+    (): Trait<fn(u32) -> u32>,
+{
+    let _ = needs_trait(|x| x.pow(2));
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver -Zshuffle-obligations-verify -Zshuffle-obligations-seed=1
+// Both where-clauses of `constrain` constrain `T` to a different type, so which
+// one holds depends on the one we process first. Check that this is reported.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Single<T> {}
+impl Single<u8> for u32 {}
+impl Single<u16> for u64 {}
+
+fn constrain<T>() -> T
+where
+    u32: Single<T>,
+    u64: Single<T>,
+{
+    loop {}
+}
+
+fn main() {
+    let _ = constrain();
+    //~^ ERROR the result of proving these obligations depends on the order in which they are processed
+    //~| ERROR the trait bound
+}
@@ -0,0 +1,7 @@
+//@ compile-flags: -Znext-solver -Zshuffle-obligations
+//@ normalize-stderr: "seed [0-9]+" -> "seed $$SEED"
+//@ normalize-stderr: "seed=[0-9]+" -> "seed=$$SEED"
+//@ check-pass
+// Check that we print the randomly chosen seed used to shuffle obligations.
+
+fn main() {}
@@ -0,0 +1,36 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver -Zshuffle-obligations-verify -Zshuffle-obligations-seed=1
+//@ check-pass
+// Proving these obligations does not depend on the order in which
+// they or their candidates are processed.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Trait<T> {
+    type Assoc;
+}
+impl Trait<u8> for u32 {
+    type Assoc = u16;
+}
+impl Trait<u16> for u32 {
+    type Assoc = u8;
+}
+
+trait Other {}
+impl Other for u8 {}
+
+fn constrain<T, U>(_: T) -> U
+where
+    T: Trait<U>,
+    T::Assoc: Other,
+{
+    loop {}
+}
+
+fn main() {
+    let x = constrain(1u32);
+    let _: u16 = x;
+}
@@ -0,0 +1,34 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver -Zsolver-validation
+#![feature(no_core, marker_trait_attr)]
+#![no_core]
+
+// Check that `-Zsolver-validation` doesn't report any invariant violations
+// when building proof trees for cached goals while reporting errors.
+
+extern crate minicore;
+use minicore::*;
+
+struct Root;
+struct MultipleCandidates;
+struct Wrapper<T>(T);
+
+#[marker]
+trait Trait {}
+impl Trait for Root
+where
+    MultipleCandidates: Trait,
+    MultipleCandidates: Trait,
+{
+}
+impl Trait for MultipleCandidates where Root: Trait {}
+impl Trait for MultipleCandidates {}
+impl<T: Trait> Trait for Wrapper<T> {}
+
+fn impls_trait<T: Trait>() {}
+
+fn main() {
+    impls_trait::<Wrapper<Root>>();
+    impls_trait::<Wrapper<Wrapper<u32>>>();
+    //~^ ERROR the trait bound `u32: Trait` is not satisfied
+}
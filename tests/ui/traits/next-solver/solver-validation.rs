@@ -0,0 +1,55 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver -Zsolver-validation
+//@ check-pass
+#![feature(no_core, rustc_attrs, marker_trait_attr)]
+#![no_core]
+
+// Check that `-Zsolver-validation` doesn't report any invariant violations
+// when reevaluating cached goals involving cycles and projections.
+
+extern crate minicore;
+use minicore::*;
+
+struct Root;
+struct MultipleCandidates;
+
+#[marker]
+trait Trait {}
+impl Trait for Root
+where
+    MultipleCandidates: Trait,
+    MultipleCandidates: Trait,
+{
+}
+impl Trait for MultipleCandidates where Root: Trait {}
+impl Trait for MultipleCandidates {}
+
+#[rustc_coinductive]
+trait A {}
+#[rustc_coinductive]
+trait B {}
+impl<T: B> A for T {}
+impl<T: A> B for T {}
+
+trait Project {
+    type Assoc;
+}
+impl Project for u8 {
+    type Assoc = u16;
+}
+impl Project for u16 {
+    type Assoc = u8;
+}
+
+fn impls_trait<T: Trait>() {}
+fn impls_a<T: A>() {}
+fn project<T: Project<Assoc = U>, U: Project<Assoc = T>>() {}
+
+fn main() {
+    impls_trait::<Root>();
+    impls_trait::<Root>();
+    impls_a::<()>();
+    impls_a::<()>();
+    project::<u8, _>();
+    project::<u16, _>();
+}
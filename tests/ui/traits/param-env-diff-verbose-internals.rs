@@ -0,0 +1,27 @@
+//@ add-minicore
+//@ compile-flags: -Zverbose-internals
+// Checks that we note the where-clauses of the trait item which are missing
+// from the environment of the impl item.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+fn needs_copy<T: Copy>() {}
+
+trait Trait<T> {
+    fn method()
+    where
+        T: Copy;
+}
+
+struct S;
+impl<T> Trait<T> for S {
+    fn method() {
+        needs_copy::<T>();
+        //~^ ERROR the trait bound `T: minicore::Copy` is not satisfied
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//@ add-minicore
+//@ aux-build: private-impl.rs
+// Mention that an unsatisfied trait has an implementation in another crate
+// which we don't suggest as its self type is private to that crate.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+extern crate private_impl;
+
+use private_impl::Marker;
+
+fn requires_marker<T: Marker>() {}
+
+fn main() {
+    requires_marker::<u8>();
+    //~^ ERROR the trait bound `u8: Marker` is not satisfied
+}
@@ -36,6 +36,7 @@ fn bar() -> Result<(), String> { //~ NOTE expected `String` because of this
     //~| NOTE the question mark operation (`?`) implicitly performs a conversion on the error value using the `From` trait
     //~| NOTE required for `Result<(), String>` to implement `FromResidual<Result<Infallible, ()>>`
     //~| HELP the following other types implement trait `From<T>`:
+    //~| HELP consider converting the error with `map_err`
     Ok(one)
 }
 
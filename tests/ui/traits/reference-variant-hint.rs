@@ -0,0 +1,39 @@
+//@ add-minicore
+// Check that we point out which reference variant of the self type implements the
+// trait if the one that is used doesn't.
+
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+trait Owned {}
+impl Owned for S {}
+
+trait Mutable {}
+impl<'a> Mutable for &'a mut S {}
+
+struct S;
+
+fn owned<T: Owned>(_: T) {}
+fn mutable<T: Mutable>(_: T) {}
+
+fn param(s: &S) {
+    owned(s);
+    //~^ ERROR the trait bound `&S: Owned` is not satisfied
+}
+
+fn main() {
+    let s = S;
+    let r = &s;
+    owned(r);
+    //~^ ERROR the trait bound `&S: Owned` is not satisfied
+    owned(&s);
+    //~^ ERROR the trait bound `&S: Owned` is not satisfied
+    mutable(r);
+    //~^ ERROR the trait bound `&S: Mutable` is not satisfied
+    let mut s = S;
+    mutable(&s);
+    //~^ ERROR the trait bound `&S: Mutable` is not satisfied
+}
@@ -0,0 +1,21 @@
+// check that reservation impls of traits with associated items are accounted
+// for in negative reasoning when normalizing.
+//@ revisions: old next
+//@[next] compile-flags: -Znext-solver
+#![feature(rustc_attrs)]
+
+trait MyTrait {
+    type Assoc;
+}
+#[rustc_reservation_impl = "this impl is reserved"]
+impl MyTrait for () {
+    type Assoc = u8;
+    //~^ ERROR the trait bound `(): MyTrait` is not satisfied
+}
+
+trait OtherTrait {}
+impl OtherTrait for () {}
+impl<T: MyTrait<Assoc = u8>> OtherTrait for T {}
+//~^ ERROR conflicting implementations
+
+fn main() {}
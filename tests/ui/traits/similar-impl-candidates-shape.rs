@@ -0,0 +1,32 @@
+// Check which impls are mentioned as similar candidates. Impls are grouped by the
+// shape of their self type, e.g. all numeric types or all arrays and slices are
+// similar, while ADTs are only similar to themselves. References are ignored.
+
+trait Trait {}
+
+struct Local;
+struct Other;
+
+impl Trait for u8 {}
+impl Trait for i64 {}
+impl Trait for f32 {}
+impl Trait for [u8; 3] {}
+impl Trait for Vec<u8> {}
+impl Trait for String {}
+impl Trait for (u8,) {}
+impl Trait for &Other {}
+impl Trait for Option<Local> {}
+impl Trait for Option<u8> {}
+
+fn needs<T: Trait>(_: T) {}
+
+fn main() {
+    needs(1u32);
+    //~^ ERROR the trait bound `u32: Trait` is not satisfied
+    needs([1u16]);
+    //~^ ERROR the trait bound `[u16; 1]: Trait` is not satisfied
+    needs(Other);
+    //~^ ERROR the trait bound `Other: Trait` is not satisfied
+    needs(Some(1u16));
+    //~^ ERROR the trait bound `Option<u16>: Trait` is not satisfied
+}
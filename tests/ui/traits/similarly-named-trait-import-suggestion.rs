@@ -0,0 +1,28 @@
+//@ add-minicore
+// Suggest importing the similarly named trait which is implemented instead of the
+// imported one which is not.
+#![feature(no_core)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+mod a {
+    pub trait Speak {}
+    //~^ HELP this trait has no implementations, consider adding one
+}
+
+mod b {
+    pub trait Speak {}
+    impl Speak for u8 {}
+}
+
+use a::Speak;
+//~^ HELP consider importing `b::Speak` instead
+
+fn speak<T: Speak>(_: T) {}
+
+fn main() {
+    speak(1u8);
+    //~^ ERROR the trait bound `u8: a::Speak` is not satisfied
+}
@@ -0,0 +1,54 @@
+//@ add-minicore
+//@ revisions: current next
+//@[next] compile-flags: -Znext-solver
+// Check that we walk the `Deref` chain of smart pointers to find a target that satisfies
+// an unsatisfied trait bound.
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+extern crate minicore;
+use minicore::*;
+
+#[lang = "deref"]
+trait Deref {
+    #[lang = "deref_target"]
+    type Target: ?Sized;
+
+    fn deref(&self) -> &Self::Target;
+}
+
+struct Rc<T>(T);
+impl<T> Deref for Rc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+struct S;
+
+trait ByRef {}
+impl<'a> ByRef for &'a S {}
+
+trait ByValue {}
+impl ByValue for S {}
+
+fn by_ref<T: ByRef>(_: T) {}
+fn by_value<T: ByValue>(_: T) {}
+
+fn main() {
+    let rc = Rc(S);
+    by_ref(rc);
+    //~^ ERROR the trait bound `Rc<S>: ByRef` is not satisfied
+    let rc = Rc(Rc(S));
+    by_ref(&rc);
+    //~^ ERROR the trait bound `&Rc<Rc<S>>: ByRef` is not satisfied
+    let rc = &Rc(Rc(S));
+    by_ref(rc);
+    //~^ ERROR the trait bound `&Rc<Rc<S>>: ByRef` is not satisfied
+    let rc = Rc(S);
+    by_value(rc);
+    //~^ ERROR the trait bound `Rc<S>: ByValue` is not satisfied
+}
@@ -0,0 +1,18 @@
+//@ revisions: current next
+//@[next] compile-flags: -Znext-solver
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@ compile-flags: -Ztrait-solver-progress-interval=1
+//@ check-pass
+
+// Checks that calling the default `trait_solver_progress` hook, which never
+// cancels trait solving, does not affect compilation.
+
+trait Trait {}
+impl Trait for u32 {}
+impl<T: Trait> Trait for Vec<T> {}
+
+fn requires_trait<T: Trait>(_: T) {}
+
+fn main() {
+    requires_trait(vec![vec![1u32]]);
+}
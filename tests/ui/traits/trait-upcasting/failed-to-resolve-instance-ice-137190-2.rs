@@ -0,0 +1,21 @@
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#137190
+trait Supertrait<T> {
+    fn method(&self) {} //~ ERROR the trait bound `(): Supertrait<()>` is not satisfied
+}
+
+trait Trait<P>: Supertrait<()> {}
+
+impl<P> Trait<P> for () {} //~ ERROR the trait bound `(): Supertrait<()>` is not satisfied
+
+const fn upcast<P>(x: &dyn Trait<P>) -> &dyn Supertrait<()> {
+    x
+}
+
+const fn foo() -> &'static dyn Supertrait<()> {
+    upcast::<()>(&())
+}
+
+const _: &'static dyn Supertrait<()> = foo();
+
+fn main() {}
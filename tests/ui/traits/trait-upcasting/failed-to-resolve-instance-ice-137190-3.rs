@@ -0,0 +1,13 @@
+// ICE failed to resolve instance for ...
+// issue: rust-lang/rust#137190
+trait Supertrait {
+    fn method(&self) {} //~ ERROR the trait bound `(): Supertrait` is not satisfied
+}
+
+trait Trait: Supertrait {}
+
+impl Trait for () {} //~ ERROR the trait bound `(): Supertrait` is not satisfied
+
+const _: &dyn Supertrait = &() as &dyn Trait as &dyn Supertrait;
+
+fn main() {}
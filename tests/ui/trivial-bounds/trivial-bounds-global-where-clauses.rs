@@ -0,0 +1,26 @@
+//@ add-minicore
+// With `trivial_bounds`, `global_where_clauses` also reports trait bounds which can never
+// hold, and takes precedence over the `trivial_bounds` lint for the bounds it reports.
+#![feature(no_core, trivial_bounds)]
+#![no_core]
+#![deny(global_where_clauses, trivial_bounds)]
+
+extern crate minicore;
+use minicore::*;
+
+pub struct Local;
+
+pub fn always() where i32: Copy {}
+//~^ ERROR the trait bound `i32: minicore::Copy` always holds
+
+pub fn never() where Local: Copy {}
+//~^ ERROR the trait bound `Local: minicore::Copy` can never hold
+
+pub fn outlives() where i32: 'static {}
+//~^ ERROR lifetime bound i32: 'static does not depend on any type or lifetime parameters
+
+#[allow(global_where_clauses)]
+pub fn allowed() where Local: Copy {}
+//~^ ERROR trait bound Local: minicore::Copy does not depend on any type or lifetime parameters
+
+fn main() {}
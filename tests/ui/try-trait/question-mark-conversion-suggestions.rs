@@ -0,0 +1,107 @@
+//@ add-minicore
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+// When `?` fails to convert the error, suggest implementing `From` if one of the error types
+// is local, and `map_err` otherwise.
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+#![allow(internal_features)]
+
+extern crate minicore;
+use minicore::*;
+
+enum Infallible {}
+
+enum ControlFlow<B, C> {
+    #[lang = "Continue"]
+    Continue(C),
+    #[lang = "Break"]
+    Break(B),
+}
+
+#[rustc_diagnostic_item = "FromResidual"]
+trait FromResidual<R> {
+    #[lang = "from_residual"]
+    fn from_residual(residual: R) -> Self;
+}
+
+#[lang = "Try"]
+trait Try: FromResidual<Self::Residual> {
+    type Output;
+    type Residual;
+    #[lang = "from_output"]
+    fn from_output(output: Self::Output) -> Self;
+    #[lang = "branch"]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+#[rustc_diagnostic_item = "From"]
+trait From<T> {
+    fn from(value: T) -> Self;
+}
+
+impl<T> From<T> for T {
+    fn from(value: T) -> T {
+        value
+    }
+}
+
+#[rustc_diagnostic_item = "Result"]
+enum Res<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+impl<T, E> Try for Res<T, E> {
+    type Output = T;
+    type Residual = Res<Infallible, E>;
+    fn from_output(output: T) -> Self {
+        Res::Ok(output)
+    }
+    fn branch(self) -> ControlFlow<Res<Infallible, E>, T> {
+        match self {
+            Res::Ok(v) => ControlFlow::Continue(v),
+            Res::Err(e) => ControlFlow::Break(Res::Err(e)),
+        }
+    }
+}
+
+impl<T, E, F: From<E>> FromResidual<Res<Infallible, E>> for Res<T, F> {
+//~^ HELP the trait `FromResidual<Res<_, ParseError>>` is not implemented for `Res<u8, AppError>`
+//~| HELP the trait `FromResidual<Res<_, u8>>` is not implemented for `Res<u8, u16>`
+    fn from_residual(residual: Res<Infallible, E>) -> Self {
+        match residual {
+            Res::Err(e) => Res::Err(From::from(e)),
+        }
+    }
+}
+
+struct ParseError;
+struct AppError;
+//~^ HELP consider implementing `From<ParseError>` for `AppError`
+
+fn parse() -> Res<u8, ParseError> {
+    Res::Err(ParseError)
+}
+
+fn run() -> Res<u8, AppError> {
+    let x = parse()?;
+    //~^ ERROR `?` couldn't convert the error to `AppError`
+    //~| HELP for that trait implementation, expected `AppError`, found `ParseError`
+    Res::Ok(x)
+}
+
+fn byte() -> Res<u8, u8> {
+    Res::Err(0)
+}
+
+fn run_foreign() -> Res<u8, u16> {
+    let x = byte()?;
+    //~^ ERROR `?` couldn't convert the error to `u16`
+    //~| HELP for that trait implementation, expected `u16`, found `u8`
+    //~| HELP consider converting the error with `map_err`
+    Res::Ok(x)
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+//@ add-minicore
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+#![feature(no_core, unsafe_binders)]
+#![no_core]
+#![allow(incomplete_features)]
+
+// Check that the unstable marker trait used to require that unsafe binder types
+// never need to be dropped doesn't leak into diagnostics.
+
+extern crate minicore;
+use minicore::*;
+
+struct NotCopy;
+
+fn generic<T>(_: unsafe<> T) {}
+//~^ ERROR the trait bound `T: Copy` is not satisfied
+
+fn tuple(_: unsafe<> (u8, NotCopy)) {}
+//~^ ERROR the trait bound `NotCopy: Copy` is not satisfied
+
+fn main() {}
@@ -0,0 +1,35 @@
+//@ add-minicore
+//@ compile-flags: -Znext-solver
+#![feature(no_core, staged_api)]
+#![allow(internal_features)]
+#![no_core]
+#![unstable(feature = "feat_foo", issue = "none")]
+
+// Unstable impls are never used for inference guidance without the
+// feature, so we mention them when reporting the resulting ambiguity.
+
+extern crate minicore;
+use minicore::*;
+
+pub trait Foo {
+    fn foo(self);
+}
+pub struct Wrapper<T>(T);
+
+impl Foo for Wrapper<u32> {
+    fn foo(self) {}
+}
+
+#[unstable_feature_bound(feat_foo)]
+impl Foo for Wrapper<u64> {
+    fn foo(self) {}
+}
+
+fn make<T>() -> Wrapper<T> {
+    loop {}
+}
+
+fn main() {
+    Foo::foo(make());
+    //~^ ERROR type annotations needed
+}